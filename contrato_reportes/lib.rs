@@ -0,0 +1,277 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![allow(unreachable_code, unexpected_cfgs, clippy::needless_return, clippy::diverging_sub_expression)]
+
+///Contrato de reportes del Club SemRust. Guarda una referencia (ClubSemRustRef) al contrato del club y arma reportes a
+///partir de sus consultas de solo lectura: socios morosos, recaudación por mes y cantidad de socios por categoría y por
+///deporte. Para que funcione, el Owner del club debe otorgarle a la cuenta de este contrato el permiso de solo lectura
+///(agregar_lector).
+#[ink::contract]
+pub mod contrato_reportes {
+    use ink::prelude::collections::BTreeMap;
+    use ink::prelude::vec::Vec;
+    use ClubSemRust::ClubSemRust::{CategoriasSocios, ClubSemRustRef, Deporte, Pago, Socio};
+
+    /// Este struct describe a un socio moroso. Almacena su DNI (dni), la cantidad de pagos vencidos e impagos
+    /// (pagos_vencidos), la suma de sus montos (deuda_vencida) y el vencimiento del más antiguo de ellos
+    /// (vencimiento_mas_antiguo), expresado en milisegundos.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Moroso {
+        pub dni: u32,
+        pub pagos_vencidos: u32,
+        pub deuda_vencida: u128,
+        pub vencimiento_mas_antiguo: u64,
+    }
+
+    /// Este struct almacena lo recaudado en un mes calendario (UTC): el año (anio), el mes del 1 al 12 (mes), la
+    /// cantidad de pagos efectuados en ese mes (cantidad_pagos) y la suma de sus montos (total).
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct RecaudacionMensual {
+        pub anio: u32,
+        pub mes: u8,
+        pub cantidad_pagos: u32,
+        pub total: u128,
+    }
+
+    /// Storage del contrato de reportes. Almacena:
+    /// :> La referencia al contrato del club que se consulta (club), la cual es un tipo de dato ClubSemRustRef.
+    /// :> El dueño del contrato de reportes (owner), el cual es un tipo de dato AccountId.
+    /// :> Las cuentas habilitadas a pedir reportes además del owner (permitidos), el cual es un tipo de dato
+    ///    Vec(AccountId). Los reportes exponen datos del club, por lo que no están abiertos a cualquier cuenta.
+    #[ink(storage)]
+    pub struct ContratoReportes {
+        club: ClubSemRustRef,
+        owner: AccountId,
+        permitidos: Vec<AccountId>,
+    }
+
+    impl ContratoReportes {
+        ///Crea el contrato de reportes para el club desplegado en la cuenta club. El caller queda como owner.
+        #[ink(constructor)]
+        pub fn new(club: AccountId) -> Self {
+            return Self::new_priv(club);
+        }
+
+        fn new_priv(club: AccountId) -> Self {
+            let club = ink::env::call::FromAccountId::from_account_id(club);
+            return Self {club, owner: Self::env().caller(), permitidos: Vec::new()};
+        }
+
+        ///El método agregar_permitido habilita a una cuenta a pedir reportes. Solo el owner puede llamarlo, y arrojará un
+        ///panic si no lo es o si la cuenta ya estaba habilitada. Retorna true.
+        #[ink(message)]
+        pub fn agregar_permitido(&mut self, cuenta: AccountId) -> bool {
+            return self.agregar_permitido_priv(cuenta);
+        }
+
+        fn agregar_permitido_priv(&mut self, cuenta: AccountId) -> bool {
+            if (self.es_admin()) && (!self.permitidos.contains(&cuenta)) {
+                self.permitidos.push(cuenta);
+                return true;
+            }
+            return panic!("No cuenta con rango Owner o la cuenta ingresada ya esta habilitada!");
+        }
+
+        ///El método quitar_permitido revoca la habilitación de una cuenta para pedir reportes. Solo el owner puede
+        ///llamarlo, y arrojará un panic si no lo es o si la cuenta no estaba habilitada. Retorna true.
+        #[ink(message)]
+        pub fn quitar_permitido(&mut self, cuenta: AccountId) -> bool {
+            return self.quitar_permitido_priv(cuenta);
+        }
+
+        fn quitar_permitido_priv(&mut self, cuenta: AccountId) -> bool {
+            if (self.es_admin()) && (self.permitidos.contains(&cuenta)) {
+                self.permitidos.retain(|permitido| permitido != &cuenta);
+                return true;
+            }
+            return panic!("No cuenta con rango Owner o la cuenta ingresada no esta habilitada!");
+        }
+
+        ///El método get_morosos devuelve los socios que tienen al menos un pago impago con el vencimiento ya cumplido a la
+        ///fecha del bloque actual, ordenados de mayor a menor deuda vencida. Arrojará un panic si el caller no está
+        ///habilitado o si el club rechaza la consulta por no haber otorgado el permiso de lectura a este contrato.
+        #[ink(message)]
+        pub fn get_morosos(&self) -> Vec<Moroso> {
+            return self.get_morosos_priv();
+        }
+
+        fn get_morosos_priv(&self) -> Vec<Moroso> {
+            self.verificar_habilitado();
+            return calcular_morosos(&self.club.get_pagos_totales(None), self.env().block_timestamp());
+        }
+
+        ///El método get_recaudacion_mensual devuelve lo recaudado por el club en cada mes calendario (UTC), según la fecha
+        ///en que se efectuó cada pago, ordenado cronológicamente. Arrojará un panic en los mismos casos que get_morosos.
+        #[ink(message)]
+        pub fn get_recaudacion_mensual(&self) -> Vec<RecaudacionMensual> {
+            return self.get_recaudacion_mensual_priv();
+        }
+
+        fn get_recaudacion_mensual_priv(&self) -> Vec<RecaudacionMensual> {
+            self.verificar_habilitado();
+            return calcular_recaudacion_mensual(&self.club.get_pagos_totales(None));
+        }
+
+        ///El método get_socios_por_categoria devuelve la cantidad de socios de cada categoría, incluyendo las que no
+        ///tienen socios. Arrojará un panic en los mismos casos que get_morosos.
+        #[ink(message)]
+        pub fn get_socios_por_categoria(&self) -> Vec<(CategoriasSocios, u32)> {
+            return self.get_socios_por_categoria_priv();
+        }
+
+        fn get_socios_por_categoria_priv(&self) -> Vec<(CategoriasSocios, u32)> {
+            self.verificar_habilitado();
+            return contar_por_categoria(&self.club.get_socios());
+        }
+
+        ///El método get_socios_por_deporte devuelve la cantidad de socios que eligieron cada deporte, incluyendo los que
+        ///no tienen socios. Los socios sin deporte asignado no se cuentan. Arrojará un panic en los mismos casos que
+        ///get_morosos.
+        #[ink(message)]
+        pub fn get_socios_por_deporte(&self) -> Vec<(Deporte, u32)> {
+            return self.get_socios_por_deporte_priv();
+        }
+
+        fn get_socios_por_deporte_priv(&self) -> Vec<(Deporte, u32)> {
+            self.verificar_habilitado();
+            return contar_por_deporte(&self.club.get_socios());
+        }
+
+        fn es_admin(&self) -> bool {
+            return self.env().caller() == self.owner;
+        }
+
+        fn verificar_habilitado(&self) {
+            if !self.es_admin() && !self.permitidos.contains(&self.env().caller()) {
+                panic!("No se cuenta con los permisos necesarios para pedir reportes!");
+            }
+        }
+    }
+
+    /// Agrupa por socio los pagos impagos cuyo vencimiento es anterior a ahora, y los ordena de mayor a menor deuda.
+    fn calcular_morosos(pagos: &[Pago], ahora: u64) -> Vec<Moroso> {
+        let mut por_dni: BTreeMap<u32, Moroso> = BTreeMap::new();
+        for pago in pagos.iter().filter(|pago| pago.get_fecha_pago().is_none() && pago.get_fecha_venci() < ahora) {
+            let moroso = por_dni.entry(pago.get_dni_socio()).or_insert(Moroso {
+                dni: pago.get_dni_socio(),
+                pagos_vencidos: 0,
+                deuda_vencida: 0,
+                vencimiento_mas_antiguo: pago.get_fecha_venci(),
+            });
+            moroso.pagos_vencidos += 1;
+            moroso.deuda_vencida += pago.get_monto();
+            moroso.vencimiento_mas_antiguo = moroso.vencimiento_mas_antiguo.min(pago.get_fecha_venci());
+        }
+        let mut morosos: Vec<Moroso> = por_dni.into_values().collect();
+        morosos.sort_by_key(|moroso| core::cmp::Reverse(moroso.deuda_vencida));
+        return morosos;
+    }
+
+    /// Convierte un timestamp en milisegundos al año y mes calendario UTC que le corresponden. El calculo vive en
+    /// nucleo_facturacion, para que el contrato, este reporte y la CLI agrupen los meses de la misma forma.
+    fn anio_y_mes(milisegundos: u64) -> (u32, u8) {
+        return nucleo_facturacion::anio_y_mes(milisegundos);
+    }
+
+    fn calcular_recaudacion_mensual(pagos: &[Pago]) -> Vec<RecaudacionMensual> {
+        let mut por_mes: BTreeMap<(u32, u8), RecaudacionMensual> = BTreeMap::new();
+        for pago in pagos {
+            if let Some(fecha_pago) = pago.get_fecha_pago() {
+                let (anio, mes) = anio_y_mes(fecha_pago);
+                let recaudacion = por_mes.entry((anio, mes)).or_insert(RecaudacionMensual {anio, mes, cantidad_pagos: 0, total: 0});
+                recaudacion.cantidad_pagos += 1;
+                recaudacion.total += pago.get_monto();
+            }
+        }
+        return por_mes.into_values().collect();
+    }
+
+    fn contar_por_categoria(socios: &[Socio]) -> Vec<(CategoriasSocios, u32)> {
+        return (1..=3).filter_map(|id| CategoriasSocios::categoria_from_id(&id)).map(|categoria| {
+            let cantidad = socios.iter().filter(|socio| socio.get_categoria() == categoria).count() as u32;
+            (categoria, cantidad)
+        }).collect();
+    }
+
+    fn contar_por_deporte(socios: &[Socio]) -> Vec<(Deporte, u32)> {
+        return (1..=8).filter_map(|id| Deporte::deporte_from_id(&Some(id))).map(|deporte| {
+            let cantidad = socios.iter().filter(|socio| socio.get_deporte() == Some(deporte.clone())).count() as u32;
+            (deporte, cantidad)
+        }).collect();
+    }
+
+    #[cfg(test)]
+    #[allow(clippy::bool_assert_comparison, clippy::vec_init_then_push)]
+    mod tests {
+        use super::*;
+
+        const ENERO_2024: u64 = 1_704_067_200_000;
+        const FEBRERO_2024: u64 = 1_706_745_600_000;
+
+        fn pagos_de_prueba() -> Vec<Pago> {
+            let mut pagos = Vec::new();
+            pagos.push(Pago::new(30537974, 3000, ENERO_2024 + 10, Some(ENERO_2024), false));
+            pagos.push(Pago::new(30537974, 3000, FEBRERO_2024 + 10, None, false));
+            pagos.push(Pago::new(31195032, 5000, ENERO_2024 + 10, None, false));
+            pagos.push(Pago::new(31195032, 5000, FEBRERO_2024 + 10, None, false));
+            pagos.push(Pago::new(31195032, 5000, FEBRERO_2024 + 20, Some(FEBRERO_2024 + 1), false));
+            return pagos;
+        }
+
+        #[ink::test]
+        fn calcular_morosos_test() {
+            let morosos = calcular_morosos(&pagos_de_prueba(), FEBRERO_2024 + 15);
+            assert_eq!(morosos, vec![
+                Moroso {dni: 31195032, pagos_vencidos: 2, deuda_vencida: 10000, vencimiento_mas_antiguo: ENERO_2024 + 10},
+                Moroso {dni: 30537974, pagos_vencidos: 1, deuda_vencida: 3000, vencimiento_mas_antiguo: FEBRERO_2024 + 10},
+            ]);
+            //antes de que venza cualquier pago no hay morosos
+            assert_eq!(calcular_morosos(&pagos_de_prueba(), ENERO_2024), Vec::new());
+        }
+
+        #[ink::test]
+        fn calcular_recaudacion_mensual_test() {
+            let recaudacion = calcular_recaudacion_mensual(&pagos_de_prueba());
+            assert_eq!(recaudacion, vec![
+                RecaudacionMensual {anio: 2024, mes: 1, cantidad_pagos: 1, total: 3000},
+                RecaudacionMensual {anio: 2024, mes: 2, cantidad_pagos: 1, total: 5000},
+            ]);
+            assert_eq!(anio_y_mes(FEBRERO_2024 - 1), (2024, 1));
+            assert_eq!(anio_y_mes(0), (1970, 1));
+        }
+
+        #[ink::test]
+        fn contar_por_categoria_y_deporte_test() {
+            let mut socios = Vec::new();
+            socios.push(Socio::new("David Starsky".to_string(), 30537974, CategoriasSocios::B, Some(Deporte::Tenis)));
+            socios.push(Socio::new("Kenneth Hutchinson".to_string(), 31195032, CategoriasSocios::B, Some(Deporte::Tenis)));
+            socios.push(Socio::new("Harold Dobey".to_string(), 20123456, CategoriasSocios::A, None));
+            assert_eq!(contar_por_categoria(&socios), vec![(CategoriasSocios::A, 1), (CategoriasSocios::B, 2), (CategoriasSocios::C, 0)]);
+            let por_deporte = contar_por_deporte(&socios);
+            assert_eq!(por_deporte.len(), 8);
+            assert!(por_deporte.contains(&(Deporte::Tenis, 2)));
+            assert!(por_deporte.contains(&(Deporte::Futbol, 0)));
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "No cuenta con rango Owner o la cuenta ingresada ya esta habilitada!")]
+        fn agregar_permitido_sin_permisos_test() {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x1; 32]));
+            let mut reportes = ContratoReportes::new(AccountId::from([0x9; 32]));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+            reportes.agregar_permitido(AccountId::from([0x2; 32]));
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "No se cuenta con los permisos necesarios para pedir reportes!")]
+        fn get_morosos_no_habilitado_test() {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x1; 32]));
+            let mut reportes = ContratoReportes::new(AccountId::from([0x9; 32]));
+            assert_eq!(reportes.agregar_permitido(AccountId::from([0x2; 32])), true);
+            assert_eq!(reportes.quitar_permitido(AccountId::from([0x2; 32])), true);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+            reportes.get_morosos();
+        }
+    }
+}
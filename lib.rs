@@ -1,87 +1,37 @@
 //comando para el html del tarpaulin: cargo tarpaulin --target-dir src/coverage --skip-clean --exclude-files=target/debug/* --out html
 
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
+// El crate y su módulo de contrato se llaman ClubSemRust desde el primer despliegue.
+#![allow(non_snake_case)]
 pub use self::ClubSemRust::ClubSemRustRef;
 
+// El contrato informa sus errores con `return panic!(...)` y retorna siempre con `return`, por lo que se permiten los
+// lints que dispara ese estilo, además de los cfg que genera ink 4.2 y que el compilador no reconoce.
 #[ink::contract]
+#[allow(unexpected_cfgs, unreachable_code, clippy::needless_return, clippy::diverging_sub_expression)]
 pub mod ClubSemRust {
 
-    ///El trait ConversionFecha se encarga de facilitar el trabajo en el manejo del tiempo con milisegundos.
-    ///Permite la traducción de una manera sencilla del Timestamp del entonrno a las distintas medidas de tiempo que
-    ///puedan ser necesarias al momento de manejar el tiempo.   
-    pub trait ConversionFecha {
-        // Es llamado por el tipo que implemente el trait y devuelve los n segundos correspondientes
-        fn from_segundos (&self) -> u64;
-        // Es llamado por el tipo que implemente el trait y devuelve los n minutos correspondientes
-        fn from_horas (&self) -> u64;
-        // Es llamado por el tipo que implemente el trait y devuelve las n horas correspondientes
-        fn from_dias (&self) -> u64;
-        // Es llamado por el tipo que implemente el trait y devuelve los n dias correspondientes
-        fn from_semanas (&self) -> u64;
-        // Es llamado por el tipo que implemente el trait y devuelve las n semanas correspondientes
-        fn from_meses (&self) -> u64;
-        // Es llamado por el tipo que implemente el trait y devuelve los n años correspondientes
-        fn from_anios (&self) -> u64;
-    }
-
-    /// Implementacion del trait ConversionFecha para el u64, mismo tipo de dato del Timestamp del entorno
-    /// para realizar todas las operaciones necesarias. A partir del calculo de los minutos hacia las unidades mas significativas
-    /// se decidió reutilizar la funcion from_segundos para mejorar la legibilidad de las formulas empleadas. Se adapta la formula para el caso de la conversion
-    /// a meses y años, debido a que existen diferentes cantidades para las mismas unidades (28, 29, 30 o 31 dias para 1 mes y diferentes cantidades de dias 
-    /// en la composicion de 1 año).
-    
-    impl ConversionFecha for u64 {
-        /// Se multiplica el numero que llama el metodo por 1000, devolviendo el resultado 
-         fn from_segundos (&self) -> u64 {
-             return self * 1000;
-         }   
-          
-         ///Se multiplica el resultado de los segundos correspondientes
-         ///por 3600 se obtiene la cantidad de horas desde el numero disparador del metodo. 
-         fn from_horas (&self) -> u64 {
-             return self.from_segundos() * 3600;
-         }
-          
-         ///Se multiplica el resultado de los segundos correspondientes
-         ///por 86400 se obtiene la cantidad de dias desde el numero disparador del metodo.
-         fn from_dias (&self) -> u64 {
-             return self.from_segundos() * 86400;
-         }
-            
-         ///Se multiplica el resultado de los segundos correspondientes
-         ///por 86400 se obtiene la cantidad de semanas desde el numero disparador del metodo.
-         fn from_semanas (&self) -> u64 {
-             return self.from_segundos() * 604800;
-         }
-         
-         ///Se multiplica el numero disparador del metodo por 2629743, devolviendo el numero de meses correspondiente donde 1 Mes = 30.44 días 
-         fn from_meses (&self) -> u64 {
-             return self.from_segundos() * 2629743;
-         }
-         
-         ///Se multiplica el numero disparador del metodo por 2629743, devolviendo el numero de años correspondiente donde 1 Año = 365.24 días 
-         fn from_anios (&self) -> u64 {
-             return self.from_segundos() * 31556926;
-         }
-     }
-
-    use ink::env::block_timestamp;
+    pub use nucleo_facturacion::{ConversionFecha, ErrorAritmetico, Monto};
+    use nucleo_facturacion::PagoFacturable;
     use ink::prelude::string::String;
     use ink::prelude::vec::Vec;
+    use ink::prelude::collections::BTreeSet;
+    use scale::Encode;
 
-    #[derive(scale::Decode, scale::Encode, Debug, Clone)]
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
     #[cfg_attr(
         feature = "std",
         derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
     )]
-
     ///Este struct, se encargar de almacenar los costos monetarios relacionados al Club. Almacena:
     /// :> El costo para categoria (a, b y c), las cuales son un tipo de dato u128.
     /// :> La cantidad de pagos consecutivos para un descuento ofrecido por el Club (beneficio), el cual es de un tipo de
     ///    dato u128.
     /// :> El monto del beneficio, el cual es proporcionado cuándo el socio acumula la cantidad de pagos consecutivos
     ///    mencionados, el cual es de un tipo de dato u8. 
-    /// Incluye 5 metodos: new, set_costo, get_costo, set_beneficio, y set_pagos_consecutivos_para_beneficio.
+    /// Incluye 8 metodos: new, set_costo, get_costo, get_costo_minimo, get_beneficio, get_pagos_consecutivos_para_beneficio,
+    /// set_beneficio, y set_pagos_consecutivos_para_beneficio. Los setters rechazan los valores que dejarían un beneficio
+    /// mayor al costo de alguna categoria.
     pub struct CostosCategoria {
         a: u128,
         b: u128,
@@ -94,27 +44,32 @@ pub mod ClubSemRust {
         ///Recibe el valor del costo de la categoria a, b, y c en variables de tipo u128, luego recibe el
         ///valor a descontar una vez que sea bonificado y luego recibe la cantidad de pagos consecutivos que 
         ///deberian concretarse para aplicarle beneficio al proximo pago
-        fn new(val_a: u128, val_b: u128, val_c: u128, valor_beneficio: u128, pagos_consecutivos_para_beneficio: u8) -> CostosCategoria {
+        pub fn new(val_a: u128, val_b: u128, val_c: u128, valor_beneficio: u128, pagos_consecutivos_para_beneficio: u8) -> CostosCategoria {
            CostosCategoria { a: (val_a), b: (val_b), c: (val_c), beneficio: (valor_beneficio), pagos_consecutivos_para_beneficio} 
         }
 
         ///Recibe en el parametro categoria, una variable de tipo CategoriasSocios
         ///la cual usa para matchear con las categorias del struct CostosCategoria
         ///y actualizar el valor de la variable con el valor ingresado en el parametro
-        ///nuevo_valor
-        fn set_costo (&mut self, nuevo_valor: u128, categoria: &CategoriasSocios) -> bool {
+        ///nuevo_valor. Si nuevo_valor es menor al beneficio, no modifica nada y
+        ///retorna ErrorCostos::BeneficioSuperaCosto
+        fn set_costo (&mut self, nuevo_valor: u128, categoria: &CategoriasSocios) -> Result<(), ErrorCostos> {
+            if nuevo_valor < self.beneficio {
+                return Err(ErrorCostos::BeneficioSuperaCosto);
+            }
             match categoria {
-                CategoriasSocios::A => {self.a = nuevo_valor; return true},
-                CategoriasSocios::B => {self.b = nuevo_valor; return true},
-                CategoriasSocios::C => {self.c = nuevo_valor; return true},
+                CategoriasSocios::A => self.a = nuevo_valor,
+                CategoriasSocios::B => self.b = nuevo_valor,
+                CategoriasSocios::C => self.c = nuevo_valor,
             }
+            return Ok(());
         }
 
         ///Recibe por parametro una CategoriasSocios la cual usa para matcheaer con
         ///las variables del CostosCategoria, si CategoriasSocios es A retorna el contenido de
         ///la variable a, si es B, retorna el contenido de la variable b si es C retorna
         ///el contenido de la variable c
-        fn get_costo (&self, categoria: &CategoriasSocios) -> u128 {
+        pub fn get_costo (&self, categoria: &CategoriasSocios) -> u128 {
             match categoria {
                 CategoriasSocios::A => self.a,
                 CategoriasSocios::B => self.b,
@@ -122,11 +77,30 @@ pub mod ClubSemRust {
             }
         }
 
+        ///Retorna el menor de los costos de las tres categorias
+        pub fn get_costo_minimo (&self) -> u128 {
+            return self.a.min(self.b).min(self.c);
+        }
+
+        ///Retorna el contenido de la variable beneficio
+        pub fn get_beneficio (&self) -> u128 {
+            return self.beneficio;
+        }
+
+        ///Retorna el contenido de la variable pagos_consecutivos_para_beneficio
+        pub fn get_pagos_consecutivos_para_beneficio (&self) -> u8 {
+            return self.pagos_consecutivos_para_beneficio;
+        }
+
         ///Recibe el valor del nuevo beneficio por parametro y lo setea en la
-        ///variable beneficio
-        fn set_beneficio (&mut self, nuevo_benedificio: u128) -> bool {
+        ///variable beneficio. Si supera el costo de la categoria mas barata, no
+        ///modifica nada y retorna ErrorCostos::BeneficioSuperaCosto
+        fn set_beneficio (&mut self, nuevo_benedificio: u128) -> Result<(), ErrorCostos> {
+            if nuevo_benedificio > self.get_costo_minimo() {
+                return Err(ErrorCostos::BeneficioSuperaCosto);
+            }
             self.beneficio = nuevo_benedificio;
-            return true;
+            return Ok(());
         }
 
         ///Recibe el nuevo valor para los pagos consecutivos para obtener el beneficio
@@ -151,7 +125,8 @@ pub mod ClubSemRust {
     /// :> Si el pago fue bonificado, el cual es un tipo de dato bool, el cual si es false, indica que el pago no fue 
     ///    bonificado, y si fue bonificado, esta variable almacena un true.
     /// 
-    /// Cuenta con 6 implementaciones: new, get_dni_socio, get_monto, get_fecha_venci, get_fecha_pago, y set_fecha_pago. 
+    /// Cuenta con 7 implementaciones: new, get_dni_socio, get_monto, get_fecha_venci, get_fecha_pago, is_bonificado, y
+    /// set_fecha_pago. 
     pub struct Pago {
         dni: u32,
         monto: u128,
@@ -182,12 +157,39 @@ pub mod ClubSemRust {
         pub fn get_fecha_pago (&self) -> Option<u64> {
             return self.fecha_pago;
         }
+        ///Retorna el contenido de la variable bonificado
+        pub fn is_bonificado (&self) -> bool {
+            return self.bonificado;
+        }
         ///Setea el valor ingresado como parametro, en la variable fecha_pago
         pub fn set_fecha_pago (&mut self, fecha_pago: u64) {
             self.fecha_pago = Some(fecha_pago);
         }
     }
 
+    /// Implementacion del trait PagoFacturable del nucleo de facturación, para que sus reglas operen directamente sobre
+    /// los pagos del club.
+    impl PagoFacturable for Pago {
+        fn dni (&self) -> u32 {
+            return self.dni;
+        }
+        fn monto (&self) -> u128 {
+            return self.monto;
+        }
+        fn fecha_venci (&self) -> u64 {
+            return self.fecha_venci;
+        }
+        fn fecha_pago (&self) -> Option<u64> {
+            return self.fecha_pago;
+        }
+        fn bonificado (&self) -> bool {
+            return self.bonificado;
+        }
+        fn marcar_pagado (&mut self, fecha_pago: u64) {
+            self.set_fecha_pago(fecha_pago);
+        }
+    }
+
     /// Se trata de un Enum que contiene las variantes disponibles de categorías de socios, las cuales al momento son
     /// A, B o C. Cuenta con una implementacion:
     /// categoria_from_id.
@@ -276,12 +278,17 @@ pub mod ClubSemRust {
     /// :> La categoría elegida por el socio, la cual es una variante del Enum CategoriasSocios (categoria);
     /// :> El deporte elegido por el socio (en caso de haber elegido la variente B del Enum CategoriasSocios), el cual 
     ///    es un dato de tipo Option(Deporte) (deporte);
-    /// Cuenta con 5 implementaciones: new, get_appellido_y_nombre, get_dni, get_categoria, get_deporte;
+    /// :> Los compromisos (hashes con sal) de su identidad, en caso de haberse registrado con la protección de datos,
+    ///    el cual es un tipo de dato Option(CompromisoIdentidad) (compromiso). Si es Some(), el socio no guarda su
+    ///    apellido y nombre, y en el campo dni guarda el identificador derivado del compromiso del DNI;
+    /// Cuenta con 7 implementaciones: new, new_protegido, get_appellido_y_nombre, get_dni, get_categoria, get_deporte,
+    /// get_compromiso;
     pub struct Socio {
         apellido_y_nombre: String,
         dni: u32,
         categoria: CategoriasSocios,
         deporte: Option<Deporte>,
+        compromiso: Option<CompromisoIdentidad>,
     }
 
     impl Socio {
@@ -294,11 +301,21 @@ pub mod ClubSemRust {
         ///    que puede ser None o una variante del tipo de dato Deporte (deporte).
 		pub fn new (apellido_y_nombre: String, dni: u32, categoria: CategoriasSocios, deporte: Option<Deporte>) -> Socio {
             match categoria {
-                CategoriasSocios::B => Socio {dni, categoria, apellido_y_nombre, deporte},
-                   _ => Socio {dni, categoria, apellido_y_nombre, deporte: (None)},
+                CategoriasSocios::B => Socio {dni, categoria, apellido_y_nombre, deporte, compromiso: (None)},
+                   _ => Socio {dni, categoria, apellido_y_nombre, deporte: (None), compromiso: (None)},
             }
         }
 
+        /// Se encarga de crear un socio sin datos personales en texto plano a partir de los compromisos de su
+        /// identidad (compromiso), de tipo CompromisoIdentidad. El apellido y nombre queda vacío, y como DNI se guarda
+        /// el identificador recibido (identificador), que el club deriva del compromiso del DNI y es el que vincula al
+        /// socio con sus pagos. La categoria y el deporte se tratan de la misma forma que en new.
+        pub fn new_protegido (compromiso: CompromisoIdentidad, identificador: u32, categoria: CategoriasSocios, deporte: Option<Deporte>) -> Socio {
+            let mut socio = Socio::new(String::new(), identificador, categoria, deporte);
+            socio.compromiso = Some(compromiso);
+            return socio;
+        }
+
         /// Se encarga de clonar el contenido del campo apellido_y_nombre, y devolverlo, por lo que devuelve
         /// un tipo de dato String. Recibe una referencia a si mismo (Socio).
         pub fn get_apellido_y_nombre (&self) -> String {
@@ -323,750 +340,4914 @@ pub mod ClubSemRust {
         pub fn get_deporte (&self) -> Option<Deporte> {
             return self.deporte.clone();
         }
+
+        /// Indica si la categoría del socio incluye el deporte recibido: la A incluye todos, la B solo el deporte elegido
+        /// y la C ninguno.
+        pub fn incluye_deporte (&self, deporte: &Deporte) -> bool {
+            match self.categoria {
+                CategoriasSocios::A => true,
+                CategoriasSocios::B => self.deporte.as_ref() == Some(deporte),
+                CategoriasSocios::C => false,
+            }
+        }
+
+        /// Se encarga de clonar el contenido del campo compromiso, y devolverlo, por lo que devuelve un
+        /// Option(CompromisoIdentidad), que será None si el socio se registró con sus datos en texto plano.
+        /// Recibe una referencia a si mismo (Socio).
+        pub fn get_compromiso (&self) -> Option<CompromisoIdentidad> {
+            return self.compromiso.clone();
+        }
     }
 
-    /// Este struct contiene toda la información relevante al Club, por lo cual, es el storage del Club. Almacena:
-    /// :> Los costos referentes al club (costos), el cual es un tipo de dato CostosCategoria.
-    /// :> Los socios del club (socios), el cual es un tipo de dato Vec(Socio).
-    /// :> Los pagos efectuado por el Club (pagos), el cual es un tipo de dato Vec(Pago).
-    /// :> El owner del Club, y por lo tanto del contrato (owner), el cual es un tipo de dato AccountId.
-    /// :> Los permitidos, o staff's, para operar en el contrato en los metodos en que están permitidos operar (permitidos),
-    ///    el cual es un tipo de dato Vec(AccountId).
-    /// :> La politica de autorización (politica_activada), la cual indica que cualquiera pueda operar en el contrato en 
-    ///    los metodos que no se refieren a la administracion de costos y permitidos del mismo, de estar desactivada. 
-    ///    Es un tipo de dato bool, que si guarda false indica que la politica está desactivada, y cualquiera puede 
-    ///    operar en los metodos mencionados, en cambio si esta en true, no podrá acceder cualquiera, solo un staff o el
-    ///    propio owner.
-    /// :> La fecha de emisión del último pago mensual (emision_ultimo_pago), el cual es un tipo de dato Option(u64). 
-    ///    Este almacena None al momento de construir el club, ya que no hay pagos emitidos, y este guardará un Some() 
-    ///    cuando se registre el primer socio, guardando la fecha en la que se registro dicho socio.
-    /// 
-    /// Cuenta con distintos métodos que permiten: la creacion del club, la actualizacion de cualquier valor de sus 
-    /// costos, la verificacion del usuario que está llamando a los metodos, el registro de socios y staff's, cambio de
-    /// owner, obtención y consulta de los pagos, corrobaración para el beneficio, la emisión de pagos y el registro de
-    /// pagos, entre otros metodos.
-    #[ink(storage)]
-    pub struct ClubSemRust {
-        costos: CostosCategoria,
-        socios: Vec<Socio>,
-        pagos: Vec<Pago>,
-        owner: AccountId,
-        permitidos: Vec<AccountId>,
-        politica_activada: bool,
-        emision_ultimo_pago: Option<u64>,
+    /// Bit que distingue a los identificadores de los socios protegidos de los DNI en texto plano: todo identificador
+    /// derivado de un compromiso lo tiene encendido, y ningún DNI en texto plano puede tenerlo.
+    pub const MARCA_IDENTIFICADOR_PROTEGIDO: u32 = 1 << 31;
+
+    /// Este struct almacena los compromisos de la identidad de un socio registrado con la protección de datos.
+    /// Almacena:
+    /// :> El hash Blake2x256 de la codificacion SCALE del DNI junto a la sal (dni), el cual es un tipo de dato Hash.
+    /// :> El hash Blake2x256 de la codificacion SCALE del apellido y nombre junto a la sal (apellido_y_nombre), el
+    ///    cual es un tipo de dato Hash.
+    ///
+    /// La sal, de 32 bytes, la elige y la guarda el socio (o el club) fuera de la cadena, por lo que los compromisos
+    /// pueden calcularse fuera de la cadena con calcular_dni y calcular_apellido_y_nombre y luego presentarse al
+    /// contrato sin revelar los datos. Cuenta con 5 implementaciones: new, calcular_dni, calcular_apellido_y_nombre,
+    /// identificador y get_dni.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct CompromisoIdentidad {
+        dni: Hash,
+        apellido_y_nombre: Hash,
     }
 
-    impl ClubSemRust {
-        ///Recibe los 3 primeros costos de las categorias a,b y c en parametros de tip u128, recibe el valor del beneficio en un parametro de tipo u128
-        ///y recibe los pagos consecutivos para aplicar beneficio en un parametro de tipo u8, luego, crea el Vec de socios vacio, el vec de pagos vacio
-        ///la variable costos con todos los parametros ingresados previamente, setea al owner como el caller actual y crea el vec de permitidos vacio
-        #[ink(constructor)]
-        pub fn new(costo_a: u128, costo_b: u128, costo_c: u128, valor_beneficio: u128, pagos_consecutivos_para_beneficio: u8) -> Self {
-            return ClubSemRust::new_priv(costo_a, costo_b, costo_c, valor_beneficio, pagos_consecutivos_para_beneficio);
+    impl CompromisoIdentidad {
+        ///Recibe el compromiso del DNI y el del apellido y nombre, ambos de tipo Hash, y crea el CompromisoIdentidad
+        pub fn new (dni: Hash, apellido_y_nombre: Hash) -> CompromisoIdentidad {
+            CompromisoIdentidad {dni, apellido_y_nombre}
         }
 
-        fn new_priv(costo_a: u128, costo_b: u128, costo_c: u128, valor_beneficio: u128, pagos_consecutivos_para_beneficio: u8) -> Self {
-            let socios = Vec::new();
-            let pagos = Vec::new();
-            let costos = CostosCategoria::new(costo_a, costo_b, costo_c, valor_beneficio, pagos_consecutivos_para_beneficio);
-            let owner = ClubSemRust::env().caller();
-            let permitidos = Vec::new();
-            Self {socios, pagos, costos, owner, permitidos, emision_ultimo_pago: (None), politica_activada: (true)}
-        }
-        
-        ///La función get_socios devuelve la lista "cruda" de socios registrados que almacena el club. Se recibe el parametro self (ClubSemRust)
-        ///y se retorna una estructura Vec<Socio>. El metodo cumple con la politica de autorizacion verificando los permisos
-        ///necesarios, devolviendo un panic en caso de no contar con los mismos. En caso de no existir socios se devuelve un vector vacio.
-        #[ink(message)]
-        pub fn get_socios (&self) -> Vec<Socio> {
-            return self.get_socios_priv();
+        ///Calcula el compromiso de un DNI, aplicando Blake2x256 a la codificacion SCALE de la tupla (dni, sal)
+        pub fn calcular_dni (dni: u32, sal: &[u8; 32]) -> Hash {
+            return hash_blake2x256(&(dni, sal).encode());
         }
 
-        fn get_socios_priv (&self) -> Vec<Socio> {
-            if self.verificar_permisos() {
-                let mut vec_copia = Vec::new();
-                vec_copia.clone_from(&self.socios);
-                return vec_copia;
-            }
-            return panic!("No se cuenta con los permisos necesarios!");
+        ///Calcula el compromiso de un apellido y nombre, aplicando Blake2x256 a la codificacion SCALE de la tupla
+        ///(apellido_y_nombre, sal)
+        pub fn calcular_apellido_y_nombre (apellido_y_nombre: &str, sal: &[u8; 32]) -> Hash {
+            return hash_blake2x256(&(apellido_y_nombre, sal).encode());
         }
 
-	    /// Este método, permite realizar un cambio de Owner del Club, sólo si el que realiza el llamado a este método,
-        /// es el mismo Owner. Dicho cambio de owner, se realiza por el ingresado como parámetro (nuevo_owner). En caso
-        /// que cualquier otro usuario realice esta operación, este método arrojará un panic!, abortando la operación 
-        /// del método. El metodo recibe como parametros una referencia mutable de sí mismo (ClubSemRust), y el nuevo 
-        /// owner, que es un tipo de dato AccountId.
-        #[ink(message)]
-        pub fn set_owner(&mut self, nuevo_owner: AccountId) -> bool {
-            return self.set_owner_priv(nuevo_owner);
+        ///Devuelve el identificador numerico del socio, formado por los primeros 4 bytes (little endian) del
+        ///compromiso del DNI con el bit más alto encendido (MARCA_IDENTIFICADOR_PROTEGIDO), para que nunca coincida con
+        ///el DNI de un socio registrado en texto plano. Es el identificador preferido del socio: si ya lo usa otro socio,
+        ///el club le asigna el siguiente libre, por lo que el valor que se guarda como DNI en el Socio y en sus Pagos es
+        ///el que devuelve registrar_socio_protegido.
+        pub fn identificador (&self) -> u32 {
+            let bytes: &[u8] = self.dni.as_ref();
+            return u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) | MARCA_IDENTIFICADOR_PROTEGIDO;
         }
 
-        fn set_owner_priv(&mut self, nuevo_owner: AccountId) -> bool{
-            if self.es_admin() {
-                self.owner = nuevo_owner;
-                return true;
-            } 
-            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        ///Retorna el contenido de la variable dni
+        pub fn get_dni (&self) -> Hash {
+            return self.dni;
         }
+    }
 
-        ///El get politica de autorizacion primero comprueba que el caller sea admin o staff y luego
-        ///retorna el valor de la variable politica_activada, de no ser admin o staff arrojara un panic
-        #[ink(message)]
-        pub fn get_politica_autorizacion(&self) ->bool{
-            return self.get_politica_autorizacion_priv();
-        }
+    /// Se trata de un Enum que contiene los tipos de acciones administrativas que quedan registradas en la auditoria
+    /// del club.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum AccionAdministrativa {
+        CambioOwner,
+        TogglePolitica,
+        ToggleProteccionDatos,
+        AgregarStaff,
+        QuitarStaff,
+        CambioCostoCategoria,
+        CambioBeneficio,
+        CambioPagosConsecutivos,
+        CambioVentanaReversion,
+        CambioRetencionAuditoria,
+        RegistroSocio,
+        AgregarGuardian,
+        QuitarGuardian,
+        Pausa,
+        Reanudacion,
+        ImportacionEstado,
+        AgregarLector,
+        QuitarLector,
+        CambioTokenPago,
+        CambioPuntosCategoria,
+        CambioVigenciaPuntos,
+        CambioValorPunto,
+        BajaSocio,
+        CreacionPlanCuotas,
+        CambioDescuentoPlan,
+        CambioPoliticaMorosidad,
+        RevocacionLibreDeuda,
+        CambioVigenciaCertificados,
+        CreacionClase,
+        AgregarProfesor,
+        QuitarProfesor,
+        AgregarDispositivo,
+        QuitarDispositivo,
+        CreacionInstalacion,
+        CambioPlazoCancelacion,
+        CaidaPlanCuotas,
+    }
+
+    /// Se trata de un Enum que representa los valores anteriores y nuevos que se guardan en una entrada de auditoria,
+    /// segun el tipo de dato que se modificó: un monto, una cantidad, una cuenta, un booleano, un DNI, el costo o los
+    /// puntos de una categoría en particular, o los umbrales de la politica de morosidad.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum ValorAuditado {
+        Monto(u128),
+        Cantidad(u64),
+        Cuenta(AccountId),
+        Booleano(bool),
+        Dni(u32),
+        CostoCategoria(CategoriasSocios, u128),
+        PuntosCategoria(CategoriasSocios, u32),
+        PoliticaMorosidad(PoliticaMorosidad),
+    }
 
+    /// Este struct almacena una entrada de la auditoria de acciones administrativas. Almacena:
+    /// :> El numero de la entrada (numero), el cual es un tipo de dato u64, correlativo desde la primera entrada.
+    /// :> La accion realizada (accion), la cual es una variante del Enum AccionAdministrativa.
+    /// :> La cuenta que realizó la accion (actor), el cual es un tipo de dato AccountId.
+    /// :> La fecha de la accion (fecha), el cual es un tipo de dato u64.
+    /// :> El numero de bloque en el que se realizó (bloque), el cual es un tipo de dato u32.
+    /// :> El valor anterior y el nuevo (valor_anterior y valor_nuevo), los cuales son un tipo de dato
+    ///    Option(ValorAuditado), que son None cuando la accion no tiene un valor previo o posterior (por ejemplo, al
+    ///    agregar o quitar un staff).
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct EntradaAuditoria {
+        numero: u64,
+        accion: AccionAdministrativa,
+        actor: AccountId,
+        fecha: u64,
+        bloque: u32,
+        valor_anterior: Option<ValorAuditado>,
+        valor_nuevo: Option<ValorAuditado>,
+    }
 
-        fn get_politica_autorizacion_priv(&self) -> bool {
-            if ((self.es_admin()) || (self.es_staff())) {
-                return self.politica_activada;
-            } 
-            return panic!("No se cuenta con los permisos necesarios!");
-        }
+    /// Se trata de un Enum que contiene los motivos por los que puede fallar un elemento de una operación en lote. A
+    /// diferencia de las operaciones individuales, que arrojan un panic, las operaciones en lote informan el resultado
+    /// de cada elemento por separado.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ErrorLote {
+        CategoriaInvalida,
+        DeporteInvalido,
+        SocioExistente,
+        DniRepetidoEnLote,
+        SocioInexistente,
+        SinPagoPendiente,
+        LoteCancelado,
+        DniInvalido,
+    }
 
-        ///La función toggle_politica_autorizacion activa y desactiva la política de autorización en la administración
-        ///del ClubSemRust. Se aplica la operación logica NOT sobre el valor del booleano correspondiente a la política
-        ///de autorización del club. Se recibe la referencia mutable del ClubSemRust y se retorna el nuevo estado de la 
-        ///política. Es importante notar que solo el Adress con característica de Owner puede ejecutar exitosamente el 
-        ///método, de no contar con los permisos requeridos se lanza un panic! notando el error.
-        #[ink(message)]
-        pub fn toggle_politica_autorizacion(&mut self) -> bool {
-            return self.toggle_politica_autorizacion_priv();
+    /// Se trata de un Enum con los errores de los setters de CostosCategoria: el valor ingresado dejaría un beneficio
+    /// mayor al costo de alguna categoria, lo que haría negativa su cuota bonificada.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ErrorCostos {
+        BeneficioSuperaCosto,
+    }
+
+    /// Se trata de un Enum con los errores que define el estándar PSP22, tal como los devuelve el contrato del token en
+    /// transfer_from. Se replica aquí para poder decodificar su respuesta.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        Custom(String),
+        InsufficientBalance,
+        InsufficientAllowance,
+    }
+
+    /// Se trata de un Enum con los motivos por los que puede fallar el cobro de un pago en token: el socio no tiene
+    /// saldo suficiente (SaldoInsuficiente), no autorizó al club a debitar el monto (AutorizacionInsuficiente), el token
+    /// rechazó la transferencia por otro motivo (RechazoDelToken), o la llamada al contrato del token no pudo
+    /// completarse (LlamadaFallida). En todos los casos el pago sigue pendiente.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ErrorPagoToken {
+        SaldoInsuficiente,
+        AutorizacionInsuficiente,
+        RechazoDelToken(String),
+        LlamadaFallida,
+    }
+
+    /// Version del formato de Instantanea que exporta e importa esta version del contrato.
+    pub const VERSION_INSTANTANEA: u16 = 14;
+
+    /// Este struct contiene una copia del estado del club, que se exporta codificada en SCALE para respaldos y
+    /// migraciones a un nuevo despliegue. Almacena la version del formato (version) y los costos, socios, pagos,
+    /// owner, staff (permitidos), configuración, recibos, movimientos, guardianes, lectores y token de pago del club,
+    /// junto con los puntos de fidelidad (configuración, lotes, historial y canjes pendientes), los grupos familiares,
+    /// los carnets de socio, los planes de cuotas, cuyos pagos refinanciados se guardan como posiciones en pagos, los
+    /// planes de facturación con sus descuentos, la política de morosidad, los asientos de la cuenta corriente y los
+    /// certificados de libre deuda con su vigencia, los aptos médicos, las clases, los profesores, las
+    /// asistencias, los dispositivos de acceso, las instalaciones y sus reservas con el plazo de cancelación, y los
+    /// reintegros pendientes de los socios.
+    /// No incluye la auditoria, que pertenece a cada despliegue, ni el estado de pausa.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Instantanea {
+        pub version: u16,
+        pub costos: CostosCategoria,
+        pub socios: Vec<Socio>,
+        pub pagos: Vec<Pago>,
+        pub owner: AccountId,
+        pub permitidos: Vec<AccountId>,
+        pub politica_activada: bool,
+        pub emision_ultimo_pago: Option<u64>,
+        pub proteccion_datos: bool,
+        pub recibos: Vec<Recibo>,
+        pub ultimo_nro_recibo: u32,
+        pub movimientos: Vec<MovimientoPago>,
+        pub ventana_reversion: u64,
+        pub retencion_auditoria: u32,
+        pub guardianes: Vec<AccountId>,
+        pub lectores: Vec<AccountId>,
+        pub token_pago: Option<AccountId>,
+        pub puntos_categoria: PuntosCategoria,
+        pub vigencia_puntos: u64,
+        pub valor_punto: u128,
+        pub lotes_puntos: Vec<LotePuntos>,
+        pub movimientos_puntos: Vec<MovimientoPuntos>,
+        pub canjes_pendientes: Vec<(u32, u128)>,
+        pub grupos_familiares: Vec<(u32, u32)>,
+        pub carnets: Vec<Carnet>,
+        pub planes_cuotas: Vec<PlanCuotas>,
+        pub descuentos_plan: DescuentosPlan,
+        pub facturacion_socios: Vec<FacturacionSocio>,
+        pub politica_morosidad: PoliticaMorosidad,
+        pub asientos: Vec<AsientoCuenta>,
+        pub certificados: Vec<CertificadoLibreDeuda>,
+        pub vigencia_certificados: u64,
+        pub aptos_medicos: Vec<AptoMedico>,
+        pub clases: Vec<Clase>,
+        pub profesores: Vec<Profesor>,
+        pub asistencias: Vec<Asistencia>,
+        pub dispositivos_acceso: Vec<AccountId>,
+        pub instalaciones: Vec<Instalacion>,
+        pub reservas: Vec<Reserva>,
+        pub plazo_cancelacion: u64,
+        pub reintegros_pendientes: Vec<(u32, u128)>,
+    }
+
+    /// Este struct describe una Instantanea exportada, y es lo que se presenta al iniciar su importación para poder
+    /// verificar su integridad. Almacena la version del formato (version), la cantidad total de bytes de la
+    /// instantanea codificada (total_bytes), el tamaño de cada chunk (tamanio_chunk) y la cantidad de chunks
+    /// (cantidad_chunks), el hash Blake2x256 de los bytes (hash) y la cantidad de socios, pagos y recibos que contiene.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct MetadatosInstantanea {
+        pub version: u16,
+        pub total_bytes: u32,
+        pub tamanio_chunk: u32,
+        pub cantidad_chunks: u32,
+        pub hash: Hash,
+        pub cantidad_socios: u32,
+        pub cantidad_pagos: u32,
+        pub cantidad_recibos: u32,
+    }
+
+    /// Este struct almacena una importación en curso: los metadatos presentados al iniciarla (metadatos), los bytes
+    /// recibidos hasta el momento (bytes) y el indice del próximo chunk esperado (proximo_chunk).
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ImportacionEnCurso {
+        metadatos: MetadatosInstantanea,
+        bytes: Vec<u8>,
+        proximo_chunk: u32,
+    }
+
+    /// Aplica Blake2x256 a los bytes recibidos (entrada) y devuelve el resultado como un Hash. Es la funcion de hash
+    /// que usa el contrato para los compromisos de identidad y para el contenido de los recibos.
+    fn hash_blake2x256 (entrada: &[u8]) -> Hash {
+        let mut salida = [0u8; 32];
+        ink::env::hash_bytes::<ink::env::hash::Blake2x256>(entrada, &mut salida);
+        return Hash::from(salida);
+    }
+
+    /// Este struct almacena el comprobante que se emite cada vez que se registra un pago. Almacena:
+    /// :> El numero de recibo (numero), el cual es un tipo de dato u32. Es correlativo y nunca se reutiliza.
+    /// :> El DNI del socio que realizó el pago (dni), el cual es un tipo de dato u32.
+    /// :> El monto abonado (monto), el cual es un tipo de dato u128.
+    /// :> El periodo que se abonó (periodo), el cual es un tipo de dato u64 y corresponde a la fecha de vencimiento
+    ///    del pago saldado.
+    /// :> La fecha en la que se realizó el pago (fecha_pago), el cual es un tipo de dato u64.
+    /// :> La cuenta que registró el cobro (cobrado_por), el cual es un tipo de dato AccountId.
+    /// :> El hash del contenido del recibo (hash), el cual es un tipo de dato Hash.
+    /// :> Si el recibo fue anulado por la reversión de su pago (anulado), el cual es un tipo de dato bool. No forma
+    ///    parte del contenido sobre el que se calcula el hash.
+    /// :> Lo que se cobró con el recibo (origen), el cual es una variante del Enum OrigenRecibo. Tampoco forma parte
+    ///    del contenido sobre el que se calcula el hash.
+    ///
+    /// Cuenta con 5 implementaciones: new, calcular_hash, get_numero, is_anulado y get_origen.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Recibo {
+        numero: u32,
+        dni: u32,
+        monto: u128,
+        periodo: u64,
+        fecha_pago: u64,
+        cobrado_por: AccountId,
+        hash: Hash,
+        anulado: bool,
+        origen: OrigenRecibo,
+    }
+
+    impl Recibo {
+        ///Recibe el numero de recibo, el dni del socio, el monto, el periodo (fecha de vencimiento del pago), la fecha
+        ///de pago, la cuenta que registró el cobro y lo que se cobró, y crea el recibo calculando el hash de su contenido
+        pub fn new (numero: u32, dni: u32, monto: u128, periodo: u64, fecha_pago: u64, cobrado_por: AccountId, origen: OrigenRecibo) -> Recibo {
+            let hash = Recibo::calcular_hash(numero, dni, monto, periodo, fecha_pago, cobrado_por);
+            Recibo {numero, dni, monto, periodo, fecha_pago, cobrado_por, hash, anulado: (false), origen}
         }
 
-        fn toggle_politica_autorizacion_priv(&mut self) -> bool {
-            if self.es_admin() {
-                self.politica_activada = !self.politica_activada;
-                return self.politica_activada;
-            }
-            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        ///Calcula el hash del contenido de un recibo, aplicando Blake2x256 a la codificacion SCALE de la tupla
+        ///(numero, dni, monto, periodo, fecha_pago, cobrado_por)
+        pub fn calcular_hash (numero: u32, dni: u32, monto: u128, periodo: u64, fecha_pago: u64, cobrado_por: AccountId) -> Hash {
+            return hash_blake2x256(&(numero, dni, monto, periodo, fecha_pago, cobrado_por).encode());
         }
 
-	    /// Este método, permite agregar un usuario permitido para operar, que lo definimos como "staff", solo si el
-        /// método es llamado por el Owner, y si dicho staff no está cargado. De esta forma, si se cumplen las 
-        /// condiciones descriptas anteriormente, el staff será agregado exitosamente, devolviendo un "true" como 
-        /// demostracion. De lo contrario, si alguna de ellas no se cumple, el método arrojará un panic! con un mensaje
-        /// informando la situacion. El método recibe como párametro una referencia mutable de sí mismo (ClubSemRust), y 
-        /// el nuevo staff (nuevo_staff), que es un tipo de dato AccountId.
-        #[ink(message)]
-        pub fn agregar_staff(&mut self, nuevo_staff: AccountId) -> bool {
-            return self.agregar_staff_priv(nuevo_staff);
+        ///Retorna el contenido de la variable numero
+        pub fn get_numero (&self) -> u32 {
+            return self.numero;
         }
 
-        fn agregar_staff_priv(&mut self, nuevo_staff: AccountId) -> bool {
-            if (self.es_admin()) && (!self.permitidos.contains(&nuevo_staff)) {
-                self.permitidos.push(nuevo_staff);
-                return true;
-            }
-            return panic!("No cuenta con rango Owner o la cuenta ingresada ya es Staff!");
+        ///Retorna el contenido de la variable anulado
+        pub fn is_anulado (&self) -> bool {
+            return self.anulado;
         }
 
-       ///Primero se chequea que el caller sea el owner y que el accountId que se quiere quitar del staff
-       ///exista en el vec de permitidos. Luego se obtiene la posicion del accountId que queremos quitar del 
-       ///vec de permitidos y finalmente se remueve la posicion en la cual se encontro el account id
-       ///de no ser owner o no encontrarse el accoundId, se arrojara un panick 
-        #[ink(message)]
-        pub fn quitar_staff(&mut self, quitar: AccountId) -> bool {
-            return self.quitar_staff_priv(quitar);
+        ///Retorna el contenido de la variable origen
+        pub fn get_origen (&self) -> OrigenRecibo {
+            return self.origen.clone();
         }
+    }
 
-        fn quitar_staff_priv(&mut self, quitar: AccountId) -> bool {
-            if (self.es_admin()) && (self.permitidos.contains(&quitar)) {
-                let pos = self.permitidos.iter().position(|staff| staff == &quitar).unwrap();
-                self.permitidos.remove(pos);
-                return true;
-            }
-            return panic!("No se cuenta con rango Owner o la cuenta ingresada no es Staff!");
+    /// Se trata de un Enum con lo que se cobró con un recibo: un pago del Vec de pagos del club, una cuota de un plan
+    /// de cuotas, identificada por el numero de plan y el numero de cuota, o el arancel de una reserva cobrado en el
+    /// momento, identificada por su numero. Es lo que permite revertir el cobro.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum OrigenRecibo {
+        Pago,
+        CuotaPlan(u32, u32),
+        Reserva(u32),
+    }
+
+    /// Este struct almacena un certificado de libre deuda, que acredita que un socio no tenía pagos pendientes al
+    /// momento de su emisión. Almacena:
+    /// :> El numero de certificado (id), el cual es un tipo de dato u32. Es correlativo y nunca se reutiliza.
+    /// :> El hash del DNI del socio (hash_dni), el cual es un tipo de dato Hash. Si el socio se registró con la
+    ///    protección de datos es el compromiso de su DNI; si no, el compromiso del DNI con la sal del certificado, que
+    ///    no se guarda en el contrato.
+    /// :> La fecha de emisión (emitido) y la fecha hasta la que es valido (valido_hasta), las cuales son un tipo de
+    ///    dato u64.
+    /// :> El hash del contenido del certificado (hash), el cual es un tipo de dato Hash.
+    /// :> Si el certificado fue revocado (revocado), el cual es un tipo de dato bool. No forma parte del contenido
+    ///    sobre el que se calcula el hash.
+    ///
+    /// Cuenta con 4 implementaciones: calcular_hash_dni, calcular_hash, get_id e is_revocado.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct CertificadoLibreDeuda {
+        id: u32,
+        hash_dni: Hash,
+        emitido: u64,
+        valido_hasta: u64,
+        hash: Hash,
+        revocado: bool,
+    }
+
+    impl CertificadoLibreDeuda {
+        ///Calcula el hash del DNI de un socio registrado sin la protección de datos como el compromiso de su DNI con la
+        ///sal del certificado (CompromisoIdentidad::calcular_dni), para que quien recibe el DNI y la sal pueda comprobar a
+        ///quien pertenece, sin que el DNI pueda obtenerse probando todos los valores posibles
+        pub fn calcular_hash_dni (dni: u32, sal: &[u8; 32]) -> Hash {
+            return CompromisoIdentidad::calcular_dni(dni, sal);
         }
 
-        fn es_admin(&self)-> bool{
-            return self.env().caller() == self.owner;
+        ///Calcula el hash del contenido de un certificado, aplicando Blake2x256 a la codificacion SCALE de la tupla
+        ///(id, hash_dni, emitido, valido_hasta)
+        pub fn calcular_hash (id: u32, hash_dni: Hash, emitido: u64, valido_hasta: u64) -> Hash {
+            return hash_blake2x256(&(id, hash_dni, emitido, valido_hasta).encode());
         }
 
-	    /// Este método, permite realizar comprobaciones internas en el programa, verificando si el usuario que está
-        /// llamando es reconocible como staff. De esta forma, si es reconocido como staff, devuelve "true", o "false"
-        /// de lo contrario. El método recibe como párametro una referencia a si mismo (ClubSemRust).
-        fn es_staff(&self) -> bool {
-            return self.permitidos.contains(&self.env().caller());     
+        ///Retorna el contenido de la variable id
+        pub fn get_id (&self) -> u32 {
+            return self.id;
         }
 
-       /// Para empezar se chequea que el caller sea admin o staff, luego, con los parametros recibidos(el id de categoria y el nuevo costo)
-       /// se llama a la funcion set_costo de la variable costos del club, actualizando asi, el nuevo costo de una categoria en especifico
-       /// recibida por parametro. para comprobar que la categoria sea una permitida se llama al metodo categoria_from_id
-       /// la cual arrojara un panic si el id ingresado no es o 1 o 2 o 3. Por otra parte, si el caller no es admin o staff se arrojara un panic
-        #[ink(message)] 
-        pub fn actualizar_costos_categoria(&mut self, id_categoria : u32, nuevo_costo : u128,) -> bool {
-            return self.actualizar_costos_categoria_priv(id_categoria, nuevo_costo);
+        ///Retorna el contenido de la variable revocado
+        pub fn is_revocado (&self) -> bool {
+            return self.revocado;
         }
+    }
 
-        pub fn actualizar_costos_categoria_priv(&mut self, id_categoria: u32, nuevo_costo: u128) -> bool {
-            if (self.es_admin()) || (self.es_staff()) {
+    /// Este struct almacena el apto médico vigente de un socio, que el seguro exige para practicar deportes. Almacena:
+    /// :> El DNI del socio (dni), el cual es un tipo de dato u32.
+    /// :> La fecha de emisión (emitido) y la de vencimiento (vence) del apto, las cuales son un tipo de dato u64.
+    /// :> El hash del documento que respalda el apto (documento), el cual es un tipo de dato Option(Hash), ya que es
+    ///    opcional.
+    ///
+    /// Cuenta con 4 implementaciones: get_dni, get_emitido, get_vence y esta_vigente.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct AptoMedico {
+        dni: u32,
+        emitido: u64,
+        vence: u64,
+        documento: Option<Hash>,
+    }
 
-                if let Some(categoria) = CategoriasSocios::categoria_from_id(&id_categoria){
-                    return self.costos.set_costo(nuevo_costo, &categoria);
-                }
-            }
-            return panic!("No se cuenta con los permisos necesarios!");
+    impl AptoMedico {
+        ///Retorna el contenido de la variable dni
+        pub fn get_dni (&self) -> u32 {
+            return self.dni;
         }
-
-	    /// Este metodo, se encarga de verificar que se cumpla alguno de los 3 permisos para realizar una acción: que el
-        /// usuario que llama al metodo sea owner, sea staff, o que la politica de autorizacion este desactivada. De 
-        /// esta manera, realiza una verificacion entre las 3 condiciones: con que una condicion de ellas resultase 
-        /// verdadera, la expresión resulta ser verdadera y devuelve "true", en cambio, si ninguna devuelve "true", la 
-        /// expresión devolverá "false".
-        /// 
-        /// Este método recibe una referencia a si mismo (ClubSemRust), y devuelve un tipo de dato bool.
-        #[ink(message)]
-        pub fn verificar_permisos(&self) -> bool {
-            return self.verificar_permisos_priv();
+        ///Retorna el contenido de la variable emitido
+        pub fn get_emitido (&self) -> u64 {
+            return self.emitido;
         }
-
-        fn verificar_permisos_priv(&self) -> bool {
-              return (self.es_admin()) || (self.es_staff()) || (!self.politica_activada);
+        ///Retorna el contenido de la variable vence
+        pub fn get_vence (&self) -> u64 {
+            return self.vence;
         }
-
-        ///Este método altera el valor actual del descuento aplicable para aquellos socios que cumplan las condiciones
-        ///necesarias, recibiendo el nuevo importe y actualizando el importe del beneficio que el club ofrece. Esta función
-        ///únicamente puede ser ejecutada por el usuario administrador o los integrantes del staff, cualquier otro usuario
-        ///encontrará denegado el intento de actualización.
-        #[ink(message)]
-        pub fn actualizar_costo_beneficio(&mut self, nuevo_costo : u128) -> bool {
-            return self.actualizar_costo_beneficio_priv (nuevo_costo);
+        ///Retorna true si el apto ya fue emitido y todavía no venció en la fecha recibida
+        pub fn esta_vigente (&self, fecha: u64) -> bool {
+            return (self.emitido <= fecha) && (fecha < self.vence);
         }
+    }
 
-        fn actualizar_costo_beneficio_priv(&mut self, nuevo_costo : u128) -> bool {
-            if (self.es_admin()) || (self.es_staff()) {
-                return self.costos.set_beneficio(nuevo_costo);
-            }
+    /// Se trata de un Enum con los dias de la semana en que puede dictarse una clase. Cuenta con una implementacion:
+    /// dia_from_id.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum DiaSemana {
+        Lunes,
+        Martes,
+        Miercoles,
+        Jueves,
+        Viernes,
+        Sabado,
+        Domingo,
+    }
 
-            return panic!("No se cuenta con los permisos necesarios!");
+    impl DiaSemana {
+        /// Obtiene el dia correspondiente al ID recibido, del 1 (lunes) al 7 (domingo). Con cualquier otro ID arroja un
+        /// panic informando que el ID no es valido.
+        pub fn dia_from_id(id: &u32) -> Option<DiaSemana> {
+            match id {
+                1 => Some(DiaSemana::Lunes),
+                2 => Some(DiaSemana::Martes),
+                3 => Some(DiaSemana::Miercoles),
+                4 => Some(DiaSemana::Jueves),
+                5 => Some(DiaSemana::Viernes),
+                6 => Some(DiaSemana::Sabado),
+                7 => Some(DiaSemana::Domingo),
+                _ => panic!("El id de dia de la semana ingresado no es valido!"),
+            }
         }
+    }
 
-	    /// Este método permite modificar la cantidad de pagos consecutivos necesarios para acceder a un beneficio. 
-        /// Dicha modificación, será posible solamente si el usuario que realiza el llamado es Owner o Staff. De 
-        /// esta forma, si el usuario cumple con uno de estos criterios, se realizará la actualizacion, y se devolverá
-        /// un "true" como muestra de ello. De lo contrario, arrojará un panic! con un mensaje informando la situación.
-        /// El metodo recibe como parametros una referencia mutable de sí mismo (ClubSemRust), y la nueva cantidad de
-        /// pagos consecutivos necesarios para acceder al descuento (nuevo_valor), que es un tipo de dato u8.
-        #[ink(message)]
-        pub fn actualizar_pagos_consecutivos_para_descuento(&mut self, nuevo_valor: u8) -> bool {
-            return self.actualizar_pagos_consecutivos_para_descuento_priv (nuevo_valor);
-        }
+    /// Este struct almacena un horario semanal de una clase: el dia de la semana (dia), y el minuto del dia en que
+    /// empieza (inicio) y en que termina (fin), los cuales son un tipo de dato u32 entre 0 y 1440.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct HorarioClase {
+        dia: DiaSemana,
+        inicio: u32,
+        fin: u32,
+    }
 
-        fn actualizar_pagos_consecutivos_para_descuento_priv(&mut self, nuevo_valor: u8) -> bool {
-            if (self.es_admin()) || (self.es_staff()) {
-                return self.costos.set_pagos_consecutivos_para_beneficio(nuevo_valor);
+    impl HorarioClase {
+        ///Recibe el id del dia de la semana y los minutos de inicio y fin. Arroja un panic si el dia no es valido o si el
+        ///horario no termina después de empezar dentro del mismo dia.
+        pub fn new (id_dia: u32, inicio: u32, fin: u32) -> HorarioClase {
+            let Some (dia) = DiaSemana::dia_from_id(&id_dia) else {
+                return panic!("El id de dia de la semana ingresado no es valido!");
+            };
+            if (inicio >= fin) || (fin > 1440) {
+                return panic!("El horario de la clase no es valido!");
             }
-
-            return panic!("No se cuenta con los permisos necesarios!");
-        }
-
-        ///Se comprueba que el caller sea admin o staff o que la politica de autorizacion este desactivada, luego
-        ///se busca al socio en el vec de socios con un iter, y si el metodo find retorna un some, devuelve un clone
-        ///del socio encontrado, sino retorna un None. Por otra parte, si el caller no es admin o staff y la politica esta activada
-        ///el metodo arrojara un panick
-        #[ink(message)]
-        pub fn get_socio (&self, dni: u32) -> Option<Socio> {
-            return self.get_socio_priv(dni);
+            HorarioClase {dia, inicio, fin}
         }
+    }
 
-        fn get_socio_priv (&self, dni: u32) -> Option<Socio> {
+    /// Este struct almacena una clase de un deporte. Almacena:
+    /// :> El numero de la clase (id), el cual es un tipo de dato u32.
+    /// :> El deporte de la clase (deporte), el cual es un tipo de dato Deporte.
+    /// :> La cuenta del profesor que la dicta (profesor), la cual es un tipo de dato AccountId.
+    /// :> Los horarios semanales en que se dicta (horarios), el cual es un tipo de dato Vec(HorarioClase).
+    /// :> La cantidad máxima de inscriptos (cupo), el cual es un tipo de dato u32.
+    /// :> Los DNI de los socios inscriptos (inscriptos) y los de la lista de espera, en orden de llegada (espera), los
+    ///    cuales son un tipo de dato Vec(u32).
+    ///
+    /// Cuenta con 6 implementaciones: get_id, get_deporte, get_profesor, get_cupo, get_inscriptos y get_espera.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Clase {
+        id: u32,
+        deporte: Deporte,
+        profesor: AccountId,
+        horarios: Vec<HorarioClase>,
+        cupo: u32,
+        inscriptos: Vec<u32>,
+        espera: Vec<u32>,
+    }
 
-            if self.verificar_permisos() {
-                if let Some(socio) = self.socios.iter().find(|socio| socio.dni == dni) {
-                    return Some (socio.clone());
-                }
-                else {
-                    return None;
-                }
+    impl Clase {
+        ///Retorna el contenido de la variable id
+        pub fn get_id (&self) -> u32 {
+            return self.id;
+        }
+        ///Retorna el contenido de la variable deporte
+        pub fn get_deporte (&self) -> Deporte {
+            return self.deporte.clone();
+        }
+        ///Retorna el contenido de la variable profesor
+        pub fn get_profesor (&self) -> AccountId {
+            return self.profesor;
+        }
+        ///Retorna el contenido de la variable cupo
+        pub fn get_cupo (&self) -> u32 {
+            return self.cupo;
+        }
+        ///Retorna el contenido de la variable inscriptos
+        pub fn get_inscriptos (&self) -> Vec<u32> {
+            return self.inscriptos.clone();
+        }
+        ///Retorna el contenido de la variable espera
+        pub fn get_espera (&self) -> Vec<u32> {
+            return self.espera.clone();
+        }
+        ///Mientras haya lugar y socios esperando, pasa a los primeros de la lista de espera a inscriptos
+        fn promover_espera (&mut self) {
+            while (self.inscriptos.len() < self.cupo as usize) && !self.espera.is_empty() {
+                let dni = self.espera.remove(0);
+                self.inscriptos.push(dni);
             }
-            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
         }
+    }
 
-        ///El método existe_socio simplifica la verificación de la existencia de un socio, recibiendo el dni del 
-        ///socio a verificar y enmascarando el método de get_socio retorna el booleano correspondiente a la 
-        ///afirmación de la firma.
-        fn existe_socio(&self, dni: u32) -> bool {
-            if let Some (_) = self.get_socio(dni) {
-                true
-            }
-            else {
-                false
-            }
+    /// Este struct almacena un profesor o instructor del club. Almacena la cuenta con la que opera (cuenta), su nombre
+    /// (nombre), los deportes que enseña (deportes) y si está activo (activo). Los profesores inactivos se conservan
+    /// para el historial de las clases que dictaron. Cuenta con 4 implementaciones: get_cuenta, get_nombre, get_deportes
+    /// e is_activo.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Profesor {
+        cuenta: AccountId,
+        nombre: String,
+        deportes: Vec<Deporte>,
+        activo: bool,
+    }
+
+    impl Profesor {
+        ///Retorna el contenido de la variable cuenta
+        pub fn get_cuenta (&self) -> AccountId {
+            return self.cuenta;
         }
-        
-	    /// Este método, se encarga de realizar un resumen de los pagos de un socio, si se ingresa su DNI, de lo 
-        /// contrario mostrará un resumen de todos los pagos de todos los socios. De esta forma, si el usuario que llama
-        /// al método cumple con los permisos necesarios, se realizará, la verificación de si se ha ingresado un DNI o
-        /// no, de lo contrario, el método arrojará un panic informando lo sucedido.
-        /// 
-        /// De haber ingresado un DNI, se obtiene el socio mediante su DNI, y se recorren todos los pagos (ya sea 
-        /// un pago realizado o un pago pendiente), buscando todos aquellos pagos que contengan el DNI del socio, y se 
-        /// extrae de ellos su monto y se agregan a un Vec. Una vez obtenidos todos los pagos de un socio, para devolver
-        /// lo pedido, se crea una tupla de 2 elementos, la cual contiene como primer elemento al Socio con toda su info,
-        /// y como segundo elemento al Vec obtenido anteriormente, con todos los pagos de dicho socio. Como el método
-        /// esta planteado de forma que se pueda emitir una consulta por un socio en especial, o por todos los socios,
-        /// este método devuelve un Vec de tuplas, donde cada tupla es igual a la descripta anteriormente, por lo tanto, 
-        /// esta tupla obtenida se la inserta en un Vec, siendo este último, el tipo de dato que devuelve el metodo: 
-        /// Vec((Socio, Vec(u128))). De no encontrar a un socio con el DNI ingresado, el método arrojará un panic 
-        /// infromando lo sucedido.
-        /// 
-        /// Si se detecta que no se ha ingresado un DNI, se hace lo mencionado anteriormente para 
-        /// un DNI ingresado, pero a cada socio, se le extrae el DNI para detectar sus pagos, y agregar el monto al Vec de 
-        /// montos. Cuando se termina de procesar a un socio, se crea su tupla y se agrega al Vec de tuplas.
-        /// 
-        /// Este método, recibe una referencia a sí mismo (ClubSemRust), un tipo de dato Option de u32 (option_dni), y como destacamos 
-        /// anteriormente, devuelve un tipo de dato Vec, que contiene tuplas, donde cada tupla, contiene un socio y otro
-        /// Vec que contiene los montos: Vec((Socio, Vec(u128))).
-        #[ink(message)]
-        pub fn consulta_de_pagos(&self, option_dni: Option<u32>) -> Vec<(Socio, Vec<u128>)> { 
-            return self.consulta_de_pagos_priv(option_dni);
+        ///Retorna el contenido de la variable nombre
+        pub fn get_nombre (&self) -> String {
+            return self.nombre.clone();
         }
+        ///Retorna el contenido de la variable deportes
+        pub fn get_deportes (&self) -> Vec<Deporte> {
+            return self.deportes.clone();
+        }
+        ///Retorna el contenido de la variable activo
+        pub fn is_activo (&self) -> bool {
+            return self.activo;
+        }
+    }
 
-        fn consulta_de_pagos_priv(&self, option_dni: Option<u32>) -> Vec<(Socio, Vec<u128>)> {
-            if self.verificar_permisos() {
-
-                if let Some (dni) = option_dni {
-                    if let Some (socio) = self.get_socio(dni) {
-                        let mut vec_tuplas = Vec::new();
-                        let mut vec_pagos = Vec::new();
-                        for pago in &self.pagos {
-                            if pago.dni == socio.dni {
-                                vec_pagos.push(pago.monto);
-                            }
-                        }
-                        let tupla = (socio.clone(), vec_pagos);
-                        vec_tuplas.push(tupla);
-                        return vec_tuplas;
-                    }
+    /// Este struct almacena una asistencia de un socio. Almacena:
+    /// :> El DNI del socio (dni), el cual es un tipo de dato u32.
+    /// :> El deporte al que asistió (deporte), el cual es un tipo de dato Deporte.
+    /// :> La fecha de la asistencia (fecha), la cual es un tipo de dato u64.
+    /// :> El numero de la clase, si asistió a una (clase), el cual es un tipo de dato Option(u32).
+    /// :> La cuenta que registró la asistencia (registrado_por), la cual es un tipo de dato AccountId.
+    ///
+    /// Cuenta con 4 implementaciones: get_dni, get_deporte, get_fecha y get_clase.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Asistencia {
+        dni: u32,
+        deporte: Deporte,
+        fecha: u64,
+        clase: Option<u32>,
+        registrado_por: AccountId,
+    }
 
-                    return panic!("No se ha encontrado un socio con el DNI ingresado!")
-                }
-                else {
-                    let mut vec_tuplas = Vec::new();
-                    for socio in &self.socios {
-                        let mut vec_pagos = Vec::new();
-                        for pago in &self.pagos {
-                            if pago.dni == socio.dni {
-                                vec_pagos.push(pago.monto);
-                            }
-                        }
-                        let tupla = (socio.clone(), vec_pagos);
-                        vec_tuplas.push(tupla);
-                    }
-                    return vec_tuplas;
-                }
-            }
-            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+    impl Asistencia {
+        ///Retorna el contenido de la variable dni
+        pub fn get_dni (&self) -> u32 {
+            return self.dni;
         }
-
-        ///Para empezar se comprueba que el caller sea admin o staff o que la politica de autorizacion este desactivada.
-        ///luego crea un vec vacio, posteriormente, recorre el vec de pagos y chequea si en cada pago, el dni del pago es
-        ///igual al ingresado, entonces se agrega al vec recientemente creado, por ultimo retorna el vec luego del for.
-        ///Si el caller no tenia permisos necesarios para llamar a la funcion, entonces se arrojara un panick.
-        ///Cabe aclarar que este metodo tiene una doble funcionalidad, si se quiere recibir los pagos totales de una persona en
-        ///especifico, se recibira un Some(con un dni) y si se quiere recibir todos los pagos del club, se recibira un None como
-        ///parametro y cuando en el if se detecte que el DNi es none, retornara los pagos totales del club
-        #[ink(message)]
-        pub fn get_pagos_totales (&self, dni: Option<u32>) -> Vec<Pago> {
-            return self.get_pagos_totales_priv(&dni);
+        ///Retorna el contenido de la variable deporte
+        pub fn get_deporte (&self) -> Deporte {
+            return self.deporte.clone();
         }
+        ///Retorna el contenido de la variable fecha
+        pub fn get_fecha (&self) -> u64 {
+            return self.fecha;
+        }
+        ///Retorna el contenido de la variable clase
+        pub fn get_clase (&self) -> Option<u32> {
+            return self.clase;
+        }
+    }
 
+    /// Este struct almacena cuantas asistencias tuvo un socio (dni) en un deporte (deporte) durante un mes calendario
+    /// (cantidad). Es el resultado de get_asistencias_mensuales.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct AsistenciasMensuales {
+        pub dni: u32,
+        pub deporte: Deporte,
+        pub cantidad: u32,
+    }
 
-        fn get_pagos_totales_priv (&self, dni : &Option<u32>) -> Vec<Pago>{
-            if self.verificar_permisos() {
-                let mut aux_vec = Vec::new();
-                if let Some(dni) = dni{
-                    for pago in &self.pagos{
-                        if pago.dni == *dni{
-                            aux_vec.push(pago.clone());
-                        }
-                    }
-                } else {
-                    aux_vec = self.pagos.clone();
-                }
-                return aux_vec;
-            }
-            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+    /// Se trata de un Enum con el resultado de una inscripción a una clase: el socio quedó inscripto, o quedó en la
+    /// lista de espera en la posición indicada (empezando por 1).
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoInscripcion {
+        Inscripto,
+        EnEspera(u32),
+    }
+
+    /// Este struct almacena una instalación reservable del club, como una cancha de tenis o de paddle. Almacena:
+    /// :> El numero de la instalación (id), el cual es un tipo de dato u32.
+    /// :> Su nombre (nombre), el cual es un tipo de dato String.
+    /// :> El deporte que se practica en ella (deporte), el cual es un tipo de dato Deporte.
+    /// :> La hora UTC de apertura (apertura) y de cierre (cierre), las cuales son un tipo de dato u32 entre 0 y 24. Se
+    ///    reserva por turnos de horas completas dentro de ese horario.
+    /// :> El arancel por hora de reserva (tarifa_hora), el cual es un tipo de dato u128.
+    ///
+    /// Cuenta con 3 implementaciones: get_id, get_deporte y get_tarifa_hora.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Instalacion {
+        id: u32,
+        nombre: String,
+        deporte: Deporte,
+        apertura: u32,
+        cierre: u32,
+        tarifa_hora: u128,
+    }
+
+    impl Instalacion {
+        ///Retorna el contenido de la variable id
+        pub fn get_id (&self) -> u32 {
+            return self.id;
+        }
+        ///Retorna el contenido de la variable deporte
+        pub fn get_deporte (&self) -> Deporte {
+            return self.deporte.clone();
         }
+        ///Retorna el contenido de la variable tarifa_hora
+        pub fn get_tarifa_hora (&self) -> u128 {
+            return self.tarifa_hora;
+        }
+    }
 
-	    /// Este método se encarga de verificar si a un socio le corresponde la bonificación en un pago al momento de
-        /// ejecutarlo. Dicha bonificacion se obtiene si: 
-        /// :> Pago a tiempo los últimos pagos, donde la cantidad de pagos es probista por el club.
-        /// :> Si dentro de la cantidad de pagos descripta anteriomente, no hubo ya un pago bonifcado.
-        /// 
-        /// Para comenzar, se obtiene todos los pagos realizados por un socio. Luego, se verifica si la cantidad de pagos
-        /// obtenidos es mayor o igual a la cantidad de pagos necesarios para acceder al beneficio, ya que de no ser así,
-        /// el socio no puede obtener el beneficio. Ya si hay tantos pagos (ya sea pendientes como realizados) como los
-        /// necesarios para obtener el beneficio, se obtienen tantos últimos pagos, como indique la cantidad de 
-        /// últimos pagos necesarios probista por el club y se almacenan en un Vec. Una vez obtenidos los pagos necesarios
-        /// para la verificacion. Se empieza a verificar cada pago, corroborando que:
-        /// :> Ninguno de ellos sea un pago pendiente (que tenga una fecha de pago)
-        /// :> Que la fecha de pago, sea inferior a la de vencimiento
-        /// :> Que el pago no haya sido bonificado
-        /// 
-        /// Si alguno de estos 3 requisitos no se ha cumplido en algun pago de los necesarios, el método devolverá
-        /// "false", indicando que el socio no puede obtener el beneficio, ya que el socio no cumple las condiciones
-        /// necesarias. De lo contrario, si cumplió con todos los requisitos en todos los pagos necesarios, el método
-        /// devolverá "true", indicando que el socio cumple con las condiciones para obtener el beneficio.
-        /// 
-        /// Este método recibe una referencia a sí mismo (ClubSemRust), y un tipo de dato u32 que corresponde al DNI de un
-        /// socio (dni), y devuelve un tipo de dato bool, que indica si el socio está en condiciones de recibir el
-        /// beneficio, o no. 
-        fn corroborar_bonificacion(&self, dni: u32) -> bool {
+    /// Se trata de un Enum con la forma de cobro del arancel de una reserva: sumado a la próxima cuota que se le emita
+    /// al socio, o cobrado en el momento de reservar, con su recibo.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum CobroReserva {
+        ProximaCuota,
+        Inmediato,
+    }
 
-            let mut vec_aux: Vec<Pago> = self.get_pagos_totales(Some(dni));
-            //verifico que no sea un vector vacio y que la cantidad de pagos sea la suficiente como para recibir una bonificacion
-            if (!vec_aux.is_empty()) && (self.costos.pagos_consecutivos_para_beneficio as usize <= vec_aux.len()) {
-                //itero la cantidad de veces necesaria para conseguir la bonificacion
-                for _i in 0..self.costos.pagos_consecutivos_para_beneficio {
-                    //obtengo el siguiente pago
-                    let pago = vec_aux.pop().unwrap();
-                    //verifico que haya una fecha de pago, si hay, verifico las condiciones, sino, devuelvo false
-                    if let Some (fecha_pago) = pago.fecha_pago {
-                        //si la fecha de pago se hizo fuera de termino o el pago esta bonificado, corto la ejecucion y devuelvo false
-                        if (fecha_pago > pago.fecha_venci) || (pago.bonificado) {
-                            return false;
-                        }
-                    }
-                    else {
-                        return false;
-                    }
-                }
-                //si el for termina sin devolver false, entonces se devuelve true
-                return true;
-            }
-            //si el vector es vacio o la cantidad de pagos no alcanza la minima necesaria para la bonificacion, se devuelve false
-            return false;
-        }
+    /// Este struct almacena una reserva de una instalación. Almacena:
+    /// :> El numero de la reserva (id), el numero de la instalación (instalacion) y el DNI del socio (dni), los cuales
+    ///    son un tipo de dato u32.
+    /// :> El inicio y el fin del turno reservado (inicio, fin), los cuales son un tipo de dato u64.
+    /// :> El arancel de la reserva (monto), el cual es un tipo de dato u128.
+    /// :> La forma de cobro del arancel (cobro), la cual es un tipo de dato CobroReserva.
+    /// :> Si el arancel ya fue cobrado o sumado a una cuota (cobrada), y si la reserva fue cancelada (cancelada), los
+    ///    cuales son un tipo de dato bool.
+    ///
+    /// Cuenta con 6 implementaciones: get_id, get_dni, get_inicio, get_monto, is_cobrada e is_cancelada.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Reserva {
+        id: u32,
+        instalacion: u32,
+        dni: u32,
+        inicio: u64,
+        fin: u64,
+        monto: u128,
+        cobro: CobroReserva,
+        cobrada: bool,
+        cancelada: bool,
+    }
 
-        /// Para esta funcion, primero se comprueba que la variable emision_ultimo_pago contenga algo, de no ser asi, no se han registrado socios
-       /// y no tiene sentido disparar el metodo, con lo cual retorna un panick. En el caso de que la emision_ultimo_pago contenga algo, se obtiene
-       /// el dia_actual en una variable. Luego se comprueba que el caller sea admin u owner y que el dia actual sea mayor o igual a 30 dias posteriores
-       /// a la ultima emision(que nos guardamos previamente en la variable fecha_emision) una vez hechas estas comprobaciones. Para cada socio del vec de socios
-       /// se llama a la funncion corroborar_bonificacion, y esta retorna en la variable bonificado si el proximo pago debe ser bonificado o no. Luego se crea una
-       /// fecha de vencimiento que sera el dia de hoy + 30 dias y una variable costo cuyo valor se obtiene con la funcion get_costo. Posteriormente
-       /// si la variable bonificado previamente dio como resultado true, se le descuenta al costo el beneficio de la variable costos.beneficio
-       /// por ultimo,se crea el nnuevo pago con el dni del socio, el costo, la fecha de vencimiento, fecha de pago en None y la variable booleana que
-       /// indica si fue bonificado o no. Finalmente se pushea un clone del pago al vec de pagos del club.
-       /// Una vez terminado de repetir esto para todos los socios, se actualiza la emision del ultimo pago con la variable previamente creada, dia_actual 
-       /// y se retorna true. Si el caller no era admin, staff o la politica estaba desactivada y el dia actual no era mayor o igual a la ultima fecha + 30 dias
-       /// entonces el programa arrojara un panick
-        #[ink(message)]
-        pub fn emitir_pago_mensual (&mut self) -> bool {
-            self.emitir_pago_mensual_priv()
+    impl Reserva {
+        ///Retorna el contenido de la variable id
+        pub fn get_id (&self) -> u32 {
+            return self.id;
+        }
+        ///Retorna el contenido de la variable dni
+        pub fn get_dni (&self) -> u32 {
+            return self.dni;
+        }
+        ///Retorna el contenido de la variable inicio
+        pub fn get_inicio (&self) -> u64 {
+            return self.inicio;
+        }
+        ///Retorna el contenido de la variable monto
+        pub fn get_monto (&self) -> u128 {
+            return self.monto;
+        }
+        ///Retorna el contenido de la variable cobrada
+        pub fn is_cobrada (&self) -> bool {
+            return self.cobrada;
         }
+        ///Retorna el contenido de la variable cancelada
+        pub fn is_cancelada (&self) -> bool {
+            return self.cancelada;
+        }
+    }
 
-        fn emitir_pago_mensual_priv (&mut self) -> bool {
-            if let Some (fecha_emision) = self.emision_ultimo_pago {
-                let dia_actual = self.get_milisegundos_actuales();
-                if self.verificar_permisos() && (dia_actual >= (fecha_emision + 30.from_dias())) { 
-                    for socio in &self.socios {
-                        let bonificado = self.corroborar_bonificacion(socio.dni);
-                        let fecha_venci = self.env().block_timestamp() + 30.from_dias();
-                        let mut costo = self.costos.get_costo(&socio.categoria);
-                        if bonificado {
-                            costo -= self.costos.beneficio;
-                        }
-                        let nuevo_pago = Pago::new(socio.dni, costo, fecha_venci, None, bonificado);
-                        self.pagos.push(nuevo_pago.clone());
-                    }
-                    self.emision_ultimo_pago = Some (dia_actual);
-                    return true;
-                }
-                else {
-                    return panic!("No se cuenta con los permisos necesarios, la politica de autorizacion se encuentra activada, o no han pasado 30 dias desde la ultima emision!");
-                } 
-            }
-            else {
-                return panic!("No se han emitido pagos aun!");
-            }
+    /// Se trata de un Enum que contiene los tipos de movimientos que puede tener un pago: su cobro, la reversión
+    /// del cobro (el pago vuelve a quedar pendiente) y el reintegro del dinero recibido al socio.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum TipoMovimiento {
+        Cobro,
+        Reversion,
+        Reintegro,
+    }
+
+    /// Este struct almacena cada paso en la historia de los pagos del club. Almacena:
+    /// :> El tipo de movimiento (tipo), el cual es una variante del Enum TipoMovimiento.
+    /// :> El DNI del socio (dni), el cual es un tipo de dato u32.
+    /// :> El monto involucrado (monto), el cual es un tipo de dato u128.
+    /// :> El numero del recibo al que se refiere el movimiento (nro_recibo), el cual es un tipo de dato u32.
+    /// :> El motivo del movimiento (motivo), el cual es un tipo de dato String, vacío para los cobros y obligatorio
+    ///    para las reversiones y reintegros.
+    /// :> La cuenta que realizó el movimiento (realizado_por), el cual es un tipo de dato AccountId.
+    /// :> La fecha del movimiento (fecha), el cual es un tipo de dato u64.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct MovimientoPago {
+        tipo: TipoMovimiento,
+        dni: u32,
+        monto: u128,
+        nro_recibo: u32,
+        motivo: String,
+        realizado_por: AccountId,
+        fecha: u64,
+    }
+
+    impl MovimientoPago {
+        ///Recibe todos los datos del movimiento y lo crea
+        pub fn new (tipo: TipoMovimiento, dni: u32, monto: u128, nro_recibo: u32, motivo: String, realizado_por: AccountId, fecha: u64) -> MovimientoPago {
+            MovimientoPago {tipo, dni, monto, nro_recibo, motivo, realizado_por, fecha}
         }
+    }
 
-        ///La función registrar_socio permite incorporar un nuevo socio al club, recibiendo los datos necesarios : dni, apellido y nombre, id de
-        ///la categoría del asociado y, en el caso de que se forme parte de la categoría B, el id del deporte elegido. El método verifica permisos,
-        ///por lo que es una acción que usuarios selectos pueden realizar a menos que la política de autorización se encuentre desactivada. La función
-        ///rechazará el pedido en caso de que cualquiera de los IDs sean incorrectos, en caso de que el dni ingresado pertenezca a un socio ya
-        ///afiliado o de que no se cuenten con los permisos requeridos. Dada una ejecución exitosa, el método retornará la información del
-        ///primer pago pendiente del nuevo miembro. 
-        #[ink(message)]
-        pub fn registrar_socio (&mut self, dni: u32, apellido_y_nombre: String, id_categoria: u32, id_deporte: Option<u32>) -> Option<Pago> {
-            return self.registrar_socio_priv(dni, apellido_y_nombre, id_categoria, id_deporte);
+    /// Este struct almacena los puntos de fidelidad que recibe un socio de cada categoria (a, b y c) por cada cuota que
+    /// paga antes de su vencimiento, los cuales son un tipo de dato u32. Incluye 3 metodos: new, get_puntos y set_puntos.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PuntosCategoria {
+        a: u32,
+        b: u32,
+        c: u32,
+    }
+
+    impl PuntosCategoria {
+        ///Recibe los puntos que otorga cada categoria, a, b y c
+        pub fn new (a: u32, b: u32, c: u32) -> PuntosCategoria {
+            PuntosCategoria {a, b, c}
         }
 
-        fn registrar_socio_priv (&mut self, dni: u32, apellido_y_nombre: String, id_categoria: u32, id_deporte: Option<u32>) -> Option<Pago> {
-            if self.verificar_permisos() && (!self.existe_socio(dni)) {
-                let option_categoria = CategoriasSocios::categoria_from_id(&id_categoria);
-                match option_categoria {
-                    Some (categoria) => {
-                        let fecha_hoy = self.env().block_timestamp();
-                        let fecha_venci = fecha_hoy + 10.from_dias();
-                        match Deporte::deporte_from_id(&id_deporte){
-                            Some(deporte) => {let nuevo_socio = Socio::new(apellido_y_nombre.clone(), dni, categoria.clone(), Some(deporte));
-                                let pago_pend = Pago::new(dni, self.costos.get_costo(&categoria), fecha_venci, None, false);
-                                self.pagos.push(pago_pend.clone());
-                                self.socios.push(nuevo_socio.clone());
-                                if self.emision_ultimo_pago == None {
-                                    self.emision_ultimo_pago = Some (fecha_hoy);
-                                    }
-                                return Some(pago_pend);    
-                                },
-                            None=> { let nuevo_socio = Socio::new(apellido_y_nombre.clone(), dni, categoria.clone(), None);
-                                let pago_pend = Pago::new(dni, self.costos.get_costo(&categoria), fecha_venci, None, false);
-                                self.pagos.push(pago_pend.clone());
-                                self.socios.push(nuevo_socio.clone());
-                                if self.emision_ultimo_pago == None {
-                                    self.emision_ultimo_pago = Some (fecha_hoy);
-                                    }
-                                return Some(pago_pend);    
-                            }
-                        }
-                    }    
-                    None => return panic!("La id de categoria no está entre las presentes!"),
-                }
-            }
-            if (self.existe_socio(dni)){
-                return panic!("Ese socio existe!");
+        ///Retorna los puntos que otorga la categoria recibida
+        pub fn get_puntos (&self, categoria: &CategoriasSocios) -> u32 {
+            match categoria {
+                CategoriasSocios::A => self.a,
+                CategoriasSocios::B => self.b,
+                CategoriasSocios::C => self.c,
             }
-            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
         }
 
-	    /// Este método se encarga de registrar un pago de un socio.
-        /// 
-        /// Para comenzar, se verifica que el usuario que llama al método cumpla con los permisos necesarios. De no ser 
-        /// así, se arrojará un panic informando lo sucedido. De contar con permisos necesarios, comienza la busqueda
-        /// del pago, en el Vec de pagos. Así, se intenta ubicar a un pago que cumpla con las condiciones necesarias, 
-        /// que son:
-        /// :> Que el pago contenga el DNI del socio.
-        /// :> Que el monto ingresado a abonar, sea el mismo que el del pago.
-        /// :> Que el pago, no tenga fecha de pago.
-        /// 
-        /// De no encontrar un pago que cumpla con estas condiciones, se arrojará un panic informando la situación. De lo
-        /// contrario, se obtiene el pago del Vec de pagos, y se establece como fecha de pago, la fecha actual, y como
-        /// muestra de que se ha registrado el pago, retorna el pago.
-        /// 
-        /// Este método, recibe una referencia mutable de sí mismo (ClubSemRust), un tipo de dato u32 que hace
-        /// referencia al DNI del socio (dni) y un tipo de dato u128, que hace referncia al monto del pago que se quiere
-        /// registrar, y como mencionamos anteriormente, devuelve un tipo de dato Pago
-        #[ink(message)]
-        pub fn realizar_pago (&mut self, dni: u32, monto: u128) -> Pago {
-            return self.realizar_pago_priv(dni, monto);
+        ///Actualiza los puntos que otorga la categoria recibida con el valor nuevo_valor
+        fn set_puntos (&mut self, nuevo_valor: u32, categoria: &CategoriasSocios) {
+            match categoria {
+                CategoriasSocios::A => self.a = nuevo_valor,
+                CategoriasSocios::B => self.b = nuevo_valor,
+                CategoriasSocios::C => self.c = nuevo_valor,
+            }
         }
+    }
 
-        fn realizar_pago_priv (&mut self, dni: u32, monto: u128) -> Pago {
-            if self.verificar_permisos() {
+    /// Este struct almacena un lote de puntos de un socio que vencen juntos. Almacena el DNI del socio (dni), los puntos
+    /// que quedan sin usar (cantidad), la fecha en que vencen (vence) y, si los puntos se acreditaron por un pago, el
+    /// numero del recibo de ese pago (nro_recibo), que permite anularlos si el cobro se revierte.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct LotePuntos {
+        dni: u32,
+        cantidad: u32,
+        vence: u64,
+        nro_recibo: Option<u32>,
+    }
 
-                if self.existe_socio(dni) {
-                    let fecha_actual = self.env().block_timestamp();
-                    let option_pos = self.pagos.iter().position(|pago| (pago.dni == dni) && (pago.monto == monto) && (pago.fecha_pago == None));
-                    if let Some (pos) = option_pos {
-                        let pago = self.pagos.get_mut(pos).unwrap();
-                        pago.fecha_pago = Some(fecha_actual);
-                        return pago.clone();  
-                    }
+    /// Se trata de un Enum con los tipos de movimiento de los puntos de fidelidad: la acreditación por un pago a término,
+    /// el canje por un descuento en la próxima cuota, la transferencia a otro integrante del grupo familiar (enviada y
+    /// recibida), el vencimiento, y la anulación de los puntos de un cobro revertido.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum TipoMovimientoPuntos {
+        Acreditacion,
+        Canje,
+        TransferenciaEnviada,
+        TransferenciaRecibida,
+        Vencimiento,
+        Anulacion,
+    }
 
-                    return panic!("El cliente no tiene pagos pendientes con el monto ingresado!");
-                }
-                else {
-                    return panic! ("No se ha encontrado un socio con el DNI provisto!");
-                }
-            }
+    /// Este struct almacena cada movimiento de los puntos de un socio: el tipo (tipo), el DNI del socio (dni), los
+    /// puntos involucrados (cantidad), la fecha (fecha) y una referencia (referencia), que es el numero de recibo en las
+    /// acreditaciones y anulaciones, el DNI del otro socio en las transferencias, y None en el resto.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct MovimientoPuntos {
+        tipo: TipoMovimientoPuntos,
+        dni: u32,
+        cantidad: u32,
+        fecha: u64,
+        referencia: Option<u32>,
+    }
 
-            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+    impl MovimientoPuntos {
+        ///Recibe todos los datos del movimiento y lo crea
+        pub fn new (tipo: TipoMovimientoPuntos, dni: u32, cantidad: u32, fecha: u64, referencia: Option<u32>) -> MovimientoPuntos {
+            MovimientoPuntos {tipo, dni, cantidad, fecha, referencia}
+        }
+        ///Retorna el tipo del movimiento
+        pub fn get_tipo (&self) -> TipoMovimientoPuntos {
+            return self.tipo.clone();
+        }
+        ///Retorna los puntos involucrados en el movimiento
+        pub fn get_cantidad (&self) -> u32 {
+            return self.cantidad;
         }
-            fn get_milisegundos_actuales(&self)->u64{
-                return self.env().block_timestamp();
-            }       
     }
 
-    mod tests{
-    use super::*;
-    //zona de testing
+    /// Se trata de un Enum con los identificadores de token que define el estándar PSP34. Los carnets de socio usan
+    /// siempre la variante U32, con el numero de carnet.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Id {
+        U8(u8),
+        U16(u16),
+        U32(u32),
+        U64(u64),
+        U128(u128),
+        Bytes(Vec<u8>),
+    }
 
-    //Testing de pago
-    // Para testear los new, simplemente hago un new de cada pago y en cada test chequeo que la variable del struct pago corresponda con el valor ingresado
-    #[ink::test]
-    fn pago_new_check_dni_test (){
-        let p1 = Pago::new(44933856,2000,1688639827,None,false);
-        assert_eq!(p1.dni,44933856);
+    /// Se trata de un Enum con los errores que define el estándar PSP34, tal como los devuelven los mensajes de
+    /// transferencia y aprobación del carnet de socio.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP34Error {
+        Custom(String),
+        SelfApprove,
+        NotApproved,
+        TokenExists,
+        TokenNotExists,
+        SafeTransferCheckFailed(String),
     }
-    #[ink::test]
-    fn pago_new_check_monto_test (){
-        let p1 = Pago::new(44933856,2000,1688639827,None,false);
-        assert_eq!(p1.monto,2000);
+
+    /// Se trata de un Enum con el estado de un carnet de socio: al dia (sin cuotas vencidas impagas), con deuda (con al
+    /// menos una cuota vencida impaga) o revocado (el socio renunció).
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum EstadoCarnet {
+        AlDia,
+        ConDeuda,
+        Revocado,
     }
-    #[ink::test]
-    fn pago_new_check_fecha_vencimiento_test (){
-        let p1 = Pago::new(44933856,2000,1688639827,None,false);
-        assert_eq!(p1.fecha_venci,1688639827);
+
+    /// Este struct almacena el carnet de socio, un token no fungible e intransferible al estilo PSP34 que se emite al
+    /// registrar a cada socio. Almacena el numero de carnet (id), el DNI del socio (dni), su categoria y deporte al
+    /// momento del alta (categoria y deporte), la cuenta que lo posee (titular), que es None hasta que se vincula una,
+    /// la fecha de emisión (emitido) y si fue revocado por la renuncia del socio (revocado). Incluye 3 metodos:
+    /// get_id, get_titular e is_revocado.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Carnet {
+        id: u32,
+        dni: u32,
+        categoria: CategoriasSocios,
+        deporte: Option<Deporte>,
+        titular: Option<AccountId>,
+        emitido: u64,
+        revocado: bool,
     }
-    #[ink::test]
-    fn pago_new_check_fecha_pago_none_test (){
-        let p1 = Pago::new(44933856,2000,1688639827,None,false);
-        assert_eq!(p1.fecha_pago,None);
+
+    impl Carnet {
+        ///Retorna el numero de carnet
+        pub fn get_id (&self) -> u32 {
+            return self.id;
+        }
+        ///Retorna la cuenta titular del carnet, si tiene una vinculada
+        pub fn get_titular (&self) -> Option<AccountId> {
+            return self.titular;
+        }
+        ///Retorna true si el carnet fue revocado
+        pub fn is_revocado (&self) -> bool {
+            return self.revocado;
+        }
     }
-    #[ink::test]
-    fn pago_new_check_fecha_pago_some_test (){
-        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),false);
-        assert_eq!(p1.fecha_pago,Some(1688553427));
+
+    /// Este struct almacena una cuota de un plan de cuotas: su numero dentro del plan (numero), el monto a pagar (monto),
+    /// la fecha de vencimiento (fecha_venci) y la fecha en que se pagó (fecha_pago), que es None mientras esté pendiente.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct CuotaPlan {
+        numero: u32,
+        monto: u128,
+        fecha_venci: u64,
+        fecha_pago: Option<u64>,
     }
-    #[ink::test]
-    fn pago_new_check_bonificacion_false_test (){
-        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),false);
-        assert_eq!(p1.bonificado,false);
+
+    impl CuotaPlan {
+        ///Retorna el monto de la cuota
+        pub fn get_monto (&self) -> u128 {
+            return self.monto;
+        }
+        ///Retorna la fecha de vencimiento de la cuota
+        pub fn get_fecha_venci (&self) -> u64 {
+            return self.fecha_venci;
+        }
+        ///Retorna la fecha en que se pagó la cuota, o None si está pendiente
+        pub fn get_fecha_pago (&self) -> Option<u64> {
+            return self.fecha_pago;
+        }
     }
-    #[ink::test]
-    fn pago_new_check_bonificacion_true_test (){
-        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
-        assert_eq!(p1.bonificado,true);
-    }
-    //Para chequear los getters, hago el new de pago, creo una variable x_recibido y compruebo que x_recibido se corresponda con el valor ingresado previamente en el new
-    #[ink::test]
-    fn get_dni_socio_test(){
-        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
-        let dni_recibido = p1.get_dni_socio();
-        assert_eq!(dni_recibido,44933856);
+
+    /// Se trata de un Enum con el estado de un plan de cuotas: vigente mientras se pagan sus cuotas, cumplido cuando se
+    /// pagaron todas, y caido si alguna cuota venció sin pagarse.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoPlan {
+        Vigente,
+        Cumplido,
+        Caido,
     }
-    #[ink::test]
-    fn get_monto_test(){
-        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
-        let monto_recibido = p1.get_monto();
-        assert_eq!(monto_recibido,2000);
+
+    /// Este struct almacena un plan de cuotas con el que un socio refinancia sus pagos vencidos. Almacena el numero de
+    /// plan (id), el DNI del socio (dni), las posiciones en el Vec de pagos del club de los pagos refinanciados (pagos),
+    /// la suma de esos pagos (deuda_original), el porcentaje de interés aplicado (tasa_interes), las cuotas (cuotas) y
+    /// el estado del plan (estado). Mientras el plan está vigente, los pagos refinanciados no pueden pagarse por separado.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PlanCuotas {
+        id: u32,
+        dni: u32,
+        pagos: Vec<u32>,
+        deuda_original: u128,
+        tasa_interes: u32,
+        cuotas: Vec<CuotaPlan>,
+        estado: EstadoPlan,
     }
-    #[ink::test]
-    fn get_fecha_venci_test(){
-        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
-        let fecha_recibida = p1.get_fecha_venci();
-        assert_eq!(fecha_recibida,1688639827);
+
+    impl PlanCuotas {
+        ///Retorna el numero del plan
+        pub fn get_id (&self) -> u32 {
+            return self.id;
+        }
+        ///Retorna la suma de los pagos refinanciados
+        pub fn get_deuda_original (&self) -> u128 {
+            return self.deuda_original;
+        }
+        ///Retorna las cuotas del plan
+        pub fn get_cuotas (&self) -> Vec<CuotaPlan> {
+            return self.cuotas.clone();
+        }
+        ///Retorna el estado del plan
+        pub fn get_estado (&self) -> EstadoPlan {
+            return self.estado.clone();
+        }
     }
-    #[ink::test]
-    fn get_fecha_pago_some_test(){
-        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
-        let fecha_recibida = p1.get_fecha_pago();
-        assert_eq!(fecha_recibida,Some(1688553427));
+
+    /// Se trata de un Enum con los planes de facturación que puede elegir un socio: mensual, trimestral, semestral o
+    /// anual. Con los planes de más de un mes, la cuota se emite por adelantado y cubre todos los meses del plan.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum PlanFacturacion {
+        Mensual,
+        Trimestral,
+        Semestral,
+        Anual,
     }
-    #[ink::test]
-    fn get_fecha_pago_none_test(){
-        let p1 = Pago::new(44933856,2000,1688639827,None,true);
-        let fecha_recibida = p1.get_fecha_pago();
-        assert_eq!(fecha_recibida,None);
+
+    impl PlanFacturacion {
+        /// Obtiene el plan correspondiente al ID recibido: 1 es mensual, 2 trimestral, 3 semestral y 4 anual. Con
+        /// cualquier otro ID arroja un panic informando que el ID no es valido.
+        pub fn plan_from_id(id: &u32) -> Option<PlanFacturacion> {
+            match id {
+                1 => Some(PlanFacturacion::Mensual),
+                2 => Some(PlanFacturacion::Trimestral),
+                3 => Some(PlanFacturacion::Semestral),
+                4 => Some(PlanFacturacion::Anual),
+                _ => panic!("El id de plan de facturacion ingresado no es valido!"),
+            }
+        }
+
+        /// Retorna la cantidad de meses que cubre una cuota del plan
+        pub fn meses(&self) -> u32 {
+            match self {
+                PlanFacturacion::Mensual => 1,
+                PlanFacturacion::Trimestral => 3,
+                PlanFacturacion::Semestral => 6,
+                PlanFacturacion::Anual => 12,
+            }
+        }
     }
 
-   ///Para chequear que el set_fecha_pago se ejecute correctamente
-   ///creo un nuevo pago, le realizo un set_fecha de pago
-   ///y luego utilizo una variable auxiliar fecha_recibida
-   ///con el get_fecha_pago (ya testeado previamente)
-   ///luego compruebo que fecha_recibida sea igual a lo que
-   ///ingrese previamente en el set fecha pago
-    #[ink::test]
-    fn set_fecha_pago_test(){
-        let mut p1 = Pago::new(44933856,2000,1688639827,None,true);
-        p1.set_fecha_pago(1688553427);
-        let fecha_recibida = p1.get_fecha_pago();
-        assert_eq!(fecha_recibida,Some(1688553427));
+    /// Este struct almacena el porcentaje de descuento, sobre la suma de las cuotas mensuales, de cada plan de
+    /// facturación de más de un mes (trimestral, semestral y anual), los cuales son un tipo de dato u32 entre 0 y 100.
+    /// Incluye 3 metodos: new, get_descuento y set_descuento.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct DescuentosPlan {
+        trimestral: u32,
+        semestral: u32,
+        anual: u32,
     }
-    //testing de costos categoria
 
-    // para testear los new, simplemente hago un new de cada costo categoria y en cada test chequeo que la variable del struct CostosCategoria corresponda con el valor ingresado
-    #[ink::test]
-    fn costos_categoria_new_valor_a_test(){
-        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        assert_eq!(c1.a,1000);
+    impl DescuentosPlan {
+        ///Recibe el porcentaje de descuento de los planes trimestral, semestral y anual
+        pub fn new (trimestral: u32, semestral: u32, anual: u32) -> DescuentosPlan {
+            DescuentosPlan {trimestral, semestral, anual}
+        }
+
+        ///Retorna el porcentaje de descuento del plan recibido. El plan mensual nunca tiene descuento.
+        pub fn get_descuento (&self, plan: &PlanFacturacion) -> u32 {
+            match plan {
+                PlanFacturacion::Mensual => 0,
+                PlanFacturacion::Trimestral => self.trimestral,
+                PlanFacturacion::Semestral => self.semestral,
+                PlanFacturacion::Anual => self.anual,
+            }
+        }
+
+        ///Actualiza el porcentaje de descuento del plan recibido. Retorna false, sin modificar nada, si el plan es el
+        ///mensual o si el porcentaje supera 100.
+        fn set_descuento (&mut self, porcentaje: u32, plan: &PlanFacturacion) -> bool {
+            if porcentaje > 100 {
+                return false;
+            }
+            match plan {
+                PlanFacturacion::Mensual => return false,
+                PlanFacturacion::Trimestral => self.trimestral = porcentaje,
+                PlanFacturacion::Semestral => self.semestral = porcentaje,
+                PlanFacturacion::Anual => self.anual = porcentaje,
+            }
+            return true;
+        }
     }
-    #[ink::test]
-    fn costos_categoria_new_valor_b_test(){
-        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        assert_eq!(c1.b,3000);
+
+    /// Este struct almacena la facturación de un socio que eligió un plan distinto del mensual, o que lo cambió alguna
+    /// vez: el DNI del socio (dni), el plan con el que se le emitió la última cuota (plan), el plan que regirá desde su
+    /// próximo ciclo, si lo cambió (plan_siguiente), y cuantas emisiones mensuales más cubre su última cuota
+    /// (meses_cubiertos). Los socios sin este registro se facturan mensualmente.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct FacturacionSocio {
+        dni: u32,
+        plan: PlanFacturacion,
+        plan_siguiente: Option<PlanFacturacion>,
+        meses_cubiertos: u32,
     }
-    #[ink::test]
-    fn costos_categoria_new_valor_c_test(){
-        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        assert_eq!(c1.c,5000);
+
+    impl FacturacionSocio {
+        ///Retorna el plan vigente del socio
+        pub fn get_plan (&self) -> PlanFacturacion {
+            return self.plan.clone();
+        }
+        ///Retorna el plan que regirá desde el próximo ciclo, si el socio lo cambió
+        pub fn get_plan_siguiente (&self) -> Option<PlanFacturacion> {
+            return self.plan_siguiente.clone();
+        }
+        ///Retorna cuantas emisiones mensuales más cubre la última cuota del socio
+        pub fn get_meses_cubiertos (&self) -> u32 {
+            return self.meses_cubiertos;
+        }
     }
-    #[ink::test]
-    fn costos_categoria_new_valor_beneficio_test(){
-        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        assert_eq!(c1.beneficio,400);
+
+    /// Este struct almacena la politica de morosidad del club: a partir de cuantos pagos vencidos impagos
+    /// (pagos_para_moroso) o de cuantos dias de atraso del más antiguo (dias_para_moroso) un socio pasa a ser moroso, y a
+    /// partir de cuantos pagos o dias (pagos_para_suspension y dias_para_suspension) queda suspendido. Un umbral en 0 no
+    /// se aplica. Incluye 2 metodos: new y estado_para.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct PoliticaMorosidad {
+        pagos_para_moroso: u32,
+        dias_para_moroso: u64,
+        pagos_para_suspension: u32,
+        dias_para_suspension: u64,
     }
-    #[ink::test]
-    fn costos_categoria_new_pagos_consecutivoss_test(){
-        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        assert_eq!(c1.pagos_consecutivos_para_beneficio,3);
+
+    impl PoliticaMorosidad {
+        ///Recibe los umbrales de pagos vencidos y de dias de atraso para la morosidad y para la suspensión
+        pub fn new (pagos_para_moroso: u32, dias_para_moroso: u64, pagos_para_suspension: u32, dias_para_suspension: u64) -> PoliticaMorosidad {
+            PoliticaMorosidad {pagos_para_moroso, dias_para_moroso, pagos_para_suspension, dias_para_suspension}
+        }
+
+        ///Retorna el estado que corresponde a un socio con la cantidad de pagos vencidos impagos recibida, cuyo atraso más
+        ///antiguo es de dias_atraso dias
+        pub fn estado_para (&self, pagos_vencidos: u32, dias_atraso: u64) -> EstadoSocio {
+            let supera = |pagos: u32, dias: u64| ((pagos > 0) && (pagos_vencidos >= pagos)) || ((dias > 0) && (dias_atraso >= dias));
+            if supera(self.pagos_para_suspension, self.dias_para_suspension) {
+                return EstadoSocio::Suspendido;
+            }
+            if supera(self.pagos_para_moroso, self.dias_para_moroso) {
+                return EstadoSocio::Moroso;
+            }
+            return EstadoSocio::Activo;
+        }
     }
-    //para chequear los setters, creo una nueva variable CostosCategoria y seteo
-    //en diferentes tests sus diferentes variables. Finalmente, compruebo que la variable seteada
-    //se corresponda con el nuevo valor seteado
-    #[ink::test]
-    fn set_costo_a_test(){
-        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        c1.set_costo(2500, &CategoriasSocios::A);
-        assert_eq!(c1.a,2500);
+
+    /// Se trata de un Enum con el estado de un socio según la politica de morosidad: activo, moroso o suspendido. A los
+    /// socios suspendidos no se les emiten cuotas. El estado se calcula a partir de la deuda vencida del socio, por lo que
+    /// al saldarla vuelve a estar activo.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum EstadoSocio {
+        Activo,
+        Moroso,
+        Suspendido,
     }
-    #[ink::test]
-    fn set_costo_b_test(){
-        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        c1.set_costo(2500, &CategoriasSocios::B);
-        assert_eq!(c1.b,2500);
+
+    /// Se trata de un Enum con los tipos de asiento de la cuenta corriente de un socio. Aumentan su deuda el cargo de
+    /// una cuota, el recargo por el interés de un plan de cuotas y la reversion de un cobro; la disminuyen la
+    /// bonificación de una cuota, el descuento por canje de puntos, el pago, la anulación del recargo de un plan caido y
+    /// el reintegro de un monto ya cobrado, como el arancel de una reserva cancelada.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum TipoAsiento {
+        Cargo,
+        Bonificacion,
+        Recargo,
+        Descuento,
+        Pago,
+        Reversion,
+        AnulacionRecargo,
+        Reintegro,
     }
-    #[ink::test]
-    fn set_costo_c_test(){
-        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        c1.set_costo(2500, &CategoriasSocios::C);
-        assert_eq!(c1.c,2500);
+
+    impl TipoAsiento {
+        /// Retorna true si el asiento aumenta la deuda del socio, y false si la disminuye
+        pub fn es_debito(&self) -> bool {
+            match self {
+                TipoAsiento::Cargo | TipoAsiento::Recargo | TipoAsiento::Reversion => true,
+                TipoAsiento::Bonificacion | TipoAsiento::Descuento | TipoAsiento::Pago | TipoAsiento::AnulacionRecargo | TipoAsiento::Reintegro => false,
+            }
+        }
     }
-    ///para los getters creo una variable CostosCategoria 
-    ///realizo el get que quiero testear y compruebo que se
-    ///corresponda con el valor ingresado en el new
-    #[ink::test]
-    fn get_costo_a_test(){
-        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        let costo_recibido = c1.get_costo(&CategoriasSocios::A);
-        assert_eq!(costo_recibido,1000);
+
+    /// Este struct almacena un asiento de la cuenta corriente de un socio: el tipo (tipo), el DNI del socio (dni), la
+    /// fecha en que se registró (fecha), el monto, siempre positivo (monto), y una referencia (referencia), que es el
+    /// numero de recibo en los pagos y reversiones, el numero de plan en los recargos y sus anulaciones, y None en el resto.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct AsientoCuenta {
+        tipo: TipoAsiento,
+        dni: u32,
+        fecha: u64,
+        monto: u128,
+        referencia: Option<u32>,
     }
-    #[ink::test]
-    fn get_costo_b_test(){
-        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        let costo_recibido = c1.get_costo(&CategoriasSocios::B);
-        assert_eq!(costo_recibido,3000);
+
+    impl AsientoCuenta {
+        ///Retorna el tipo del asiento
+        pub fn get_tipo (&self) -> TipoAsiento {
+            return self.tipo.clone();
+        }
+        ///Retorna el monto del asiento
+        pub fn get_monto (&self) -> u128 {
+            return self.monto;
+        }
+        ///Retorna la fecha del asiento
+        pub fn get_fecha (&self) -> u64 {
+            return self.fecha;
+        }
     }
-    #[ink::test]
-    fn get_costo_c_test(){
-        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        let costo_recibido = c1.get_costo(&CategoriasSocios::C);
-        assert_eq!(costo_recibido,5000);
+
+    /// Este struct almacena una linea de un estado de cuenta: el asiento (asiento) y el saldo del socio luego de
+    /// aplicarlo (saldo), que es negativo si el socio tiene saldo a favor.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LineaEstadoCuenta {
+        pub asiento: AsientoCuenta,
+        pub saldo: i128,
     }
-    #[ink::test]
-    fn set_beneficio_test(){
-        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        c1.set_beneficio(500);
-        assert_eq!(c1.beneficio,500);
+
+    /// Este struct almacena el estado de cuenta de un socio entre dos fechas: el DNI del socio (dni), el saldo al
+    /// comenzar el periodo (saldo_inicial), los asientos del periodo en orden cronológico con el saldo acumulado (lineas),
+    /// el saldo al terminar el periodo (saldo_final), y, a la fecha de la consulta, la deuda pendiente (deuda_total), el
+    /// vencimiento impago más antiguo (vencido_mas_antiguo) y el próximo vencimiento (proximo_vencimiento).
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct EstadoDeCuenta {
+        pub dni: u32,
+        pub saldo_inicial: i128,
+        pub lineas: Vec<LineaEstadoCuenta>,
+        pub saldo_final: i128,
+        pub deuda_total: u128,
+        pub vencido_mas_antiguo: Option<u64>,
+        pub proximo_vencimiento: Option<u64>,
     }
-    #[ink::test]
-    fn set_pagos_consecutivos_para_beneficio_test(){
-        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
-        c1.set_pagos_consecutivos_para_beneficio(5);
-        assert_eq!(c1.pagos_consecutivos_para_beneficio,5);
+
+    /// Devuelve el resultado de una operación aritmética de nucleo_facturacion, o arroja un panic con el motivo si falló,
+    /// ya que un monto o una fecha fuera de rango nunca debe guardarse en el estado del club.
+    fn verificar_aritmetica<T> (resultado: Result<T, ErrorAritmetico>) -> T {
+        match resultado {
+            Ok (valor) => return valor,
+            Err (ErrorAritmetico::Desbordamiento) => return panic!("El resultado de la operacion excede el maximo representable!"),
+            Err (ErrorAritmetico::ResultadoNegativo) => return panic!("El resultado de la operacion seria negativo!"),
+        }
     }
 
-    //testing de clubSemRust
+    /// Evento que se emite cuando el contrato es pausado. Contiene la cuenta que lo pausó (por) y el motivo de la pausa
+    /// (motivo).
+    #[ink(event)]
+    pub struct ContratoPausado {
+        #[ink(topic)]
+        por: AccountId,
+        motivo: String,
+    }
 
+    /// Evento que se emite cuando el contrato es reanudado. Contiene la cuenta que lo reanudó (por).
+    #[ink(event)]
+    pub struct ContratoReanudado {
+        #[ink(topic)]
+        por: AccountId,
+    }
 
-   /// para testear el new, lo divido en varios tests diferentes para hacerlo lo mas unitario posible
-   /// primero creo un club con valores de costos, beneficio y dias para el beneficio, luego entre cada test  
-   /// chequeo que se instancien los valores correctamente y que los valores default como el vec de socios,
-   /// el vec de pagos o la politica se instancien correctamente  
-    #[ink::test]
-    fn club_sem_rust_new_costoa_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        assert_eq!(club1.costos.a,1000);
+    /// Este struct contiene toda la información relevante al Club, por lo cual, es el storage del Club. Almacena:
+    /// :> Los costos referentes al club (costos), el cual es un tipo de dato CostosCategoria.
+    /// :> Los socios del club (socios), el cual es un tipo de dato Vec(Socio).
+    /// :> Los pagos efectuado por el Club (pagos), el cual es un tipo de dato Vec(Pago).
+    /// :> El owner del Club, y por lo tanto del contrato (owner), el cual es un tipo de dato AccountId.
+    /// :> Los permitidos, o staff's, para operar en el contrato en los metodos en que están permitidos operar (permitidos),
+    ///    el cual es un tipo de dato Vec(AccountId).
+    /// :> La politica de autorización (politica_activada), la cual indica que cualquiera pueda operar en el contrato en 
+    ///    los metodos que no se refieren a la administracion de costos y permitidos del mismo, de estar desactivada. 
+    ///    Es un tipo de dato bool, que si guarda false indica que la politica está desactivada, y cualquiera puede 
+    ///    operar en los metodos mencionados, en cambio si esta en true, no podrá acceder cualquiera, solo un staff o el
+    ///    propio owner.
+    /// :> La fecha de emisión del último pago mensual (emision_ultimo_pago), el cual es un tipo de dato Option(u64). 
+    ///    Este almacena None al momento de construir el club, ya que no hay pagos emitidos, y este guardará un Some() 
+    ///    cuando se registre el primer socio, guardando la fecha en la que se registro dicho socio.
+    /// :> La protección de datos personales (proteccion_datos), la cual es un tipo de dato bool. Si guarda true, solo
+    ///    se admiten registros de socios mediante compromisos de su identidad, sin datos personales en texto plano.
+    /// :> Los recibos emitidos por cada pago registrado (recibos), el cual es un tipo de dato Vec(Recibo).
+    /// :> El numero del último recibo emitido (ultimo_nro_recibo), el cual es un tipo de dato u32, y que guarda 0 
+    ///    mientras no se haya emitido ninguno.
+    /// :> El historial de cobros, reversiones y reintegros (movimientos), el cual es un tipo de dato Vec(MovimientoPago).
+    /// :> El plazo, en milisegundos, durante el cual un staff puede revertir un pago desde que fue cobrado
+    ///    (ventana_reversion), el cual es un tipo de dato u64. Por defecto es de 7 dias.
+    /// :> La auditoria de acciones administrativas (auditoria), el cual es un tipo de dato Vec(EntradaAuditoria), que
+    ///    solo admite agregar entradas y descarta las mas antiguas cuando se supera la retención.
+    /// :> El numero de la última entrada de auditoria (ultimo_nro_auditoria), el cual es un tipo de dato u64.
+    /// :> La cantidad máxima de entradas que conserva la auditoria (retencion_auditoria), el cual es un tipo de dato
+    ///    u32. Por defecto es de 100 entradas.
+    /// :> Los guardianes del club (guardianes), el cual es un tipo de dato Vec(AccountId). Son cuentas designadas por el
+    ///    Owner que, ademas de él, pueden pausar el contrato ante una emergencia.
+    /// :> Si el contrato se encuentra pausado (pausado), el cual es un tipo de dato bool. Mientras guarda true, se
+    ///    rechazan todos los metodos que modifican el estado, salvo la reanudación, y solo se permiten las consultas.
+    /// :> El motivo de la pausa vigente (motivo_pausa), el cual es un tipo de dato Option(String).
+    /// :> La importación de una instantanea que se encuentra en curso (importacion), el cual es un tipo de dato
+    ///    Option(ImportacionEnCurso).
+    /// :> Las cuentas con permiso de solo lectura (lectores), como el contrato de reportes, el cual es un tipo de dato
+    ///    Vec(AccountId).
+    /// :> El contrato PSP22 del token aceptado para pagar las cuotas (token_pago), el cual es un tipo de dato
+    ///    Option(AccountId). Si es None, no se aceptan pagos en token.
+    /// :> Los puntos de fidelidad que otorga cada categoria por un pago a término (puntos_categoria), el cual es un tipo
+    ///    de dato PuntosCategoria, inicialmente 0 para todas.
+    /// :> El tiempo, en milisegundos, durante el cual son validos los puntos acreditados (vigencia_puntos), el cual es
+    ///    un tipo de dato u64.
+    /// :> El descuento que vale cada punto al canjearlo (valor_punto), el cual es un tipo de dato u128.
+    /// :> Los lotes de puntos vigentes de los socios (lotes_puntos), el cual es un tipo de dato Vec(LotePuntos).
+    /// :> El historial de movimientos de puntos (movimientos_puntos), el cual es un tipo de dato Vec(MovimientoPuntos).
+    /// :> Los descuentos canjeados que se aplicarán en la próxima cuota de cada socio (canjes_pendientes), el cual es
+    ///    un tipo de dato Vec((u32, u128)) con el DNI del socio y el descuento.
+    /// :> El grupo familiar de cada socio que pertenece a uno (grupos_familiares), el cual es un tipo de dato
+    ///    Vec((u32, u32)) con el DNI del socio y el identificador del grupo.
+    /// :> Los carnets de socio emitidos, incluidos los revocados (carnets), el cual es un tipo de dato Vec(Carnet).
+    /// :> Los planes de cuotas de los socios, en cualquier estado (planes_cuotas), el cual es un tipo de dato
+    ///    Vec(PlanCuotas).
+    /// :> El porcentaje de descuento de cada plan de facturación de más de un mes (descuentos_plan), el cual es un tipo
+    ///    de dato DescuentosPlan, inicialmente 0 para todos.
+    /// :> La facturación de los socios que no se facturan mensualmente o que cambiaron de plan (facturacion_socios), el
+    ///    cual es un tipo de dato Vec(FacturacionSocio).
+    /// :> Los umbrales a partir de los cuales un socio es moroso o queda suspendido (politica_morosidad), el cual es un
+    ///    tipo de dato PoliticaMorosidad, inicialmente sin umbrales.
+    /// :> Los asientos de la cuenta corriente de los socios (asientos), el cual es un tipo de dato Vec(AsientoCuenta).
+    /// :> Los certificados de libre deuda emitidos, incluidos los revocados (certificados), el cual es un tipo de dato
+    ///    Vec(CertificadoLibreDeuda).
+    /// :> El tiempo, en milisegundos, durante el cual es valido un certificado de libre deuda (vigencia_certificados),
+    ///    el cual es un tipo de dato u64, inicialmente 30 dias.
+    /// :> El último apto médico registrado de cada socio (aptos_medicos), el cual es un tipo de dato Vec(AptoMedico).
+    /// :> Las clases de los deportes del club, con sus inscriptos (clases), el cual es un tipo de dato Vec(Clase).
+    /// :> Los profesores e instructores del club, activos o no (profesores), el cual es un tipo de dato Vec(Profesor).
+    /// :> Las asistencias registradas de los socios (asistencias), el cual es un tipo de dato Vec(Asistencia).
+    /// :> Las cuentas de los dispositivos de control de acceso, que solo pueden registrar ingresos
+    ///    (dispositivos_acceso), el cual es un tipo de dato Vec(AccountId).
+    /// :> Las instalaciones reservables del club (instalaciones), el cual es un tipo de dato Vec(Instalacion).
+    /// :> Las reservas de las instalaciones, incluidas las canceladas (reservas), el cual es un tipo de dato
+    ///    Vec(Reserva).
+    /// :> La anticipación mínima, en milisegundos, con la que puede cancelarse una reserva (plazo_cancelacion), el cual
+    ///    es un tipo de dato u64, inicialmente 24 horas.
+    /// :> Los montos ya cobrados que se descontarán del próximo pago emitido a cada socio, como los aranceles de
+    ///    reservas canceladas o lo cobrado por un plan de cuotas caido que no llegó a saldar un pago
+    ///    (reintegros_pendientes), el cual es un tipo de dato Vec((u32, u128)) con el DNI del socio y el monto.
+    /// 
+    /// Cuenta con distintos métodos que permiten: la creacion del club, la actualizacion de cualquier valor de sus 
+    /// costos, la verificacion del usuario que está llamando a los metodos, el registro de socios y staff's, cambio de
+    /// owner, obtención y consulta de los pagos, corrobaración para el beneficio, la emisión de pagos y el registro de
+    /// pagos, entre otros metodos.
+    #[ink(storage)]
+    pub struct ClubSemRust {
+        costos: CostosCategoria,
+        socios: Vec<Socio>,
+        pagos: Vec<Pago>,
+        owner: AccountId,
+        permitidos: Vec<AccountId>,
+        politica_activada: bool,
+        emision_ultimo_pago: Option<u64>,
+        proteccion_datos: bool,
+        recibos: Vec<Recibo>,
+        ultimo_nro_recibo: u32,
+        movimientos: Vec<MovimientoPago>,
+        ventana_reversion: u64,
+        auditoria: Vec<EntradaAuditoria>,
+        ultimo_nro_auditoria: u64,
+        retencion_auditoria: u32,
+        guardianes: Vec<AccountId>,
+        pausado: bool,
+        motivo_pausa: Option<String>,
+        importacion: Option<ImportacionEnCurso>,
+        lectores: Vec<AccountId>,
+        token_pago: Option<AccountId>,
+        puntos_categoria: PuntosCategoria,
+        vigencia_puntos: u64,
+        valor_punto: u128,
+        lotes_puntos: Vec<LotePuntos>,
+        movimientos_puntos: Vec<MovimientoPuntos>,
+        canjes_pendientes: Vec<(u32, u128)>,
+        grupos_familiares: Vec<(u32, u32)>,
+        carnets: Vec<Carnet>,
+        planes_cuotas: Vec<PlanCuotas>,
+        descuentos_plan: DescuentosPlan,
+        facturacion_socios: Vec<FacturacionSocio>,
+        politica_morosidad: PoliticaMorosidad,
+        asientos: Vec<AsientoCuenta>,
+        certificados: Vec<CertificadoLibreDeuda>,
+        vigencia_certificados: u64,
+        aptos_medicos: Vec<AptoMedico>,
+        clases: Vec<Clase>,
+        profesores: Vec<Profesor>,
+        asistencias: Vec<Asistencia>,
+        dispositivos_acceso: Vec<AccountId>,
+        instalaciones: Vec<Instalacion>,
+        reservas: Vec<Reserva>,
+        plazo_cancelacion: u64,
+        reintegros_pendientes: Vec<(u32, u128)>,
     }
-    #[ink::test]
-    fn club_sem_rust_new_costob_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+
+    impl ClubSemRust {
+        ///Recibe los 3 primeros costos de las categorias a,b y c en parametros de tip u128, recibe el valor del beneficio en un parametro de tipo u128
+        ///y recibe los pagos consecutivos para aplicar beneficio en un parametro de tipo u8, luego, crea el Vec de socios vacio, el vec de pagos vacio
+        ///la variable costos con todos los parametros ingresados previamente, setea al owner como el caller actual y crea el vec de permitidos vacio
+        #[ink(constructor)]
+        pub fn new(costo_a: u128, costo_b: u128, costo_c: u128, valor_beneficio: u128, pagos_consecutivos_para_beneficio: u8) -> Self {
+            return ClubSemRust::new_priv(costo_a, costo_b, costo_c, valor_beneficio, pagos_consecutivos_para_beneficio);
+        }
+
+        fn new_priv(costo_a: u128, costo_b: u128, costo_c: u128, valor_beneficio: u128, pagos_consecutivos_para_beneficio: u8) -> Self {
+            let socios = Vec::new();
+            let pagos = Vec::new();
+            let costos = CostosCategoria::new(costo_a, costo_b, costo_c, valor_beneficio, pagos_consecutivos_para_beneficio);
+            if costos.beneficio > costos.get_costo_minimo() {
+                panic!("El beneficio no puede superar el costo de ninguna categoria!");
+            }
+            let owner = ClubSemRust::env().caller();
+            let permitidos = Vec::new();
+            Self {socios, pagos, costos, owner, permitidos, emision_ultimo_pago: (None), politica_activada: (true), proteccion_datos: (false), recibos: Vec::new(), ultimo_nro_recibo: 0, movimientos: Vec::new(), ventana_reversion: 7.from_dias(), auditoria: Vec::new(), ultimo_nro_auditoria: 0, retencion_auditoria: 100, guardianes: Vec::new(), pausado: (false), motivo_pausa: (None), importacion: (None), lectores: Vec::new(), token_pago: (None), puntos_categoria: PuntosCategoria::new(0, 0, 0), vigencia_puntos: 365.from_dias(), valor_punto: 1, lotes_puntos: Vec::new(), movimientos_puntos: Vec::new(), canjes_pendientes: Vec::new(), grupos_familiares: Vec::new(), carnets: Vec::new(), planes_cuotas: Vec::new(), descuentos_plan: DescuentosPlan::new(0, 0, 0), facturacion_socios: Vec::new(), politica_morosidad: PoliticaMorosidad::new(0, 0, 0, 0), asientos: Vec::new(), certificados: Vec::new(), vigencia_certificados: 30.from_dias(), aptos_medicos: Vec::new(), clases: Vec::new(), profesores: Vec::new(), asistencias: Vec::new(), dispositivos_acceso: Vec::new(), instalaciones: Vec::new(), reservas: Vec::new(), plazo_cancelacion: 24.from_horas(), reintegros_pendientes: Vec::new()}
+        }
+        
+        ///La función get_socios devuelve la lista "cruda" de socios registrados que almacena el club. Se recibe el parametro self (ClubSemRust)
+        ///y se retorna una estructura Vec<Socio>. El metodo cumple con la politica de autorizacion verificando los permisos
+        ///necesarios, devolviendo un panic en caso de no contar con los mismos. En caso de no existir socios se devuelve un vector vacio.
+        #[ink(message)]
+        pub fn get_socios (&self) -> Vec<Socio> {
+            return self.get_socios_priv();
+        }
+
+        fn get_socios_priv (&self) -> Vec<Socio> {
+            if self.verificar_permisos() || self.es_lector() {
+                let mut vec_copia = Vec::new();
+                vec_copia.clone_from(&self.socios);
+                return vec_copia;
+            }
+            return panic!("No se cuenta con los permisos necesarios!");
+        }
+
+	    /// Este método, permite realizar un cambio de Owner del Club, sólo si el que realiza el llamado a este método,
+        /// es el mismo Owner. Dicho cambio de owner, se realiza por el ingresado como parámetro (nuevo_owner). En caso
+        /// que cualquier otro usuario realice esta operación, este método arrojará un panic!, abortando la operación 
+        /// del método. El metodo recibe como parametros una referencia mutable de sí mismo (ClubSemRust), y el nuevo 
+        /// owner, que es un tipo de dato AccountId.
+        #[ink(message)]
+        pub fn set_owner(&mut self, nuevo_owner: AccountId) -> bool {
+            return self.set_owner_priv(nuevo_owner);
+        }
+
+        fn set_owner_priv(&mut self, nuevo_owner: AccountId) -> bool{
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                let anterior = self.owner;
+                self.owner = nuevo_owner;
+                self.registrar_auditoria(AccionAdministrativa::CambioOwner, Some(ValorAuditado::Cuenta(anterior)), Some(ValorAuditado::Cuenta(nuevo_owner)));
+                return true;
+            } 
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///El get politica de autorizacion primero comprueba que el caller sea admin o staff y luego
+        ///retorna el valor de la variable politica_activada, de no ser admin o staff arrojara un panic
+        #[ink(message)]
+        pub fn get_politica_autorizacion(&self) ->bool{
+            return self.get_politica_autorizacion_priv();
+        }
+
+
+        fn get_politica_autorizacion_priv(&self) -> bool {
+            if (self.es_admin()) || (self.es_staff()) {
+                return self.politica_activada;
+            } 
+            return panic!("No se cuenta con los permisos necesarios!");
+        }
+
+        ///La función toggle_politica_autorizacion activa y desactiva la política de autorización en la administración
+        ///del ClubSemRust. Se aplica la operación logica NOT sobre el valor del booleano correspondiente a la política
+        ///de autorización del club. Se recibe la referencia mutable del ClubSemRust y se retorna el nuevo estado de la 
+        ///política. Es importante notar que solo el Adress con característica de Owner puede ejecutar exitosamente el 
+        ///método, de no contar con los permisos requeridos se lanza un panic! notando el error.
+        #[ink(message)]
+        pub fn toggle_politica_autorizacion(&mut self) -> bool {
+            return self.toggle_politica_autorizacion_priv();
+        }
+
+        fn toggle_politica_autorizacion_priv(&mut self) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                self.politica_activada = !self.politica_activada;
+                self.registrar_auditoria(AccionAdministrativa::TogglePolitica, Some(ValorAuditado::Booleano(!self.politica_activada)), Some(ValorAuditado::Booleano(self.politica_activada)));
+                return self.politica_activada;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+	    /// Este método, permite agregar un usuario permitido para operar, que lo definimos como "staff", solo si el
+        /// método es llamado por el Owner, y si dicho staff no está cargado. De esta forma, si se cumplen las 
+        /// condiciones descriptas anteriormente, el staff será agregado exitosamente, devolviendo un "true" como 
+        /// demostracion. De lo contrario, si alguna de ellas no se cumple, el método arrojará un panic! con un mensaje
+        /// informando la situacion. El método recibe como párametro una referencia mutable de sí mismo (ClubSemRust), y 
+        /// el nuevo staff (nuevo_staff), que es un tipo de dato AccountId.
+        #[ink(message)]
+        pub fn agregar_staff(&mut self, nuevo_staff: AccountId) -> bool {
+            return self.agregar_staff_priv(nuevo_staff);
+        }
+
+        fn agregar_staff_priv(&mut self, nuevo_staff: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) && (!self.permitidos.contains(&nuevo_staff)) {
+                self.permitidos.push(nuevo_staff);
+                self.registrar_auditoria(AccionAdministrativa::AgregarStaff, None, Some(ValorAuditado::Cuenta(nuevo_staff)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner o la cuenta ingresada ya es Staff!");
+        }
+
+       ///Primero se chequea que el caller sea el owner y que el accountId que se quiere quitar del staff
+       ///exista en el vec de permitidos. Luego se obtiene la posicion del accountId que queremos quitar del 
+       ///vec de permitidos y finalmente se remueve la posicion en la cual se encontro el account id
+       ///de no ser owner o no encontrarse el accoundId, se arrojara un panick 
+        #[ink(message)]
+        pub fn quitar_staff(&mut self, quitar: AccountId) -> bool {
+            return self.quitar_staff_priv(quitar);
+        }
+
+        fn quitar_staff_priv(&mut self, quitar: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) && (self.permitidos.contains(&quitar)) {
+                let pos = self.permitidos.iter().position(|staff| staff == &quitar).unwrap();
+                self.permitidos.remove(pos);
+                self.registrar_auditoria(AccionAdministrativa::QuitarStaff, Some(ValorAuditado::Cuenta(quitar)), None);
+                return true;
+            }
+            return panic!("No se cuenta con rango Owner o la cuenta ingresada no es Staff!");
+        }
+
+        ///El método agregar_lector otorga a una cuenta el permiso de solo lectura, que la habilita a llamar a get_socios,
+        ///get_socio, get_pagos_totales y consulta_de_pagos aunque la politica de autorización esté activada, sin poder modificar nada.
+        ///Está pensado para el contrato de reportes, que consulta al club desde su propia cuenta. Solo el Owner puede
+        ///llamarlo, y arrojará un panic si no lo es o si la cuenta ya es lectora. Retorna true.
+        #[ink(message)]
+        pub fn agregar_lector(&mut self, nuevo_lector: AccountId) -> bool {
+            return self.agregar_lector_priv(nuevo_lector);
+        }
+
+        fn agregar_lector_priv(&mut self, nuevo_lector: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) && (!self.lectores.contains(&nuevo_lector)) {
+                self.lectores.push(nuevo_lector);
+                self.registrar_auditoria(AccionAdministrativa::AgregarLector, None, Some(ValorAuditado::Cuenta(nuevo_lector)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner o la cuenta ingresada ya es lectora!");
+        }
+
+        ///El método quitar_lector revoca el permiso de solo lectura de una cuenta. Solo el Owner puede llamarlo, y
+        ///arrojará un panic si no lo es o si la cuenta no es lectora. Retorna true.
+        #[ink(message)]
+        pub fn quitar_lector(&mut self, quitar: AccountId) -> bool {
+            return self.quitar_lector_priv(quitar);
+        }
+
+        fn quitar_lector_priv(&mut self, quitar: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) && (self.lectores.contains(&quitar)) {
+                self.lectores.retain(|lector| lector != &quitar);
+                self.registrar_auditoria(AccionAdministrativa::QuitarLector, Some(ValorAuditado::Cuenta(quitar)), None);
+                return true;
+            }
+            return panic!("No se cuenta con rango Owner o la cuenta ingresada no es lectora!");
+        }
+
+        ///Devuelve true si el caller tiene el permiso de solo lectura.
+        fn es_lector(&self) -> bool {
+            return self.lectores.contains(&self.env().caller());
+        }
+
+        fn es_admin(&self)-> bool{
+            return self.env().caller() == self.owner;
+        }
+
+	    /// Este método, permite realizar comprobaciones internas en el programa, verificando si el usuario que está
+        /// llamando es reconocible como staff. De esta forma, si es reconocido como staff, devuelve "true", o "false"
+        /// de lo contrario. El método recibe como párametro una referencia a si mismo (ClubSemRust).
+        fn es_staff(&self) -> bool {
+            return self.permitidos.contains(&self.env().caller());     
+        }
+
+       /// Para empezar se chequea que el caller sea admin o staff, luego, con los parametros recibidos(el id de categoria y el nuevo costo)
+       /// se llama a la funcion set_costo de la variable costos del club, actualizando asi, el nuevo costo de una categoria en especifico
+       /// recibida por parametro. para comprobar que la categoria sea una permitida se llama al metodo categoria_from_id
+       /// la cual arrojara un panic si el id ingresado no es o 1 o 2 o 3. Por otra parte, si el caller no es admin o staff se arrojara un panic
+        #[ink(message)] 
+        pub fn actualizar_costos_categoria(&mut self, id_categoria : u32, nuevo_costo : u128,) -> bool {
+            return self.actualizar_costos_categoria_priv(id_categoria, nuevo_costo);
+        }
+
+        pub fn actualizar_costos_categoria_priv(&mut self, id_categoria: u32, nuevo_costo: u128) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) || (self.es_staff()) {
+
+                if let Some(categoria) = CategoriasSocios::categoria_from_id(&id_categoria){
+                    let anterior = self.costos.get_costo(&categoria);
+                    if self.costos.set_costo(nuevo_costo, &categoria).is_err() {
+                        return panic!("El beneficio no puede superar el costo de ninguna categoria!");
+                    }
+                    self.registrar_auditoria(AccionAdministrativa::CambioCostoCategoria, Some(ValorAuditado::CostoCategoria(categoria.clone(), anterior)), Some(ValorAuditado::CostoCategoria(categoria.clone(), nuevo_costo)));
+                    return true;
+                }
+            }
+            return panic!("No se cuenta con los permisos necesarios!");
+        }
+
+	    /// Este metodo, se encarga de verificar que se cumpla alguno de los 3 permisos para realizar una acción: que el
+        /// usuario que llama al metodo sea owner, sea staff, o que la politica de autorizacion este desactivada. De 
+        /// esta manera, realiza una verificacion entre las 3 condiciones: con que una condicion de ellas resultase 
+        /// verdadera, la expresión resulta ser verdadera y devuelve "true", en cambio, si ninguna devuelve "true", la 
+        /// expresión devolverá "false".
+        /// 
+        /// Este método recibe una referencia a si mismo (ClubSemRust), y devuelve un tipo de dato bool.
+        #[ink(message)]
+        pub fn verificar_permisos(&self) -> bool {
+            return self.verificar_permisos_priv();
+        }
+
+        fn verificar_permisos_priv(&self) -> bool {
+              return (self.es_admin()) || (self.es_staff()) || (!self.politica_activada);
+        }
+
+        ///Este método altera el valor actual del descuento aplicable para aquellos socios que cumplan las condiciones
+        ///necesarias, recibiendo el nuevo importe y actualizando el importe del beneficio que el club ofrece. Esta función
+        ///únicamente puede ser ejecutada por el usuario administrador o los integrantes del staff, cualquier otro usuario
+        ///encontrará denegado el intento de actualización.
+        #[ink(message)]
+        pub fn actualizar_costo_beneficio(&mut self, nuevo_costo : u128) -> bool {
+            return self.actualizar_costo_beneficio_priv (nuevo_costo);
+        }
+
+        fn actualizar_costo_beneficio_priv(&mut self, nuevo_costo : u128) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) || (self.es_staff()) {
+                let anterior = self.costos.beneficio;
+                if self.costos.set_beneficio(nuevo_costo).is_err() {
+                    return panic!("El beneficio no puede superar el costo de ninguna categoria!");
+                }
+                self.registrar_auditoria(AccionAdministrativa::CambioBeneficio, Some(ValorAuditado::Monto(anterior)), Some(ValorAuditado::Monto(nuevo_costo)));
+                return true;
+            }
+
+            return panic!("No se cuenta con los permisos necesarios!");
+        }
+
+	    /// Este método permite modificar la cantidad de pagos consecutivos necesarios para acceder a un beneficio. 
+        /// Dicha modificación, será posible solamente si el usuario que realiza el llamado es Owner o Staff. De 
+        /// esta forma, si el usuario cumple con uno de estos criterios, se realizará la actualizacion, y se devolverá
+        /// un "true" como muestra de ello. De lo contrario, arrojará un panic! con un mensaje informando la situación.
+        /// El metodo recibe como parametros una referencia mutable de sí mismo (ClubSemRust), y la nueva cantidad de
+        /// pagos consecutivos necesarios para acceder al descuento (nuevo_valor), que es un tipo de dato u8.
+        #[ink(message)]
+        pub fn actualizar_pagos_consecutivos_para_descuento(&mut self, nuevo_valor: u8) -> bool {
+            return self.actualizar_pagos_consecutivos_para_descuento_priv (nuevo_valor);
+        }
+
+        fn actualizar_pagos_consecutivos_para_descuento_priv(&mut self, nuevo_valor: u8) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) || (self.es_staff()) {
+                self.registrar_auditoria(AccionAdministrativa::CambioPagosConsecutivos, Some(ValorAuditado::Cantidad(self.costos.pagos_consecutivos_para_beneficio as u64)), Some(ValorAuditado::Cantidad(nuevo_valor as u64)));
+                return self.costos.set_pagos_consecutivos_para_beneficio(nuevo_valor);
+            }
+
+            return panic!("No se cuenta con los permisos necesarios!");
+        }
+
+        ///Se comprueba que el caller sea admin o staff o que la politica de autorizacion este desactivada, luego
+        ///se busca al socio en el vec de socios con un iter, y si el metodo find retorna un some, devuelve un clone
+        ///del socio encontrado, sino retorna un None. Por otra parte, si el caller no es admin o staff y la politica esta activada
+        ///el metodo arrojara un panick
+        #[ink(message)]
+        pub fn get_socio (&self, dni: u32) -> Option<Socio> {
+            return self.get_socio_priv(dni);
+        }
+
+        fn get_socio_priv (&self, dni: u32) -> Option<Socio> {
+
+            if self.verificar_permisos() || self.es_lector() {
+                if let Some(socio) = self.socios.iter().find(|socio| socio.dni == dni) {
+                    return Some (socio.clone());
+                }
+                else {
+                    return None;
+                }
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///El método existe_socio simplifica la verificación de la existencia de un socio, recibiendo el dni del 
+        ///socio a verificar y enmascarando el método de get_socio retorna el booleano correspondiente a la 
+        ///afirmación de la firma.
+        fn existe_socio(&self, dni: u32) -> bool {
+            return self.get_socio(dni).is_some();
+        }
+        
+	    /// Este método, se encarga de realizar un resumen de los pagos de un socio, si se ingresa su DNI, de lo 
+        /// contrario mostrará un resumen de todos los pagos de todos los socios. De esta forma, si el usuario que llama
+        /// al método cumple con los permisos necesarios, se realizará, la verificación de si se ha ingresado un DNI o
+        /// no, de lo contrario, el método arrojará un panic informando lo sucedido.
+        /// 
+        /// De haber ingresado un DNI, se obtiene el socio mediante su DNI, y se recorren todos los pagos (ya sea 
+        /// un pago realizado o un pago pendiente), buscando todos aquellos pagos que contengan el DNI del socio, y se 
+        /// extrae de ellos su monto y se agregan a un Vec. Una vez obtenidos todos los pagos de un socio, para devolver
+        /// lo pedido, se crea una tupla de 2 elementos, la cual contiene como primer elemento al Socio con toda su info,
+        /// y como segundo elemento al Vec obtenido anteriormente, con todos los pagos de dicho socio. Como el método
+        /// esta planteado de forma que se pueda emitir una consulta por un socio en especial, o por todos los socios,
+        /// este método devuelve un Vec de tuplas, donde cada tupla es igual a la descripta anteriormente, por lo tanto, 
+        /// esta tupla obtenida se la inserta en un Vec, siendo este último, el tipo de dato que devuelve el metodo: 
+        /// Vec((Socio, Vec(u128))). De no encontrar a un socio con el DNI ingresado, el método arrojará un panic 
+        /// infromando lo sucedido.
+        /// 
+        /// Si se detecta que no se ha ingresado un DNI, se hace lo mencionado anteriormente para 
+        /// un DNI ingresado, pero a cada socio, se le extrae el DNI para detectar sus pagos, y agregar el monto al Vec de 
+        /// montos. Cuando se termina de procesar a un socio, se crea su tupla y se agrega al Vec de tuplas.
+        /// 
+        /// Este método, recibe una referencia a sí mismo (ClubSemRust), un tipo de dato Option de u32 (option_dni), y como destacamos 
+        /// anteriormente, devuelve un tipo de dato Vec, que contiene tuplas, donde cada tupla, contiene un socio y otro
+        /// Vec que contiene los montos: Vec((Socio, Vec(u128))).
+        #[ink(message)]
+        pub fn consulta_de_pagos(&self, option_dni: Option<u32>) -> Vec<(Socio, Vec<u128>)> { 
+            return self.consulta_de_pagos_priv(option_dni);
+        }
+
+        fn consulta_de_pagos_priv(&self, option_dni: Option<u32>) -> Vec<(Socio, Vec<u128>)> {
+            if self.verificar_permisos() || self.es_lector() {
+
+                if let Some (dni) = option_dni {
+                    if let Some (socio) = self.get_socio(dni) {
+                        let mut vec_tuplas = Vec::new();
+                        let mut vec_pagos = Vec::new();
+                        for pago in &self.pagos {
+                            if pago.dni == socio.dni {
+                                vec_pagos.push(pago.monto);
+                            }
+                        }
+                        let tupla = (socio.clone(), vec_pagos);
+                        vec_tuplas.push(tupla);
+                        return vec_tuplas;
+                    }
+
+                    return panic!("No se ha encontrado un socio con el DNI ingresado!")
+                }
+                else {
+                    let mut vec_tuplas = Vec::new();
+                    for socio in &self.socios {
+                        let mut vec_pagos = Vec::new();
+                        for pago in &self.pagos {
+                            if pago.dni == socio.dni {
+                                vec_pagos.push(pago.monto);
+                            }
+                        }
+                        let tupla = (socio.clone(), vec_pagos);
+                        vec_tuplas.push(tupla);
+                    }
+                    return vec_tuplas;
+                }
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///Para empezar se comprueba que el caller sea admin o staff o que la politica de autorizacion este desactivada.
+        ///luego crea un vec vacio, posteriormente, recorre el vec de pagos y chequea si en cada pago, el dni del pago es
+        ///igual al ingresado, entonces se agrega al vec recientemente creado, por ultimo retorna el vec luego del for.
+        ///Si el caller no tenia permisos necesarios para llamar a la funcion, entonces se arrojara un panick.
+        ///Cabe aclarar que este metodo tiene una doble funcionalidad, si se quiere recibir los pagos totales de una persona en
+        ///especifico, se recibira un Some(con un dni) y si se quiere recibir todos los pagos del club, se recibira un None como
+        ///parametro y cuando en el if se detecte que el DNi es none, retornara los pagos totales del club
+        #[ink(message)]
+        pub fn get_pagos_totales (&self, dni: Option<u32>) -> Vec<Pago> {
+            return self.get_pagos_totales_priv(&dni);
+        }
+
+
+        fn get_pagos_totales_priv (&self, dni : &Option<u32>) -> Vec<Pago>{
+            if self.verificar_permisos() || self.es_lector() {
+                let mut aux_vec = Vec::new();
+                if let Some(dni) = dni{
+                    for pago in &self.pagos{
+                        if pago.dni == *dni{
+                            aux_vec.push(pago.clone());
+                        }
+                    }
+                } else {
+                    aux_vec = self.pagos.clone();
+                }
+                return aux_vec;
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+	    /// Este método se encarga de verificar si a un socio le corresponde la bonificación en un pago al momento de
+        /// ejecutarlo. Dicha bonificacion se obtiene si: 
+        /// :> Pago a tiempo los últimos pagos, donde la cantidad de pagos es probista por el club.
+        /// :> Si dentro de la cantidad de pagos descripta anteriomente, no hubo ya un pago bonifcado.
+        /// 
+        /// Para comenzar, se obtiene todos los pagos realizados por un socio. Luego, se verifica si la cantidad de pagos
+        /// obtenidos es mayor o igual a la cantidad de pagos necesarios para acceder al beneficio, ya que de no ser así,
+        /// el socio no puede obtener el beneficio. Ya si hay tantos pagos (ya sea pendientes como realizados) como los
+        /// necesarios para obtener el beneficio, se obtienen tantos últimos pagos, como indique la cantidad de 
+        /// últimos pagos necesarios probista por el club y se almacenan en un Vec. Una vez obtenidos los pagos necesarios
+        /// para la verificacion. Se empieza a verificar cada pago, corroborando que:
+        /// :> Ninguno de ellos sea un pago pendiente (que tenga una fecha de pago)
+        /// :> Que la fecha de pago, sea inferior a la de vencimiento
+        /// :> Que el pago no haya sido bonificado
+        /// 
+        /// Si alguno de estos 3 requisitos no se ha cumplido en algun pago de los necesarios, el método devolverá
+        /// "false", indicando que el socio no puede obtener el beneficio, ya que el socio no cumple las condiciones
+        /// necesarias. De lo contrario, si cumplió con todos los requisitos en todos los pagos necesarios, el método
+        /// devolverá "true", indicando que el socio cumple con las condiciones para obtener el beneficio.
+        /// 
+        /// Este método recibe una referencia a sí mismo (ClubSemRust), y un tipo de dato u32 que corresponde al DNI de un
+        /// socio (dni), y devuelve un tipo de dato bool, que indica si el socio está en condiciones de recibir el
+        /// beneficio, o no. La regla se encuentra en nucleo_facturacion::corresponde_bonificacion, este método solo obtiene
+        /// los pagos del socio y se la aplica.
+        fn corroborar_bonificacion(&self, dni: u32) -> bool {
+            let pagos_del_socio: Vec<Pago> = self.get_pagos_totales(Some(dni));
+            return nucleo_facturacion::corresponde_bonificacion(&pagos_del_socio, self.costos.pagos_consecutivos_para_beneficio);
+        }
+
+        /// Para esta funcion, primero se comprueba que la variable emision_ultimo_pago contenga algo, de no ser asi, no se han registrado socios
+       /// y no tiene sentido disparar el metodo, con lo cual retorna un panick. En el caso de que la emision_ultimo_pago contenga algo, se obtiene
+       /// el dia_actual en una variable. Luego se comprueba que el caller sea admin u owner y que el dia actual sea mayor o igual a 30 dias posteriores
+       /// a la ultima emision(que nos guardamos previamente en la variable fecha_emision) una vez hechas estas comprobaciones. Para cada socio del vec de socios
+       /// se llama a la funncion corroborar_bonificacion, y esta retorna en la variable bonificado si el proximo pago debe ser bonificado o no. Luego se crea una
+       /// fecha de vencimiento que sera el dia de hoy + 30 dias y una variable costo cuyo valor se obtiene con la funcion get_costo. Posteriormente
+       /// si la variable bonificado previamente dio como resultado true, se le descuenta al costo el beneficio de la variable costos.beneficio
+       /// por ultimo,se crea el nnuevo pago con el dni del socio, el costo, la fecha de vencimiento, fecha de pago en None y la variable booleana que
+       /// indica si fue bonificado o no. Finalmente se pushea un clone del pago al vec de pagos del club.
+       /// Una vez terminado de repetir esto para todos los socios, se actualiza la emision del ultimo pago con la variable previamente creada, dia_actual 
+       /// y se retorna true. Si el caller no era admin, staff o la politica estaba desactivada y el dia actual no era mayor o igual a la ultima fecha + 30 dias
+       /// entonces el programa arrojara un panick
+       /// Los socios con un plan de facturación de más de un mes solo reciben cuota cuando termina el período que cubre la
+       /// anterior, y esa cuota es la mensual (ya bonificada si corresponde) por los meses del plan, menos su descuento.
+       /// A los socios suspendidos según la politica de morosidad no se les emite cuota.
+       /// Los aranceles de reservas a sumar a la próxima cuota de un socio que no recibe cuota en esta emisión, por su plan
+       /// o por estar suspendido, se emiten igual en un pago aparte con el mismo vencimiento. Los reintegros pendientes del
+       /// socio se descuentan del pago que se le emite.
+        #[ink(message)]
+        pub fn emitir_pago_mensual (&mut self) -> bool {
+            self.emitir_pago_mensual_priv()
+        }
+
+        fn emitir_pago_mensual_priv (&mut self) -> bool {
+            self.verificar_no_pausado();
+            if let Some (fecha_emision) = self.emision_ultimo_pago {
+                let dia_actual = self.get_milisegundos_actuales();
+                if self.verificar_permisos() && nucleo_facturacion::puede_emitir(fecha_emision, dia_actual) { 
+                    let fecha_venci = verificar_aritmetica(nucleo_facturacion::vencimiento_cuota(self.env().block_timestamp()));
+                    for socio in self.socios.clone() {
+                        let plan = if self.estado_socio(socio.dni) == EstadoSocio::Suspendido { None } else { self.iniciar_ciclo_facturacion(socio.dni) };
+                        let Some (plan) = plan else {
+                            let aranceles = self.cobrar_reservas(socio.dni);
+                            if aranceles > 0 {
+                                let monto = self.aplicar_reintegro(socio.dni, aranceles);
+                                if monto > 0 {
+                                    self.pagos.push(Pago::new(socio.dni, monto, fecha_venci, None, false));
+                                }
+                            }
+                            continue;
+                        };
+                        let bonificado = self.corroborar_bonificacion(socio.dni);
+                        let mensual = verificar_aritmetica(nucleo_facturacion::precio_cuota(self.costos.get_costo(&socio.categoria), self.costos.beneficio, bonificado));
+                        let cargo = self.precio_plan(self.costos.get_costo(&socio.categoria), &plan);
+                        let mut costo = self.precio_plan(mensual, &plan);
+                        self.registrar_asiento(TipoAsiento::Cargo, socio.dni, cargo, None);
+                        self.registrar_asiento(TipoAsiento::Bonificacion, socio.dni, cargo - costo, None);
+                        if let Some (pos) = self.canjes_pendientes.iter().position(|(dni, _)| *dni == socio.dni) {
+                            let descuento = self.canjes_pendientes.remove(pos).1.min(costo);
+                            costo -= descuento;
+                            self.registrar_asiento(TipoAsiento::Descuento, socio.dni, descuento, None);
+                        }
+                        let aranceles = self.cobrar_reservas(socio.dni);
+                        costo = verificar_aritmetica(Monto(costo).sumar(Monto(aranceles))).valor();
+                        costo = self.aplicar_reintegro(socio.dni, costo);
+                        let nuevo_pago = Pago::new(socio.dni, costo, fecha_venci, None, bonificado);
+                        self.pagos.push(nuevo_pago.clone());
+                    }
+                    self.emision_ultimo_pago = Some (dia_actual);
+                    return true;
+                }
+                else {
+                    return panic!("No se cuenta con los permisos necesarios, la politica de autorizacion se encuentra activada, o no han pasado 30 dias desde la ultima emision!");
+                } 
+            }
+            else {
+                return panic!("No se han emitido pagos aun!");
+            }
+        }
+
+        ///La función registrar_socio permite incorporar un nuevo socio al club, recibiendo los datos necesarios : dni, apellido y nombre, id de
+        ///la categoría del asociado y, en el caso de que se forme parte de la categoría B, el id del deporte elegido. El método verifica permisos,
+        ///por lo que es una acción que usuarios selectos pueden realizar a menos que la política de autorización se encuentre desactivada. La función
+        ///rechazará el pedido en caso de que cualquiera de los IDs sean incorrectos, en caso de que el dni ingresado pertenezca a un socio ya
+        ///afiliado, de que tenga encendido el bit reservado a los socios protegidos o de que no se cuenten con los permisos requeridos. Dada una ejecución exitosa, el método retornará la información del
+        ///primer pago pendiente del nuevo miembro. 
+        #[ink(message)]
+        pub fn registrar_socio (&mut self, dni: u32, apellido_y_nombre: String, id_categoria: u32, id_deporte: Option<u32>) -> Option<Pago> {
+            return self.registrar_socio_priv(dni, apellido_y_nombre, id_categoria, id_deporte);
+        }
+
+        fn registrar_socio_priv (&mut self, dni: u32, apellido_y_nombre: String, id_categoria: u32, id_deporte: Option<u32>) -> Option<Pago> {
+            self.verificar_no_pausado();
+            if self.proteccion_datos {
+                return panic!("La proteccion de datos se encuentra activada, el socio debe registrarse con registrar_socio_protegido!");
+            }
+            if dni & MARCA_IDENTIFICADOR_PROTEGIDO != 0 {
+                return panic!("El DNI ingresado no es valido!");
+            }
+            if self.verificar_permisos() && (!self.existe_socio(dni)) {
+                let option_categoria = CategoriasSocios::categoria_from_id(&id_categoria);
+                match option_categoria {
+                    Some (categoria) => {
+                        let nuevo_socio = Socio::new(apellido_y_nombre, dni, categoria, Deporte::deporte_from_id(&id_deporte));
+                        return Some(self.alta_socio(nuevo_socio));
+                    }    
+                    None => return panic!("La id de categoria no está entre las presentes!"),
+                }
+            }
+            if self.existe_socio(dni) {
+                return panic!("Ese socio existe!");
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///La función registrar_socio_protegido permite incorporar un nuevo socio sin guardar sus datos personales en texto plano.
+        ///En lugar del dni y el apellido y nombre, recibe sus compromisos (calculados fuera de la cadena con CompromisoIdentidad::calcular_dni
+        ///y CompromisoIdentidad::calcular_apellido_y_nombre, usando una sal que el socio conserva), junto al id de la categoría y el id
+        ///del deporte. Los permisos y las validaciones de los IDs son los mismos que en registrar_socio, y se rechaza el pedido si ya
+        ///existe un socio con el mismo compromiso del DNI. Como identificador se usa el derivado del compromiso o, si ya está en
+        ///uso, el siguiente libre. Dada una ejecución exitosa, el método retornará el primer pago pendiente del nuevo miembro, cuyo
+        ///dni es ese identificador.
+        #[ink(message)]
+        pub fn registrar_socio_protegido (&mut self, compromiso_dni: Hash, compromiso_apellido_y_nombre: Hash, id_categoria: u32, id_deporte: Option<u32>) -> Option<Pago> {
+            return self.registrar_socio_protegido_priv(compromiso_dni, compromiso_apellido_y_nombre, id_categoria, id_deporte);
+        }
+
+        fn registrar_socio_protegido_priv (&mut self, compromiso_dni: Hash, compromiso_apellido_y_nombre: Hash, id_categoria: u32, id_deporte: Option<u32>) -> Option<Pago> {
+            self.verificar_no_pausado();
+            let compromiso = CompromisoIdentidad::new(compromiso_dni, compromiso_apellido_y_nombre);
+            let registrado = self.socios.iter().any(|socio| socio.compromiso.as_ref().map(|c| c.dni) == Some(compromiso_dni));
+            if self.verificar_permisos() && !registrado {
+                if let Some (categoria) = CategoriasSocios::categoria_from_id(&id_categoria) {
+                    let identificador = self.identificador_libre(&compromiso);
+                    let nuevo_socio = Socio::new_protegido(compromiso, identificador, categoria, Deporte::deporte_from_id(&id_deporte));
+                    return Some(self.alta_socio(nuevo_socio));
+                }
+                return panic!("La id de categoria no está entre las presentes!");
+            }
+            if registrado {
+                return panic!("Ese socio existe!");
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///Retorna el identificador del compromiso recibido o, si ya lo usa algún socio o algún pago (de un socio que
+        ///renunció, por ejemplo), el siguiente sin usar, recorriendo los identificadores con la marca de socio protegido.
+        fn identificador_libre (&self, compromiso: &CompromisoIdentidad) -> u32 {
+            let mut identificador = compromiso.identificador();
+            while self.socios.iter().any(|socio| socio.dni == identificador) || self.pagos.iter().any(|pago| pago.dni == identificador) {
+                identificador = identificador.wrapping_add(1) | MARCA_IDENTIFICADOR_PROTEGIDO;
+            }
+            return identificador;
+        }
+
+        ///Agrega al socio recibido al Vec de socios junto con su primer pago pendiente, que vence a los 10 dias y cuyo monto
+        ///es el costo de su categoria, y le emite su carnet de socio, todavía sin cuenta titular. Si es el primer socio del club, toma la fecha actual como la de la ultima emision.
+        ///Retorna el pago pendiente creado.
+        fn alta_socio (&mut self, nuevo_socio: Socio) -> Pago {
+            let fecha_hoy = self.env().block_timestamp();
+            let fecha_venci = verificar_aritmetica(nucleo_facturacion::vencimiento_alta(fecha_hoy));
+            let pago_pend = Pago::new(nuevo_socio.dni, self.costos.get_costo(&nuevo_socio.categoria), fecha_venci, None, false);
+            self.pagos.push(pago_pend.clone());
+            self.registrar_asiento(TipoAsiento::Cargo, pago_pend.dni, pago_pend.monto, None);
+            self.registrar_auditoria(AccionAdministrativa::RegistroSocio, None, Some(ValorAuditado::Dni(nuevo_socio.dni)));
+            self.emitir_carnet(&nuevo_socio);
+            self.socios.push(nuevo_socio);
+            if self.emision_ultimo_pago.is_none() {
+                self.emision_ultimo_pago = Some (fecha_hoy);
+            }
+            return pago_pend;
+        }
+
+        ///Emite el carnet de socio del socio recibido, con el numero siguiente al último emitido y sin cuenta titular.
+        fn emitir_carnet (&mut self, socio: &Socio) {
+            let carnet = Carnet {id: self.carnets.len() as u32 + 1, dni: socio.dni, categoria: socio.categoria.clone(), deporte: socio.deporte.clone(), titular: None, emitido: self.env().block_timestamp(), revocado: false};
+            self.carnets.push(carnet);
+        }
+
+        ///El método get_socio_protegido permite que quien conoce el dni y la sal de un socio registrado con la protección de datos
+        ///obtenga su información, incluido el identificador con el que figura en sus pagos. Como la consulta se resuelve sin
+        ///transaccion, el dni y la sal no quedan registrados en la cadena. Retorna None si ningun socio tiene ese compromiso.
+        #[ink(message)]
+        pub fn get_socio_protegido (&self, dni: u32, sal: [u8; 32]) -> Option<Socio> {
+            return self.get_socio_protegido_priv(dni, sal);
+        }
+
+        fn get_socio_protegido_priv (&self, dni: u32, sal: [u8; 32]) -> Option<Socio> {
+            let compromiso_dni = CompromisoIdentidad::calcular_dni(dni, &sal);
+            return self.socios.iter().find(|socio| socio.compromiso.as_ref().map(|c| c.dni) == Some(compromiso_dni)).cloned();
+        }
+
+        ///El método verificar_membresia sirve como prueba de membresía para terceros: a partir del dni y la sal provistos fuera
+        ///de la cadena, retorna true si existe un socio registrado con ese compromiso, y false en caso contrario. No requiere permisos.
+        #[ink(message)]
+        pub fn verificar_membresia (&self, dni: u32, sal: [u8; 32]) -> bool {
+            return self.get_socio_protegido_priv(dni, sal).is_some();
+        }
+
+        ///La función toggle_proteccion_datos activa y desactiva la protección de datos personales. Mientras está activada,
+        ///registrar_socio rechaza los registros con datos en texto plano y solo se admiten altas con registrar_socio_protegido.
+        ///Solo el Owner puede ejecutarla, de lo contrario se lanza un panic!. Retorna el nuevo estado de la protección.
+        #[ink(message)]
+        pub fn toggle_proteccion_datos(&mut self) -> bool {
+            return self.toggle_proteccion_datos_priv();
+        }
+
+        fn toggle_proteccion_datos_priv(&mut self) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                self.proteccion_datos = !self.proteccion_datos;
+                self.registrar_auditoria(AccionAdministrativa::ToggleProteccionDatos, Some(ValorAuditado::Booleano(!self.proteccion_datos)), Some(ValorAuditado::Booleano(self.proteccion_datos)));
+                return self.proteccion_datos;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///Retorna el valor de la variable proteccion_datos. Al no tratarse de un dato sensible, puede consultarlo cualquiera.
+        #[ink(message)]
+        pub fn get_proteccion_datos(&self) -> bool {
+            return self.proteccion_datos;
+        }
+
+	    /// Este método se encarga de registrar un pago de un socio.
+        /// 
+        /// Para comenzar, se verifica que el usuario que llama al método cumpla con los permisos necesarios. De no ser 
+        /// así, se arrojará un panic informando lo sucedido. De contar con permisos necesarios, comienza la busqueda
+        /// del pago, en el Vec de pagos. Así, se intenta ubicar a un pago que cumpla con las condiciones necesarias, 
+        /// que son:
+        /// :> Que el pago contenga el DNI del socio.
+        /// :> Que el monto ingresado a abonar, sea el mismo que el del pago.
+        /// :> Que el pago, no tenga fecha de pago.
+        /// 
+        /// De no encontrar un pago que cumpla con estas condiciones, se arrojará un panic informando la situación. De lo
+        /// contrario, se obtiene el pago del Vec de pagos, y se establece como fecha de pago, la fecha actual, y como
+        /// muestra de que se ha registrado el pago, se emite su recibo y retorna el pago.
+        /// 
+        /// Este método, recibe una referencia mutable de sí mismo (ClubSemRust), un tipo de dato u32 que hace
+        /// referencia al DNI del socio (dni) y un tipo de dato u128, que hace referncia al monto del pago que se quiere
+        /// registrar, y como mencionamos anteriormente, devuelve un tipo de dato Pago
+        #[ink(message)]
+        pub fn realizar_pago (&mut self, dni: u32, monto: u128) -> Pago {
+            return self.realizar_pago_priv(dni, monto);
+        }
+
+        fn realizar_pago_priv (&mut self, dni: u32, monto: u128) -> Pago {
+            self.verificar_no_pausado();
+            if self.verificar_permisos() {
+
+                if self.existe_socio(dni) {
+                    let fecha_actual = self.env().block_timestamp();
+                    let en_plan = self.pagos_en_plan();
+                    if let Some (pos) = nucleo_facturacion::posicion_pago_a_aplicar(&self.pagos, dni, monto, |pos| !en_plan.contains(&pos)) {
+                        return self.saldar_pago(pos, fecha_actual);
+                    }
+
+                    return panic!("El cliente no tiene pagos pendientes con el monto ingresado!");
+                }
+                else {
+                    return panic! ("No se ha encontrado un socio con el DNI provisto!");
+                }
+            }
+
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+        ///Crea el recibo correspondiente al pago saldado recibido, con lo que se cobró (origen) y el siguiente numero correlativo, y lo agrega al
+        ///Vec de recibos, registrando el cobro en el historial de movimientos. La cuenta que registra el cobro es el caller actual.
+        fn emitir_recibo (&mut self, pago: &Pago, origen: OrigenRecibo) -> Recibo {
+            self.ultimo_nro_recibo = verificar_aritmetica(self.ultimo_nro_recibo.checked_add(1).ok_or(ErrorAritmetico::Desbordamiento));
+            let fecha_pago = pago.fecha_pago.unwrap_or(self.env().block_timestamp());
+            let recibo = Recibo::new(self.ultimo_nro_recibo, pago.dni, pago.monto, pago.fecha_venci, fecha_pago, self.env().caller(), origen);
+            self.recibos.push(recibo.clone());
+            self.movimientos.push(MovimientoPago::new(TipoMovimiento::Cobro, pago.dni, pago.monto, recibo.numero, String::new(), recibo.cobrado_por, fecha_pago));
+            self.registrar_asiento(TipoAsiento::Pago, pago.dni, pago.monto, Some(recibo.numero));
+            return recibo;
+        }
+
+        ///El método get_recibo busca en el Vec de recibos el recibo con el numero ingresado y devuelve un clone del
+        ///mismo, o None si no existe. Respeta los mismos permisos que el resto de las consultas, de no contar con
+        ///ellos se arrojará un panic.
+        #[ink(message)]
+        pub fn get_recibo (&self, numero: u32) -> Option<Recibo> {
+            return self.get_recibo_priv(numero);
+        }
+
+        fn get_recibo_priv (&self, numero: u32) -> Option<Recibo> {
+            if self.verificar_permisos() {
+                return self.recibos.iter().find(|recibo| recibo.numero == numero).cloned();
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///El método get_recibos_socio devuelve, ordenados por numero, todos los recibos emitidos para el socio con el
+        ///dni ingresado. Si el socio no tiene recibos, devuelve un Vec vacio. Sin los permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_recibos_socio (&self, dni: u32) -> Vec<Recibo> {
+            return self.get_recibos_socio_priv(dni);
+        }
+
+        fn get_recibos_socio_priv (&self, dni: u32) -> Vec<Recibo> {
+            if self.verificar_permisos() {
+                return self.recibos.iter().filter(|recibo| recibo.dni == dni).cloned().collect();
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///El método get_recibos_por_fecha devuelve todos los recibos cuya fecha de pago se encuentra entre desde y hasta
+        ///(ambos inclusive, expresados en milisegundos). Sin los permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_recibos_por_fecha (&self, desde: u64, hasta: u64) -> Vec<Recibo> {
+            return self.get_recibos_por_fecha_priv(desde, hasta);
+        }
+
+        fn get_recibos_por_fecha_priv (&self, desde: u64, hasta: u64) -> Vec<Recibo> {
+            if self.verificar_permisos() {
+                return self.recibos.iter().filter(|recibo| (recibo.fecha_pago >= desde) && (recibo.fecha_pago <= hasta)).cloned().collect();
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///El método verificar_recibo confirma que un recibo presentado es auténtico. Para ello, recalcula el hash de su
+        ///contenido y comprueba que coincida con el hash que trae, y que el club haya emitido un recibo con ese mismo
+        ///numero y contenido que no haya sido anulado. Retorna true si el recibo es genuino y false en caso contrario.
+        ///Puede llamarlo cualquiera.
+        #[ink(message)]
+        pub fn verificar_recibo (&self, recibo: Recibo) -> bool {
+            return self.verificar_recibo_priv(&recibo);
+        }
+
+        fn verificar_recibo_priv (&self, recibo: &Recibo) -> bool {
+            let hash = Recibo::calcular_hash(recibo.numero, recibo.dni, recibo.monto, recibo.periodo, recibo.fecha_pago, recibo.cobrado_por);
+            if (hash != recibo.hash) || (recibo.anulado) {
+                return false;
+            }
+            return self.recibos.iter().any(|emitido| emitido == recibo);
+        }
+
+        ///El método revertir_pago deshace el cobro registrado en el recibo con el numero ingresado (nro_recibo): el pago
+        ///vuelve a quedar pendiente y el recibo queda anulado. Si el recibo es de una cuota de un plan, la cuota vuelve a
+        ///quedar pendiente (y el plan, si se había cumplido, vuelve a estar vigente con sus pagos refinanciados impagos); si
+        ///el plan ya había caido, el monto de la cuota vuelve a ser deuda del socio como un nuevo pago pendiente. Si es el
+        ///cobro inmediato de una reserva, la reserva queda cancelada y su arancel se acredita en la cuenta corriente del
+        ///socio; si ya estaba cancelada, se acredita lo que quede de su reintegro pendiente. Si el club efectivamente recibió el dinero y debe
+        ///devolverlo, con_reintegro en true registra además el reintegro al socio. El motivo es obligatorio, y tanto la
+        ///reversión como el reintegro quedan en el historial de movimientos junto a la cuenta que los realizó.
+        ///
+        ///El Owner puede revertir cualquier cobro, mientras que un staff solo puede hacerlo dentro de la ventana de
+        ///reversión contada desde la fecha del cobro. Se arrojará un panic si el caller no tiene esos permisos, si el
+        ///motivo está vacío, si el recibo no existe o ya fue anulado. Retorna el pago nuevamente pendiente.
+        #[ink(message)]
+        pub fn revertir_pago (&mut self, nro_recibo: u32, motivo: String, con_reintegro: bool) -> Pago {
+            return self.revertir_pago_priv(nro_recibo, motivo, con_reintegro);
+        }
+
+        fn revertir_pago_priv (&mut self, nro_recibo: u32, motivo: String, con_reintegro: bool) -> Pago {
+            self.verificar_no_pausado();
+            if motivo.is_empty() {
+                return panic!("Debe indicarse el motivo de la reversion!");
+            }
+            let fecha_actual = self.env().block_timestamp();
+            let caller = self.env().caller();
+            let Some (pos_recibo) = self.recibos.iter().position(|recibo| recibo.numero == nro_recibo) else {
+                return panic!("No se ha encontrado un recibo con el numero ingresado!");
+            };
+            let recibo = self.recibos[pos_recibo].clone();
+            if recibo.anulado {
+                return panic!("El recibo ya fue anulado!");
+            }
+            let dentro_de_ventana = fecha_actual <= recibo.fecha_pago.saturating_add(self.ventana_reversion);
+            if !(self.es_admin() || (self.es_staff() && dentro_de_ventana)) {
+                return panic!("No cuenta con rango Owner, o con rango Staff dentro de la ventana de reversion, para realizar esta operacion!");
+            }
+            let pago = match recibo.origen {
+                OrigenRecibo::Pago => {
+                    let option_pos = self.pagos.iter().position(|pago| (pago.dni == recibo.dni) && (pago.monto == recibo.monto) && (pago.fecha_venci == recibo.periodo) && (pago.fecha_pago == Some(recibo.fecha_pago)));
+                    let Some (pos) = option_pos else {
+                        return panic!("No se ha encontrado el pago correspondiente al recibo!");
+                    };
+                    self.pagos[pos].fecha_pago = None;
+                    self.pagos[pos].clone()
+                }
+                OrigenRecibo::CuotaPlan(id_plan, numero) => self.revertir_cuota_plan(id_plan, numero),
+                OrigenRecibo::Reserva(id_reserva) => self.revertir_cobro_reserva(id_reserva),
+            };
+            self.recibos[pos_recibo].anulado = true;
+            self.anular_puntos_de_recibo(nro_recibo);
+            self.registrar_asiento(TipoAsiento::Reversion, recibo.dni, recibo.monto, Some(nro_recibo));
+            self.movimientos.push(MovimientoPago::new(TipoMovimiento::Reversion, recibo.dni, recibo.monto, nro_recibo, motivo.clone(), caller, fecha_actual));
+            if con_reintegro {
+                self.movimientos.push(MovimientoPago::new(TipoMovimiento::Reintegro, recibo.dni, recibo.monto, nro_recibo, motivo, caller, fecha_actual));
+            }
+            return pago;
+        }
+
+        ///Cancela la reserva con el numero recibido, cuyo cobro inmediato se revierte, y acredita su arancel en la cuenta
+        ///corriente del socio. Si la reserva ya estaba cancelada, su arancel ya se había sumado al reintegro pendiente del
+        ///socio, por lo que se acredita lo que quede de ese reintegro. Retorna el pago que representa al arancel.
+        fn revertir_cobro_reserva (&mut self, id_reserva: u32) -> Pago {
+            let Some (reserva) = self.reservas.iter_mut().find(|reserva| reserva.id == id_reserva) else {
+                return panic!("No se ha encontrado una reserva con el numero ingresado!");
+            };
+            let (dni, monto, inicio, cancelada) = (reserva.dni, reserva.monto, reserva.inicio, reserva.cancelada);
+            reserva.cancelada = true;
+            let credito = if cancelada {
+                let mut credito = 0;
+                if let Some ((_, pendiente)) = self.reintegros_pendientes.iter_mut().find(|(dni_reintegro, _)| *dni_reintegro == dni) {
+                    credito = (*pendiente).min(monto);
+                    *pendiente -= credito;
+                }
+                self.reintegros_pendientes.retain(|(_, pendiente)| *pendiente > 0);
+                credito
+            } else {
+                monto
+            };
+            self.registrar_asiento(TipoAsiento::Reintegro, dni, credito, Some(id_reserva));
+            return Pago::new(dni, monto, inicio, None, false);
+        }
+
+        ///Deja pendiente la cuota con el numero recibido del plan con el id recibido, cuyo cobro se revierte. Si el plan se
+        ///había cumplido vuelve a estar vigente y sus pagos refinanciados vuelven a quedar impagos; si había caido, el monto
+        ///de la cuota se agrega como un nuevo pago pendiente del socio. Retorna el pago que representa a la cuota.
+        fn revertir_cuota_plan (&mut self, id_plan: u32, numero: u32) -> Pago {
+            let Some (plan) = self.planes_cuotas.iter_mut().find(|plan| plan.id == id_plan) else {
+                return panic!("No se ha encontrado un plan de cuotas con el id ingresado!");
+            };
+            let Some (cuota) = plan.cuotas.iter_mut().find(|cuota| cuota.numero == numero) else {
+                return panic!("No se ha encontrado la cuota correspondiente al recibo!");
+            };
+            cuota.fecha_pago = None;
+            let pago = Pago::new(plan.dni, cuota.monto, cuota.fecha_venci, None, false);
+            match plan.estado {
+                EstadoPlan::Vigente => {}
+                EstadoPlan::Cumplido => {
+                    plan.estado = EstadoPlan::Vigente;
+                    for pos in plan.pagos.clone() {
+                        self.pagos[pos as usize].fecha_pago = None;
+                    }
+                }
+                EstadoPlan::Caido => self.pagos.push(pago.clone()),
+            }
+            return pago;
+        }
+
+        ///El método get_historial_pagos devuelve, en orden cronológico, los cobros, reversiones y reintegros del socio con
+        ///el dni ingresado, o los de todo el club si se ingresa None. Sin los permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_historial_pagos (&self, dni: Option<u32>) -> Vec<MovimientoPago> {
+            return self.get_historial_pagos_priv(dni);
+        }
+
+        fn get_historial_pagos_priv (&self, dni: Option<u32>) -> Vec<MovimientoPago> {
+            if self.verificar_permisos() {
+                return self.movimientos.iter().filter(|movimiento| dni.is_none() || (Some(movimiento.dni) == dni)).cloned().collect();
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///Este método permite al Owner modificar la ventana de reversión, es decir, la cantidad de dias durante los cuales
+        ///un staff puede revertir un cobro. De no ser Owner, arrojará un panic. Retorna true como muestra de la actualización.
+        #[ink(message)]
+        pub fn actualizar_ventana_reversion (&mut self, dias: u64) -> bool {
+            return self.actualizar_ventana_reversion_priv(dias);
+        }
+
+        fn actualizar_ventana_reversion_priv (&mut self, dias: u64) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                let anterior = self.ventana_reversion;
+                self.ventana_reversion = verificar_aritmetica(nucleo_facturacion::duracion_dias(dias));
+                self.registrar_auditoria(AccionAdministrativa::CambioVentanaReversion, Some(ValorAuditado::Cantidad(anterior)), Some(ValorAuditado::Cantidad(self.ventana_reversion)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///Agrega una entrada a la auditoria con la accion y los valores recibidos, tomando como actor al caller actual,
+        ///junto a la fecha y el numero de bloque. Si la auditoria supera la retención, se descartan las entradas mas antiguas.
+        fn registrar_auditoria (&mut self, accion: AccionAdministrativa, valor_anterior: Option<ValorAuditado>, valor_nuevo: Option<ValorAuditado>) {
+            self.ultimo_nro_auditoria += 1;
+            let entrada = EntradaAuditoria {
+                numero: self.ultimo_nro_auditoria,
+                accion,
+                actor: self.env().caller(),
+                fecha: self.env().block_timestamp(),
+                bloque: self.env().block_number(),
+                valor_anterior,
+                valor_nuevo,
+            };
+            self.auditoria.push(entrada);
+            self.aplicar_retencion_auditoria();
+        }
+
+        fn aplicar_retencion_auditoria (&mut self) {
+            let retencion = self.retencion_auditoria as usize;
+            if self.auditoria.len() > retencion {
+                let sobrantes = self.auditoria.len() - retencion;
+                self.auditoria.drain(0..sobrantes);
+            }
+        }
+
+        ///El método get_auditoria devuelve, en orden cronológico, las entradas de auditoria conservadas, pudiendo filtrarlas
+        ///por la cuenta que realizó la accion (actor) y por el tipo de accion (accion); un None en cualquiera de ellos no
+        ///filtra por ese criterio. Solo el Owner o un staff pueden consultarla, de lo contrario se arrojará un panic.
+        #[ink(message)]
+        pub fn get_auditoria (&self, actor: Option<AccountId>, accion: Option<AccionAdministrativa>) -> Vec<EntradaAuditoria> {
+            return self.get_auditoria_priv(actor, accion);
+        }
+
+        fn get_auditoria_priv (&self, actor: Option<AccountId>, accion: Option<AccionAdministrativa>) -> Vec<EntradaAuditoria> {
+            if (self.es_admin()) || (self.es_staff()) {
+                return self.auditoria.iter()
+                    .filter(|entrada| actor.is_none_or(|actor| entrada.actor == actor))
+                    .filter(|entrada| accion.as_ref().is_none_or(|accion| &entrada.accion == accion))
+                    .cloned()
+                    .collect();
+            }
+            return panic!("No se cuenta con los permisos necesarios!");
+        }
+
+        ///Este método permite al Owner modificar la cantidad máxima de entradas que conserva la auditoria. Si la nueva
+        ///retención es menor a la cantidad de entradas actuales, se descartan las mas antiguas. De no ser Owner, o si la
+        ///retención es 0, arrojará un panic. Retorna true como muestra de la actualización.
+        #[ink(message)]
+        pub fn actualizar_retencion_auditoria (&mut self, retencion: u32) -> bool {
+            return self.actualizar_retencion_auditoria_priv(retencion);
+        }
+
+        fn actualizar_retencion_auditoria_priv (&mut self, retencion: u32) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                if retencion == 0 {
+                    return panic!("La retencion de la auditoria debe ser de al menos una entrada!");
+                }
+                let anterior = self.retencion_auditoria;
+                self.retencion_auditoria = retencion;
+                self.registrar_auditoria(AccionAdministrativa::CambioRetencionAuditoria, Some(ValorAuditado::Cantidad(anterior as u64)), Some(ValorAuditado::Cantidad(retencion as u64)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///Arroja un panic si el contrato se encuentra pausado. La llaman todos los metodos que modifican el estado del club
+        ///antes de realizar cualquier otra verificación.
+        fn verificar_no_pausado (&self) {
+            if self.pausado {
+                return panic!("El contrato se encuentra pausado!");
+            }
+        }
+
+        fn es_guardian (&self) -> bool {
+            return self.guardianes.contains(&self.env().caller());
+        }
+
+        ///El método pausar congela el contrato ante una emergencia (por ejemplo, un error en la facturación): mientras esté
+        ///pausado, todos los metodos que modifican el estado arrojarán un panic, y solo se podrán realizar consultas y la
+        ///reanudación. Puede llamarlo el Owner o un guardián, indicando obligatoriamente el motivo. Se arrojará un panic si
+        ///el caller no tiene esos permisos, si el motivo está vacío o si el contrato ya está pausado. Emite el evento
+        ///ContratoPausado y retorna true.
+        #[ink(message)]
+        pub fn pausar (&mut self, motivo: String) -> bool {
+            return self.pausar_priv(motivo);
+        }
+
+        fn pausar_priv (&mut self, motivo: String) -> bool {
+            self.verificar_no_pausado();
+            if !(self.es_admin() || self.es_guardian()) {
+                return panic!("No cuenta con rango Owner o Guardian para realizar esta operacion!");
+            }
+            if motivo.is_empty() {
+                return panic!("Debe indicarse el motivo de la pausa!");
+            }
+            self.pausado = true;
+            self.motivo_pausa = Some(motivo.clone());
+            self.registrar_auditoria(AccionAdministrativa::Pausa, Some(ValorAuditado::Booleano(false)), Some(ValorAuditado::Booleano(true)));
+            self.env().emit_event(ContratoPausado { por: self.env().caller(), motivo });
+            return true;
+        }
+
+        ///El método reanudar quita la pausa del contrato, volviendo a habilitar todos sus metodos. Solo puede llamarlo el
+        ///Owner, y arrojará un panic si el caller no es Owner o si el contrato no está pausado. Emite el evento
+        ///ContratoReanudado y retorna true.
+        #[ink(message)]
+        pub fn reanudar (&mut self) -> bool {
+            return self.reanudar_priv();
+        }
+
+        fn reanudar_priv (&mut self) -> bool {
+            if !self.es_admin() {
+                return panic!("No cuenta con rango Owner para realizar esta operacion!");
+            }
+            if !self.pausado {
+                return panic!("El contrato no se encuentra pausado!");
+            }
+            self.pausado = false;
+            self.motivo_pausa = None;
+            self.registrar_auditoria(AccionAdministrativa::Reanudacion, Some(ValorAuditado::Booleano(true)), Some(ValorAuditado::Booleano(false)));
+            self.env().emit_event(ContratoReanudado { por: self.env().caller() });
+            return true;
+        }
+
+        ///Retorna si el contrato se encuentra pausado y, de estarlo, el motivo de la pausa. Puede consultarlo cualquiera.
+        #[ink(message)]
+        pub fn get_estado_pausa (&self) -> (bool, Option<String>) {
+            return (self.pausado, self.motivo_pausa.clone());
+        }
+
+        ///Este método permite al Owner designar un guardián, es decir, una cuenta que puede pausar el contrato. Arrojará un
+        ///panic si el caller no es Owner o si la cuenta ya es guardián. Retorna true como muestra de ello.
+        #[ink(message)]
+        pub fn agregar_guardian (&mut self, guardian: AccountId) -> bool {
+            return self.agregar_guardian_priv(guardian);
+        }
+
+        fn agregar_guardian_priv (&mut self, guardian: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) && (!self.guardianes.contains(&guardian)) {
+                self.guardianes.push(guardian);
+                self.registrar_auditoria(AccionAdministrativa::AgregarGuardian, None, Some(ValorAuditado::Cuenta(guardian)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner o la cuenta ingresada ya es Guardian!");
+        }
+
+        ///Este método permite al Owner quitar a un guardián. Arrojará un panic si el caller no es Owner o si la cuenta no
+        ///es guardián. Retorna true como muestra de ello.
+        #[ink(message)]
+        pub fn quitar_guardian (&mut self, guardian: AccountId) -> bool {
+            return self.quitar_guardian_priv(guardian);
+        }
+
+        fn quitar_guardian_priv (&mut self, guardian: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) && (self.guardianes.contains(&guardian)) {
+                self.guardianes.retain(|cuenta| cuenta != &guardian);
+                self.registrar_auditoria(AccionAdministrativa::QuitarGuardian, Some(ValorAuditado::Cuenta(guardian)), None);
+                return true;
+            }
+            return panic!("No cuenta con rango Owner o la cuenta ingresada no es Guardian!");
+        }
+
+        ///Marca como pagado, en la fecha recibida, al pago que se encuentra en la posición pos del Vec de pagos, emite su
+        ///recibo y retorna el pago saldado.
+        fn saldar_pago (&mut self, pos: usize, fecha_pago: u64) -> Pago {
+            let pago = self.pagos.get_mut(pos).unwrap();
+            pago.marcar_pagado(fecha_pago);
+            let pago = pago.clone();
+            let recibo = self.emitir_recibo(&pago, OrigenRecibo::Pago);
+            self.acreditar_puntos(&pago, recibo.numero);
+            return pago;
+        }
+
+        ///La función registrar_socios_en_lote permite registrar muchos socios en una sola llamada, por ejemplo, al incorporar
+        ///un equipo completo al inicio de la temporada. Recibe un Vec de tuplas (dni, apellido y nombre, id de categoria,
+        ///Option del id de deporte), con los mismos datos que registrar_socio, y devuelve, en el mismo orden, el resultado de
+        ///cada registro: el primer pago pendiente del socio o el motivo por el que no pudo registrarse (ErrorLote).
+        ///
+        ///Si todo_o_nada es true y algún elemento falla, no se registra ningún socio, y los elementos que eran válidos
+        ///devuelven ErrorLote::LoteCancelado. Los permisos, la pausa y la protección de datos se verifican una sola vez para
+        ///todo el lote, arrojando un panic como en registrar_socio.
+        #[ink(message)]
+        pub fn registrar_socios_en_lote (&mut self, socios: Vec<(u32, String, u32, Option<u32>)>, todo_o_nada: bool) -> Vec<Result<Pago, ErrorLote>> {
+            return self.registrar_socios_en_lote_priv(socios, todo_o_nada);
+        }
+
+        fn registrar_socios_en_lote_priv (&mut self, socios: Vec<(u32, String, u32, Option<u32>)>, todo_o_nada: bool) -> Vec<Result<Pago, ErrorLote>> {
+            self.verificar_no_pausado();
+            if self.proteccion_datos {
+                return panic!("La proteccion de datos se encuentra activada, el socio debe registrarse con registrar_socio_protegido!");
+            }
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            //se recorre el Vec de socios una sola vez, en lugar de una vez por cada elemento del lote
+            let existentes: BTreeSet<u32> = self.socios.iter().map(|socio| socio.dni).collect();
+            let mut en_lote: BTreeSet<u32> = BTreeSet::new();
+            let mut validados = Vec::new();
+            for (dni, apellido_y_nombre, id_categoria, id_deporte) in socios {
+                let validado = if dni & MARCA_IDENTIFICADOR_PROTEGIDO != 0 {
+                    Err(ErrorLote::DniInvalido)
+                } else if !(1..=3).contains(&id_categoria) {
+                    Err(ErrorLote::CategoriaInvalida)
+                } else if id_deporte.is_some_and(|id| !(1..=8).contains(&id)) {
+                    Err(ErrorLote::DeporteInvalido)
+                } else if existentes.contains(&dni) {
+                    Err(ErrorLote::SocioExistente)
+                } else if !en_lote.insert(dni) {
+                    Err(ErrorLote::DniRepetidoEnLote)
+                } else {
+                    let categoria = CategoriasSocios::categoria_from_id(&id_categoria).unwrap();
+                    Ok(Socio::new(apellido_y_nombre, dni, categoria, Deporte::deporte_from_id(&id_deporte)))
+                };
+                validados.push(validado);
+            }
+            let cancelar = todo_o_nada && validados.iter().any(|validado| validado.is_err());
+            let mut resultados = Vec::new();
+            for validado in validados {
+                let resultado = match validado {
+                    Ok (_) if cancelar => Err(ErrorLote::LoteCancelado),
+                    Ok (socio) => Ok(self.alta_socio(socio)),
+                    Err (error) => Err(error),
+                };
+                resultados.push(resultado);
+            }
+            return resultados;
+        }
+
+        ///La función realizar_pagos_en_lote permite registrar muchos pagos en una sola llamada. Recibe un Vec de tuplas (dni,
+        ///monto), con los mismos datos que realizar_pago, y devuelve, en el mismo orden, el resultado de cada uno: el pago
+        ///saldado (cuyo recibo se emite como en realizar_pago) o el motivo por el que no pudo registrarse (ErrorLote). Si un
+        ///mismo socio aparece varias veces en el lote, cada elemento salda un pago pendiente distinto.
+        ///
+        ///Si todo_o_nada es true y algún elemento falla, no se registra ningún pago, y los elementos que eran válidos
+        ///devuelven ErrorLote::LoteCancelado. Los permisos y la pausa se verifican una sola vez para todo el lote, arrojando
+        ///un panic como en realizar_pago.
+        #[ink(message)]
+        pub fn realizar_pagos_en_lote (&mut self, pagos: Vec<(u32, u128)>, todo_o_nada: bool) -> Vec<Result<Pago, ErrorLote>> {
+            return self.realizar_pagos_en_lote_priv(pagos, todo_o_nada);
+        }
+
+        fn realizar_pagos_en_lote_priv (&mut self, pagos: Vec<(u32, u128)>, todo_o_nada: bool) -> Vec<Result<Pago, ErrorLote>> {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let dnis: BTreeSet<u32> = self.socios.iter().map(|socio| socio.dni).collect();
+            let mut reservados: BTreeSet<usize> = self.pagos_en_plan();
+            let mut validados = Vec::new();
+            for (dni, monto) in pagos {
+                let validado = if !dnis.contains(&dni) {
+                    Err(ErrorLote::SocioInexistente)
+                } else {
+                    let option_pos = nucleo_facturacion::posicion_pago_a_aplicar(&self.pagos, dni, monto, |pos| !reservados.contains(&pos));
+                    match option_pos {
+                        Some (pos) => {reservados.insert(pos); Ok(pos)},
+                        None => Err(ErrorLote::SinPagoPendiente),
+                    }
+                };
+                validados.push(validado);
+            }
+            let cancelar = todo_o_nada && validados.iter().any(|validado| validado.is_err());
+            let fecha_actual = self.env().block_timestamp();
+            let mut resultados = Vec::new();
+            for validado in validados {
+                let resultado = match validado {
+                    Ok (_) if cancelar => Err(ErrorLote::LoteCancelado),
+                    Ok (pos) => Ok(self.saldar_pago(pos, fecha_actual)),
+                    Err (error) => Err(error),
+                };
+                resultados.push(resultado);
+            }
+            return resultados;
+        }
+
+        ///El método configurar_token_pago permite al Owner definir el contrato PSP22 del token con el que los socios pueden
+        ///pagar sus cuotas (por ejemplo, una stablecoin), o dejar de aceptar pagos en token enviando None. De no ser
+        ///Owner, arrojará un panic. Retorna true.
+        #[ink(message)]
+        pub fn configurar_token_pago (&mut self, token: Option<AccountId>) -> bool {
+            return self.configurar_token_pago_priv(token);
+        }
+
+        fn configurar_token_pago_priv (&mut self, token: Option<AccountId>) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                let anterior = self.token_pago;
+                self.token_pago = token;
+                self.registrar_auditoria(AccionAdministrativa::CambioTokenPago, anterior.map(ValorAuditado::Cuenta), token.map(ValorAuditado::Cuenta));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///Devuelve el contrato PSP22 aceptado para pagar las cuotas, o None si no se aceptan pagos en token.
+        #[ink(message)]
+        pub fn get_token_pago (&self) -> Option<AccountId> {
+            return self.token_pago;
+        }
+
+        ///El método pagar_con_token salda el pago pendiente más antiguo del socio con el dni ingresado, debitando su monto
+        ///exacto en el token configurado desde la cuenta del caller. Previamente, el caller debe autorizar (approve) al
+        ///club en el contrato del token por al menos ese monto. Cualquier cuenta puede pagar la cuota de un socio, ya que
+        ///los fondos salen de ella, y queda registrada como quien realizó el cobro en el recibo.
+        ///
+        ///Arrojará un panic si el contrato está pausado, si no hay un token configurado, si el socio no existe o si no
+        ///tiene pagos pendientes. Si el token rechaza la transferencia, devuelve el motivo (ErrorPagoToken) y el pago
+        ///sigue pendiente. De lo contrario, devuelve el pago saldado.
+        #[ink(message)]
+        pub fn pagar_con_token (&mut self, dni: u32) -> Result<Pago, ErrorPagoToken> {
+            return self.pagar_con_token_priv(dni);
+        }
+
+        fn pagar_con_token_priv (&mut self, dni: u32) -> Result<Pago, ErrorPagoToken> {
+            self.verificar_no_pausado();
+            let Some (token) = self.token_pago else {
+                return panic!("No hay un token de pago configurado!");
+            };
+            if !self.socios.iter().any(|socio| socio.dni == dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            let en_plan = self.pagos_en_plan();
+            let Some (pos) = (0..self.pagos.len()).find(|pos| (self.pagos[*pos].dni == dni) && (self.pagos[*pos].fecha_pago.is_none()) && !en_plan.contains(pos)) else {
+                return panic!("El socio no tiene pagos pendientes!");
+            };
+            let monto = self.pagos[pos].monto;
+            self.transferir_desde_token(token, self.env().caller(), monto)?;
+            return Ok(self.saldar_pago(pos, self.env().block_timestamp()));
+        }
+
+        ///Llama a transfer_from del contrato PSP22 token para mover monto desde la cuenta desde hacia la cuenta del club,
+        ///traduciendo cualquier falla a un ErrorPagoToken. En los tests off-chain, donde no hay otros contratos, se
+        ///reemplaza por la respuesta que simula cada test (tests::respuesta_token).
+        #[cfg(not(test))]
+        fn transferir_desde_token (&self, token: AccountId, desde: AccountId, monto: u128) -> Result<(), ErrorPagoToken> {
+            let resultado = ink::env::call::build_call::<Environment>()
+                .call(token)
+                .gas_limit(0)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(ink::selector_bytes!("PSP22::transfer_from")))
+                        .push_arg(desde)
+                        .push_arg(self.env().account_id())
+                        .push_arg(monto)
+                        .push_arg(Vec::<u8>::new()),
+                )
+                .returns::<Result<(), PSP22Error>>()
+                .try_invoke();
+            match resultado {
+                Ok (Ok (Ok (()))) => return Ok(()),
+                Ok (Ok (Err (PSP22Error::InsufficientBalance))) => return Err(ErrorPagoToken::SaldoInsuficiente),
+                Ok (Ok (Err (PSP22Error::InsufficientAllowance))) => return Err(ErrorPagoToken::AutorizacionInsuficiente),
+                Ok (Ok (Err (PSP22Error::Custom(motivo)))) => return Err(ErrorPagoToken::RechazoDelToken(motivo)),
+                _ => return Err(ErrorPagoToken::LlamadaFallida),
+            }
+        }
+
+        #[cfg(test)]
+        fn transferir_desde_token (&self, token: AccountId, desde: AccountId, monto: u128) -> Result<(), ErrorPagoToken> {
+            return tests::respuesta_token(token, desde, monto);
+        }
+
+        ///El método actualizar_puntos_categoria permite al Owner o a un Staff definir cuantos puntos de fidelidad recibe un
+        ///socio de la categoria indicada (1, 2 o 3) por cada cuota que paga antes de su vencimiento. Con 0 puntos, la
+        ///categoria no acumula. Arrojará un panic si no se cuenta con los permisos o si el id de categoria no es valido.
+        #[ink(message)]
+        pub fn actualizar_puntos_categoria (&mut self, id_categoria: u32, puntos: u32) -> bool {
+            return self.actualizar_puntos_categoria_priv(id_categoria, puntos);
+        }
+
+        fn actualizar_puntos_categoria_priv (&mut self, id_categoria: u32, puntos: u32) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) || (self.es_staff()) {
+                if let Some (categoria) = CategoriasSocios::categoria_from_id(&id_categoria) {
+                    let anterior = self.puntos_categoria.get_puntos(&categoria);
+                    self.puntos_categoria.set_puntos(puntos, &categoria);
+                    self.registrar_auditoria(AccionAdministrativa::CambioPuntosCategoria, Some(ValorAuditado::PuntosCategoria(categoria.clone(), anterior)), Some(ValorAuditado::PuntosCategoria(categoria, puntos)));
+                    return true;
+                }
+            }
+            return panic!("No se cuenta con los permisos necesarios!");
+        }
+
+        ///Este método permite al Owner modificar la vigencia, en dias, de los puntos que se acrediten de ahora en más. Los
+        ///puntos ya acreditados conservan su vencimiento. De no ser Owner, arrojará un panic. Retorna true.
+        #[ink(message)]
+        pub fn actualizar_vigencia_puntos (&mut self, dias: u64) -> bool {
+            return self.actualizar_vigencia_puntos_priv(dias);
+        }
+
+        fn actualizar_vigencia_puntos_priv (&mut self, dias: u64) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                let anterior = self.vigencia_puntos;
+                self.vigencia_puntos = verificar_aritmetica(nucleo_facturacion::duracion_dias(dias));
+                self.registrar_auditoria(AccionAdministrativa::CambioVigenciaPuntos, Some(ValorAuditado::Cantidad(anterior)), Some(ValorAuditado::Cantidad(self.vigencia_puntos)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///Este método permite al Owner modificar el descuento que vale cada punto al canjearlo. Los canjes ya realizados
+        ///conservan su descuento. De no ser Owner, arrojará un panic. Retorna true.
+        #[ink(message)]
+        pub fn actualizar_valor_punto (&mut self, valor: u128) -> bool {
+            return self.actualizar_valor_punto_priv(valor);
+        }
+
+        fn actualizar_valor_punto_priv (&mut self, valor: u128) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                let anterior = self.valor_punto;
+                self.valor_punto = valor;
+                self.registrar_auditoria(AccionAdministrativa::CambioValorPunto, Some(ValorAuditado::Monto(anterior)), Some(ValorAuditado::Monto(valor)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///El método asignar_grupo_familiar incorpora al socio con el dni ingresado al grupo familiar indicado, o lo quita
+        ///de su grupo si se ingresa None. Los puntos solo pueden transferirse entre socios del mismo grupo. Arrojará un
+        ///panic si no se cuenta con los permisos necesarios o si el socio no existe. Retorna true.
+        #[ink(message)]
+        pub fn asignar_grupo_familiar (&mut self, dni: u32, grupo: Option<u32>) -> bool {
+            return self.asignar_grupo_familiar_priv(dni, grupo);
+        }
+
+        fn asignar_grupo_familiar_priv (&mut self, dni: u32, grupo: Option<u32>) -> bool {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            self.grupos_familiares.retain(|(dni_grupo, _)| *dni_grupo != dni);
+            if let Some (grupo) = grupo {
+                self.grupos_familiares.push((dni, grupo));
+            }
+            return true;
+        }
+
+        ///El método get_puntos devuelve los puntos vigentes (sin vencer) del socio con el dni ingresado. Sin los permisos
+        ///necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_puntos (&self, dni: u32) -> u32 {
+            return self.get_puntos_priv(dni);
+        }
+
+        fn get_puntos_priv (&self, dni: u32) -> u32 {
+            if self.verificar_permisos() {
+                return self.saldo_puntos(dni);
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///El método get_historial_puntos devuelve, en orden cronológico, todos los movimientos de puntos del socio con el
+        ///dni ingresado. Los vencimientos figuran una vez procesados. Sin los permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_historial_puntos (&self, dni: u32) -> Vec<MovimientoPuntos> {
+            return self.get_historial_puntos_priv(dni);
+        }
+
+        fn get_historial_puntos_priv (&self, dni: u32) -> Vec<MovimientoPuntos> {
+            if self.verificar_permisos() {
+                return self.movimientos_puntos.iter().filter(|movimiento| movimiento.dni == dni).cloned().collect();
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///El método canjear_puntos descuenta la cantidad de puntos indicada al socio con el dni ingresado, a cambio de un
+        ///descuento de cantidad * valor_punto en la próxima cuota que se le emita. Se consumen primero los puntos más
+        ///próximos a vencer. Puede canjearlos quien cuente con los permisos necesarios o la cuenta titular del carnet del
+        ///socio. Arrojará un panic si no se cumple ninguna de las dos condiciones, si el socio no existe, si la cantidad es
+        ///0 o supera los puntos vigentes, o si el descuento pendiente superaría el costo de su categoria. Retorna el
+        ///descuento obtenido.
+        #[ink(message)]
+        pub fn canjear_puntos (&mut self, dni: u32, cantidad: u32) -> u128 {
+            return self.canjear_puntos_priv(dni, cantidad);
+        }
+
+        fn canjear_puntos_priv (&mut self, dni: u32, cantidad: u32) -> u128 {
+            self.verificar_no_pausado();
+            if !(self.verificar_permisos() || self.es_titular_carnet(dni)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let Some (socio) = self.socios.iter().find(|socio| socio.dni == dni).cloned() else {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            };
+            self.vencer_puntos(Some(dni));
+            if (cantidad == 0) || (cantidad > self.saldo_puntos(dni)) {
+                return panic!("La cantidad de puntos a canjear debe ser mayor a 0 y no superar los puntos vigentes!");
+            }
+            let descuento = verificar_aritmetica(Monto(self.valor_punto).multiplicar(cantidad as u128)).valor();
+            let pendiente = self.canjes_pendientes.iter().find(|(dni_canje, _)| *dni_canje == dni).map(|(_, descuento)| *descuento).unwrap_or(0);
+            if Monto(pendiente).sumar_saturado(Monto(descuento)).valor() > self.costos.get_costo(&socio.categoria) {
+                return panic!("El descuento canjeado superaria el costo de la cuota del socio!");
+            }
+            self.consumir_puntos(dni, cantidad);
+            self.movimientos_puntos.push(MovimientoPuntos::new(TipoMovimientoPuntos::Canje, dni, cantidad, self.env().block_timestamp(), None));
+            self.canjes_pendientes.retain(|(dni_canje, _)| *dni_canje != dni);
+            self.canjes_pendientes.push((dni, pendiente.saturating_add(descuento)));
+            return descuento;
+        }
+
+        ///El método transferir_puntos transfiere la cantidad de puntos indicada del socio desde al socio hacia, que deben
+        ///pertenecer al mismo grupo familiar. Los puntos transferidos conservan su vencimiento. Puede transferirlos quien
+        ///cuente con los permisos necesarios o la cuenta titular del carnet del socio desde. Arrojará un panic si no se
+        ///cumple ninguna de las dos condiciones, si alguno de los socios no existe, si son el mismo socio o no comparten
+        ///grupo familiar, o si la cantidad es 0 o supera los puntos vigentes de desde. Retorna true.
+        #[ink(message)]
+        pub fn transferir_puntos (&mut self, desde: u32, hacia: u32, cantidad: u32) -> bool {
+            return self.transferir_puntos_priv(desde, hacia, cantidad);
+        }
+
+        fn transferir_puntos_priv (&mut self, desde: u32, hacia: u32, cantidad: u32) -> bool {
+            self.verificar_no_pausado();
+            if !(self.verificar_permisos() || self.es_titular_carnet(desde)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.socios.iter().any(|socio| socio.dni == desde) || !self.socios.iter().any(|socio| socio.dni == hacia) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            let grupo = |dni: u32| self.grupos_familiares.iter().find(|(dni_grupo, _)| *dni_grupo == dni).map(|(_, grupo)| *grupo);
+            if (desde == hacia) || grupo(desde).is_none() || (grupo(desde) != grupo(hacia)) {
+                return panic!("Los puntos solo pueden transferirse a otro socio del mismo grupo familiar!");
+            }
+            self.vencer_puntos(Some(desde));
+            if (cantidad == 0) || (cantidad > self.saldo_puntos(desde)) {
+                return panic!("La cantidad de puntos a transferir debe ser mayor a 0 y no superar los puntos vigentes!");
+            }
+            let fecha = self.env().block_timestamp();
+            for lote in self.consumir_puntos(desde, cantidad) {
+                self.lotes_puntos.push(LotePuntos {dni: hacia, cantidad: lote.cantidad, vence: lote.vence, nro_recibo: None});
+            }
+            self.movimientos_puntos.push(MovimientoPuntos::new(TipoMovimientoPuntos::TransferenciaEnviada, desde, cantidad, fecha, Some(hacia)));
+            self.movimientos_puntos.push(MovimientoPuntos::new(TipoMovimientoPuntos::TransferenciaRecibida, hacia, cantidad, fecha, Some(desde)));
+            return true;
+        }
+
+        ///El método procesar_vencimiento_puntos da de baja todos los lotes de puntos vencidos, registrando el vencimiento
+        ///en el historial de cada socio. Los puntos vencidos nunca cuentan como vigentes, pero hasta ser procesados no
+        ///figuran en el historial. Arrojará un panic si no se cuenta con los permisos necesarios. Retorna la cantidad de
+        ///puntos vencidos.
+        #[ink(message)]
+        pub fn procesar_vencimiento_puntos (&mut self) -> u32 {
+            return self.procesar_vencimiento_puntos_priv();
+        }
+
+        fn procesar_vencimiento_puntos_priv (&mut self) -> u32 {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            return self.vencer_puntos(None);
+        }
+
+        ///Acredita al socio del pago los puntos de su categoria, si el pago se efectuó antes de su vencimiento, en un lote
+        ///que vence al cumplirse la vigencia desde la fecha de pago.
+        fn acreditar_puntos (&mut self, pago: &Pago, nro_recibo: u32) {
+            let Some (fecha_pago) = pago.fecha_pago else {
+                return;
+            };
+            let Some (socio) = self.socios.iter().find(|socio| socio.dni == pago.dni) else {
+                return;
+            };
+            let puntos = self.puntos_categoria.get_puntos(&socio.categoria);
+            if (fecha_pago > pago.fecha_venci) || (puntos == 0) {
+                return;
+            }
+            self.lotes_puntos.push(LotePuntos {dni: pago.dni, cantidad: puntos, vence: nucleo_facturacion::Timestamp(fecha_pago).sumar_saturado(self.vigencia_puntos).valor(), nro_recibo: Some(nro_recibo)});
+            self.movimientos_puntos.push(MovimientoPuntos::new(TipoMovimientoPuntos::Acreditacion, pago.dni, puntos, fecha_pago, Some(nro_recibo)));
+        }
+
+        ///Quita los puntos que se acreditaron por el recibo indicado y que el socio todavía no usó, registrando la anulación.
+        fn anular_puntos_de_recibo (&mut self, nro_recibo: u32) {
+            if let Some (pos) = self.lotes_puntos.iter().position(|lote| lote.nro_recibo == Some(nro_recibo)) {
+                let lote = self.lotes_puntos.remove(pos);
+                self.movimientos_puntos.push(MovimientoPuntos::new(TipoMovimientoPuntos::Anulacion, lote.dni, lote.cantidad, self.env().block_timestamp(), Some(nro_recibo)));
+            }
+        }
+
+        ///Suma los puntos sin vencer del socio.
+        fn saldo_puntos (&self, dni: u32) -> u32 {
+            let ahora = self.env().block_timestamp();
+            return self.lotes_puntos.iter().filter(|lote| (lote.dni == dni) && (lote.vence > ahora)).map(|lote| lote.cantidad).sum();
+        }
+
+        ///Da de baja los lotes vencidos del socio indicado, o de todos los socios si es None, registrando cada vencimiento.
+        ///Retorna la cantidad de puntos vencidos.
+        fn vencer_puntos (&mut self, dni: Option<u32>) -> u32 {
+            let ahora = self.env().block_timestamp();
+            let (vencidos, vigentes): (Vec<LotePuntos>, Vec<LotePuntos>) = core::mem::take(&mut self.lotes_puntos).into_iter()
+                .partition(|lote| (lote.vence <= ahora) && dni.is_none_or(|dni| dni == lote.dni));
+            self.lotes_puntos = vigentes;
+            let mut total = 0;
+            for lote in vencidos {
+                total += lote.cantidad;
+                self.movimientos_puntos.push(MovimientoPuntos::new(TipoMovimientoPuntos::Vencimiento, lote.dni, lote.cantidad, lote.vence, None));
+            }
+            return total;
+        }
+
+        ///Descuenta la cantidad de puntos de los lotes del socio, empezando por los más próximos a vencer, y devuelve las
+        ///porciones consumidas de cada lote. Quien lo llama debe haber verificado que el socio tiene puntos suficientes.
+        fn consumir_puntos (&mut self, dni: u32, cantidad: u32) -> Vec<LotePuntos> {
+            let mut posiciones: Vec<usize> = (0..self.lotes_puntos.len()).filter(|pos| self.lotes_puntos[*pos].dni == dni).collect();
+            posiciones.sort_by_key(|pos| self.lotes_puntos[*pos].vence);
+            let mut restante = cantidad;
+            let mut consumidos = Vec::new();
+            for pos in posiciones {
+                if restante == 0 {
+                    break;
+                }
+                let lote = &mut self.lotes_puntos[pos];
+                let tomados = lote.cantidad.min(restante);
+                lote.cantidad -= tomados;
+                restante -= tomados;
+                consumidos.push(LotePuntos {dni, cantidad: tomados, vence: lote.vence, nro_recibo: None});
+            }
+            self.lotes_puntos.retain(|lote| lote.cantidad > 0);
+            return consumidos;
+        }
+
+        ///El método registrar_renuncia da de baja al socio con el dni ingresado: lo quita del padrón, de su grupo familiar,
+        ///de los canjes de puntos pendientes y de las clases, cancela sus reservas futuras y revoca su carnet. Lo que el club
+        ///le debe devolver, su reintegro pendiente más los aranceles ya cobrados de las reservas canceladas, queda como un
+        ///asiento de reintegro en su cuenta corriente. Sus pagos, recibos, asientos y asistencias se conservan como historial. Arrojará un panic si no se cuenta con los permisos necesarios
+        ///o si el socio no existe. Retorna true.
+        #[ink(message)]
+        pub fn registrar_renuncia (&mut self, dni: u32) -> bool {
+            return self.registrar_renuncia_priv(dni);
+        }
+
+        fn registrar_renuncia_priv (&mut self, dni: u32) -> bool {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            self.socios.retain(|socio| socio.dni != dni);
+            self.grupos_familiares.retain(|(dni_grupo, _)| *dni_grupo != dni);
+            self.canjes_pendientes.retain(|(dni_canje, _)| *dni_canje != dni);
+            self.facturacion_socios.retain(|facturacion| facturacion.dni != dni);
+            self.aptos_medicos.retain(|apto| apto.dni != dni);
+            for clase in self.clases.iter_mut() {
+                clase.inscriptos.retain(|inscripto| *inscripto != dni);
+                clase.espera.retain(|esperando| *esperando != dni);
+                clase.promover_espera();
+            }
+            let ahora = self.env().block_timestamp();
+            let mut cobrado = Monto(0);
+            for reserva in self.reservas.iter_mut().filter(|reserva| (reserva.dni == dni) && (reserva.inicio > ahora) && !reserva.cancelada) {
+                reserva.cancelada = true;
+                if reserva.cobrada {
+                    cobrado = verificar_aritmetica(cobrado.sumar(Monto(reserva.monto)));
+                }
+            }
+            self.acreditar_reintegro(dni, cobrado.valor());
+            let reintegro = self.reintegros_pendientes.iter().find(|(dni_reintegro, _)| *dni_reintegro == dni).map(|(_, reintegro)| *reintegro).unwrap_or(0);
+            self.registrar_asiento(TipoAsiento::Reintegro, dni, reintegro, None);
+            self.reintegros_pendientes.retain(|(dni_reintegro, _)| *dni_reintegro != dni);
+            for carnet in self.carnets.iter_mut().filter(|carnet| carnet.dni == dni) {
+                carnet.revocado = true;
+            }
+            self.registrar_auditoria(AccionAdministrativa::BajaSocio, Some(ValorAuditado::Dni(dni)), None);
+            return true;
+        }
+
+        ///El método vincular_titular_carnet asigna la cuenta que posee el carnet del socio con el dni ingresado, o la
+        ///desvincula si se ingresa None. Cada cuenta puede poseer un solo carnet vigente, para que la tenencia identifique
+        ///a un único socio. Arrojará un panic si no se cuenta con los permisos necesarios, si el socio no tiene un carnet
+        ///vigente o si la cuenta ya posee otro. Retorna true.
+        #[ink(message)]
+        pub fn vincular_titular_carnet (&mut self, dni: u32, titular: Option<AccountId>) -> bool {
+            return self.vincular_titular_carnet_priv(dni, titular);
+        }
+
+        fn vincular_titular_carnet_priv (&mut self, dni: u32, titular: Option<AccountId>) -> bool {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if let Some (cuenta) = titular {
+                if self.carnets.iter().any(|carnet| !carnet.revocado && (carnet.titular == Some(cuenta)) && (carnet.dni != dni)) {
+                    return panic!("La cuenta ya posee el carnet de otro socio!");
+                }
+            }
+            let Some (carnet) = self.carnets.iter_mut().find(|carnet| (carnet.dni == dni) && !carnet.revocado) else {
+                return panic!("El socio no tiene un carnet vigente!");
+            };
+            carnet.titular = titular;
+            return true;
+        }
+
+        ///El método get_carnet devuelve el carnet vigente del socio con el dni ingresado, o None si no tiene uno. Sin los
+        ///permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_carnet (&self, dni: u32) -> Option<Carnet> {
+            return self.get_carnet_priv(dni);
+        }
+
+        fn get_carnet_priv (&self, dni: u32) -> Option<Carnet> {
+            if self.verificar_permisos() || self.es_lector() {
+                return self.carnets.iter().find(|carnet| (carnet.dni == dni) && !carnet.revocado).cloned();
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///El método estado_carnet permite a cualquiera, por ejemplo un establecimiento adherido, consultar el estado del
+        ///carnet con el numero ingresado: al dia, con deuda o revocado. Retorna None si el carnet no existe.
+        #[ink(message)]
+        pub fn estado_carnet (&self, id: u32) -> Option<EstadoCarnet> {
+            return self.carnets.iter().find(|carnet| carnet.id == id).map(|carnet| self.estado_de(carnet));
+        }
+
+        ///El método verificar_carnet_cuenta permite a cualquiera consultar si la cuenta ingresada posee un carnet vigente,
+        ///y en ese caso su estado. Retorna None si la cuenta no posee ningún carnet vigente.
+        #[ink(message)]
+        pub fn verificar_carnet_cuenta (&self, cuenta: AccountId) -> Option<EstadoCarnet> {
+            return self.carnets.iter().find(|carnet| !carnet.revocado && (carnet.titular == Some(cuenta))).map(|carnet| self.estado_de(carnet));
+        }
+
+        ///Identificador de la colección PSP34 de carnets: la cuenta del contrato.
+        #[ink(message, selector = 0xffa27a5f)]
+        pub fn collection_id (&self) -> Id {
+            return Id::Bytes(AsRef::<[u8]>::as_ref(&self.env().account_id()).to_vec());
+        }
+
+        ///Cantidad de carnets vigentes que posee la cuenta (0 o 1), según el estándar PSP34.
+        #[ink(message, selector = 0xcde7e55f)]
+        pub fn balance_of (&self, owner: AccountId) -> u32 {
+            return self.carnets.iter().filter(|carnet| !carnet.revocado && (carnet.titular == Some(owner))).count() as u32;
+        }
+
+        ///Cuenta titular del carnet, según el estándar PSP34. Los carnets revocados o sin titular no tienen dueño.
+        #[ink(message, selector = 0x1168624d)]
+        pub fn owner_of (&self, id: Id) -> Option<AccountId> {
+            return self.carnet_de_id(&id).filter(|carnet| !carnet.revocado).and_then(|carnet| carnet.titular);
+        }
+
+        ///Los carnets son intransferibles, por lo que ninguna cuenta tiene autorización sobre ellos.
+        #[ink(message, selector = 0x4790f55a)]
+        pub fn allowance (&self, _owner: AccountId, _operator: AccountId, _id: Option<Id>) -> bool {
+            return false;
+        }
+
+        ///Los carnets son intransferibles: siempre rechaza la aprobación.
+        #[ink(message, selector = 0x1932a8b0)]
+        pub fn approve (&mut self, _operator: AccountId, _id: Option<Id>, _approved: bool) -> Result<(), PSP34Error> {
+            return Err(PSP34Error::Custom(String::from("El carnet de socio es intransferible")));
+        }
+
+        ///Los carnets son intransferibles: siempre rechaza la transferencia.
+        #[ink(message, selector = 0x3128d61b)]
+        pub fn transfer (&mut self, _to: AccountId, _id: Id, _data: Vec<u8>) -> Result<(), PSP34Error> {
+            return Err(PSP34Error::Custom(String::from("El carnet de socio es intransferible")));
+        }
+
+        ///Cantidad de carnets vigentes, según el estándar PSP34.
+        #[ink(message, selector = 0x628413fe)]
+        pub fn total_supply (&self) -> u128 {
+            return self.carnets.iter().filter(|carnet| !carnet.revocado).count() as u128;
+        }
+
+        ///Metadatos del carnet, según la extensión PSP34Metadata. Las claves disponibles son "categoria", "deporte" (solo
+        ///si el socio eligió uno) y "estado", y los valores se devuelven como texto.
+        #[ink(message, selector = 0xf19d48d1)]
+        pub fn get_attribute (&self, id: Id, key: Vec<u8>) -> Option<Vec<u8>> {
+            let carnet = self.carnet_de_id(&id)?;
+            let valor = match key.as_slice() {
+                b"categoria" => ink::prelude::format!("{:?}", carnet.categoria),
+                b"deporte" => ink::prelude::format!("{:?}", carnet.deporte.as_ref()?),
+                b"estado" => ink::prelude::format!("{:?}", self.estado_de(carnet)),
+                _ => return None,
+            };
+            return Some(valor.into_bytes());
+        }
+
+        ///Busca el carnet con el identificador PSP34 recibido, que debe ser de la variante U32.
+        fn carnet_de_id (&self, id: &Id) -> Option<&Carnet> {
+            let Id::U32(numero) = id else {
+                return None;
+            };
+            return self.carnets.iter().find(|carnet| carnet.id == *numero);
+        }
+
+        ///Calcula el estado del carnet: revocado si el socio renunció, con deuda si tiene algún pago vencido impago fuera
+        ///de un plan de cuotas, o alguna cuota vencida impaga de un plan vigente, y al dia en otro caso.
+        fn estado_de (&self, carnet: &Carnet) -> EstadoCarnet {
+            if carnet.revocado {
+                return EstadoCarnet::Revocado;
+            }
+            if self.tiene_deuda_vencida(carnet.dni) {
+                return EstadoCarnet::ConDeuda;
+            }
+            return EstadoCarnet::AlDia;
+        }
+
+        ///Retorna true si el socio con el dni recibido tiene algún pago vencido e impago fuera de un plan de cuotas, o
+        ///alguna cuota vencida de un plan vigente.
+        fn tiene_deuda_vencida (&self, dni: u32) -> bool {
+            let ahora = self.env().block_timestamp();
+            let en_plan = self.pagos_en_plan();
+            let pago_vencido = self.pagos.iter().enumerate().any(|(pos, pago)| (pago.dni == dni) && pago.fecha_pago.is_none() && (pago.fecha_venci < ahora) && !en_plan.contains(&pos));
+            let cuota_vencida = self.planes_cuotas.iter().filter(|plan| (plan.dni == dni) && (plan.estado == EstadoPlan::Vigente))
+                .any(|plan| plan.cuotas.iter().any(|cuota| cuota.fecha_pago.is_none() && (cuota.fecha_venci < ahora)));
+            return pago_vencido || cuota_vencida;
+        }
+
+        ///Retorna true si quien llama es la cuenta titular del carnet vigente del socio con el dni recibido
+        fn es_titular_carnet (&self, dni: u32) -> bool {
+            return self.carnets.iter().any(|carnet| (carnet.dni == dni) && !carnet.revocado && (carnet.titular == Some(self.env().caller())));
+        }
+
+        ///El método crear_plan_cuotas permite al Owner o a un Staff refinanciar todos los pagos vencidos e impagos del socio
+        ///con el dni ingresado en un plan de cantidad_cuotas cuotas, con un interés de tasa_interes por ciento sobre la
+        ///deuda. Las cuotas vencen cada 30 dias a partir de hoy y tienen el mismo monto, salvo la última, que suma el resto
+        ///de la division. Mientras el plan esté vigente, los pagos refinanciados no pueden pagarse por separado. Arrojará un
+        ///panic si no se cuenta con los permisos, si el socio no existe, si la cantidad de cuotas es 0 o si el socio no tiene
+        ///pagos vencidos fuera de otro plan. Retorna el plan creado.
+        #[ink(message)]
+        pub fn crear_plan_cuotas (&mut self, dni: u32, cantidad_cuotas: u32, tasa_interes: u32) -> PlanCuotas {
+            return self.crear_plan_cuotas_priv(dni, cantidad_cuotas, tasa_interes);
+        }
+
+        fn crear_plan_cuotas_priv (&mut self, dni: u32, cantidad_cuotas: u32, tasa_interes: u32) -> PlanCuotas {
+            self.verificar_no_pausado();
+            if !(self.es_admin() || self.es_staff()) {
+                return panic!("No se cuenta con los permisos necesarios!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            if cantidad_cuotas == 0 {
+                return panic!("La cantidad de cuotas debe ser mayor a 0!");
+            }
+            let ahora = self.env().block_timestamp();
+            let en_plan = self.pagos_en_plan();
+            let pagos: Vec<u32> = (0..self.pagos.len())
+                .filter(|pos| (self.pagos[*pos].dni == dni) && self.pagos[*pos].fecha_pago.is_none() && (self.pagos[*pos].fecha_venci < ahora) && !en_plan.contains(pos))
+                .map(|pos| pos as u32).collect();
+            if pagos.is_empty() {
+                return panic!("El socio no tiene pagos vencidos para refinanciar!");
+            }
+            let mut deuda_original = Monto(0);
+            for pos in &pagos {
+                deuda_original = verificar_aritmetica(deuda_original.sumar(Monto(self.pagos[*pos as usize].monto)));
+            }
+            let interes = verificar_aritmetica(deuda_original.multiplicar(tasa_interes as u128)).valor() / 100;
+            let total = verificar_aritmetica(deuda_original.sumar(Monto(interes))).valor();
+            let monto_cuota = total / cantidad_cuotas as u128;
+            let mut cuotas = Vec::new();
+            for numero in 1..=cantidad_cuotas {
+                let dias = nucleo_facturacion::DIAS_VENCIMIENTO_CUOTA.saturating_mul(numero as u64);
+                let fecha_venci = verificar_aritmetica(nucleo_facturacion::Timestamp(ahora).sumar_dias(dias)).valor();
+                let monto = if numero == cantidad_cuotas { monto_cuota + total % cantidad_cuotas as u128 } else { monto_cuota };
+                cuotas.push(CuotaPlan {numero, monto, fecha_venci, fecha_pago: None});
+            }
+            let plan = PlanCuotas {id: self.planes_cuotas.len() as u32 + 1, dni, pagos, deuda_original: deuda_original.valor(), tasa_interes, cuotas, estado: EstadoPlan::Vigente};
+            self.planes_cuotas.push(plan.clone());
+            self.registrar_asiento(TipoAsiento::Recargo, dni, interes, Some(plan.id));
+            self.registrar_auditoria(AccionAdministrativa::CreacionPlanCuotas, None, Some(ValorAuditado::Dni(dni)));
+            return plan;
+        }
+
+        ///El método pagar_cuota_plan registra el pago de la próxima cuota pendiente del plan con el id ingresado, emitiendo
+        ///su recibo y acreditando los puntos de fidelidad como en cualquier pago a término. Al pagarse la última cuota el
+        ///plan queda cumplido y los pagos refinanciados quedan saldados en esa fecha. Arrojará un panic si no se cuenta con
+        ///los permisos necesarios, si el plan no existe o no está vigente, si tiene alguna cuota vencida sin pagar (aunque
+        ///todavía no se haya procesado su caida), o si el monto no coincide con el de la cuota. Retorna la cuota pagada.
+        #[ink(message)]
+        pub fn pagar_cuota_plan (&mut self, id_plan: u32, monto: u128) -> CuotaPlan {
+            return self.pagar_cuota_plan_priv(id_plan, monto);
+        }
+
+        fn pagar_cuota_plan_priv (&mut self, id_plan: u32, monto: u128) -> CuotaPlan {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let fecha_actual = self.env().block_timestamp();
+            let Some (plan) = self.planes_cuotas.iter_mut().find(|plan| plan.id == id_plan) else {
+                return panic!("No se ha encontrado un plan de cuotas con el id ingresado!");
+            };
+            if plan.estado != EstadoPlan::Vigente {
+                return panic!("El plan de cuotas no se encuentra vigente!");
+            }
+            if plan.cuotas.iter().any(|cuota| cuota.fecha_pago.is_none() && (cuota.fecha_venci < fecha_actual)) {
+                return panic!("El plan de cuotas tiene una cuota vencida sin pagar y se encuentra caido!");
+            }
+            let Some (cuota) = plan.cuotas.iter_mut().find(|cuota| cuota.fecha_pago.is_none()) else {
+                return panic!("El plan de cuotas no tiene cuotas pendientes!");
+            };
+            if cuota.monto != monto {
+                return panic!("El monto ingresado no coincide con el de la cuota!");
+            }
+            cuota.fecha_pago = Some(fecha_actual);
+            let cuota = cuota.clone();
+            let plan = plan.clone();
+            let pago_cuota = Pago::new(plan.dni, cuota.monto, cuota.fecha_venci, Some(fecha_actual), false);
+            let recibo = self.emitir_recibo(&pago_cuota, OrigenRecibo::CuotaPlan(plan.id, cuota.numero));
+            self.acreditar_puntos(&pago_cuota, recibo.numero);
+            if plan.cuotas.iter().all(|cuota| cuota.fecha_pago.is_some()) {
+                for pos in &plan.pagos {
+                    self.pagos[*pos as usize].marcar_pagado(fecha_actual);
+                }
+                if let Some (plan) = self.planes_cuotas.iter_mut().find(|plan| plan.id == id_plan) {
+                    plan.estado = EstadoPlan::Cumplido;
+                }
+            }
+            return cuota;
+        }
+
+        ///El método procesar_planes_caidos da por caidos los planes vigentes que tienen alguna cuota vencida sin pagar. Al
+        ///caer un plan, los pagos refinanciados vuelven a ser deuda del socio con su monto y vencimiento originales, y las
+        ///cuotas ya pagadas quedan registradas en el plan. De lo cobrado en esas cuotas, la parte proporcional del interés se
+        ///conserva y solo se anula en la cuenta corriente el recargo de lo que no se pagó; el resto salda, en orden, los
+        ///pagos refinanciados que alcanza a cubrir por completo, y lo que sobra queda como reintegro pendiente para el próximo
+        ///pago del socio. La caida queda en la auditoria con el DNI del socio. Arrojará un panic si no se cuenta con los
+        ///permisos necesarios. Retorna la cantidad de planes caidos.
+        #[ink(message)]
+        pub fn procesar_planes_caidos (&mut self) -> u32 {
+            return self.procesar_planes_caidos_priv();
+        }
+
+        fn procesar_planes_caidos_priv (&mut self) -> u32 {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let ahora = self.env().block_timestamp();
+            let mut caidos = Vec::new();
+            for plan in self.planes_cuotas.iter_mut().filter(|plan| plan.estado == EstadoPlan::Vigente) {
+                if plan.cuotas.iter().any(|cuota| cuota.fecha_pago.is_none() && (cuota.fecha_venci < ahora)) {
+                    plan.estado = EstadoPlan::Caido;
+                    caidos.push(plan.clone());
+                }
+            }
+            for plan in &caidos {
+                let total: u128 = plan.cuotas.iter().map(|cuota| cuota.monto).sum();
+                let pagado: u128 = plan.cuotas.iter().filter(|cuota| cuota.fecha_pago.is_some()).map(|cuota| cuota.monto).sum();
+                let recargo = total - plan.deuda_original;
+                let interes_cobrado = verificar_aritmetica(Monto(recargo).multiplicar(pagado)).valor().checked_div(total).unwrap_or(0);
+                let mut capital_cobrado = pagado - interes_cobrado;
+                for pos in &plan.pagos {
+                    let pago = &mut self.pagos[*pos as usize];
+                    if pago.monto > capital_cobrado {
+                        break;
+                    }
+                    capital_cobrado -= pago.monto;
+                    pago.marcar_pagado(ahora);
+                }
+                self.acreditar_reintegro(plan.dni, capital_cobrado);
+                self.registrar_asiento(TipoAsiento::AnulacionRecargo, plan.dni, recargo - interes_cobrado, Some(plan.id));
+                self.registrar_auditoria(AccionAdministrativa::CaidaPlanCuotas, Some(ValorAuditado::Dni(plan.dni)), None);
+            }
+            return caidos.len() as u32;
+        }
+
+        ///El método get_planes_cuotas devuelve todos los planes de cuotas del socio con el dni ingresado, en cualquier
+        ///estado. Sin los permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_planes_cuotas (&self, dni: u32) -> Vec<PlanCuotas> {
+            return self.get_planes_cuotas_priv(dni);
+        }
+
+        fn get_planes_cuotas_priv (&self, dni: u32) -> Vec<PlanCuotas> {
+            if self.verificar_permisos() || self.es_lector() {
+                return self.planes_cuotas.iter().filter(|plan| plan.dni == dni).cloned().collect();
+            }
+            return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+        }
+
+        ///Devuelve las posiciones de los pagos refinanciados por algún plan de cuotas vigente.
+        fn pagos_en_plan (&self) -> BTreeSet<usize> {
+            return self.planes_cuotas.iter().filter(|plan| plan.estado == EstadoPlan::Vigente)
+                .flat_map(|plan| plan.pagos.iter().map(|pos| *pos as usize)).collect();
+        }
+
+        ///El método actualizar_descuento_plan permite al Owner o a un Staff definir el porcentaje de descuento, sobre la suma
+        ///de las cuotas mensuales que cubre, del plan de facturación indicado (2 trimestral, 3 semestral o 4 anual). Arrojará
+        ///un panic si no se cuenta con los permisos, si el id de plan no es valido o es el mensual, o si el porcentaje supera
+        ///100. Retorna true.
+        #[ink(message)]
+        pub fn actualizar_descuento_plan (&mut self, id_plan: u32, porcentaje: u32) -> bool {
+            return self.actualizar_descuento_plan_priv(id_plan, porcentaje);
+        }
+
+        fn actualizar_descuento_plan_priv (&mut self, id_plan: u32, porcentaje: u32) -> bool {
+            self.verificar_no_pausado();
+            if !(self.es_admin() || self.es_staff()) {
+                return panic!("No se cuenta con los permisos necesarios!");
+            }
+            let Some (plan) = PlanFacturacion::plan_from_id(&id_plan) else {
+                return panic!("El id de plan de facturacion ingresado no es valido!");
+            };
+            let anterior = self.descuentos_plan.get_descuento(&plan);
+            if !self.descuentos_plan.set_descuento(porcentaje, &plan) {
+                return panic!("El descuento debe ser de un plan de mas de un mes y no superar el 100 por ciento!");
+            }
+            self.registrar_auditoria(AccionAdministrativa::CambioDescuentoPlan, Some(ValorAuditado::Cantidad(anterior as u64)), Some(ValorAuditado::Cantidad(porcentaje as u64)));
+            return true;
+        }
+
+        ///El método cambiar_plan_facturacion cambia el plan de facturación (1 mensual, 2 trimestral, 3 semestral o 4 anual)
+        ///del socio con el dni ingresado. El cambio rige desde su próximo ciclo: si su última cuota todavía cubre meses por
+        ///venir, el nuevo plan se aplica recién en la emisión siguiente a esos meses. Arrojará un panic si no se cuenta con
+        ///los permisos necesarios, si el socio no existe o si el id de plan no es valido. Retorna true.
+        #[ink(message)]
+        pub fn cambiar_plan_facturacion (&mut self, dni: u32, id_plan: u32) -> bool {
+            return self.cambiar_plan_facturacion_priv(dni, id_plan);
+        }
+
+        fn cambiar_plan_facturacion_priv (&mut self, dni: u32, id_plan: u32) -> bool {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            let Some (plan) = PlanFacturacion::plan_from_id(&id_plan) else {
+                return panic!("El id de plan de facturacion ingresado no es valido!");
+            };
+            match self.facturacion_socios.iter_mut().find(|facturacion| facturacion.dni == dni) {
+                Some (facturacion) => facturacion.plan_siguiente = Some(plan),
+                None => self.facturacion_socios.push(FacturacionSocio {dni, plan: PlanFacturacion::Mensual, plan_siguiente: Some(plan), meses_cubiertos: 0}),
+            }
+            return true;
+        }
+
+        ///El método get_facturacion_socio devuelve la facturación del socio con el dni ingresado: su plan vigente, el que
+        ///regirá desde el próximo ciclo si lo cambió, y cuantas emisiones más cubre su última cuota. Sin los permisos
+        ///necesarios, o si el socio no existe, arroja un panic.
+        #[ink(message)]
+        pub fn get_facturacion_socio (&self, dni: u32) -> FacturacionSocio {
+            return self.get_facturacion_socio_priv(dni);
+        }
+
+        fn get_facturacion_socio_priv (&self, dni: u32) -> FacturacionSocio {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            return self.facturacion_socios.iter().find(|facturacion| facturacion.dni == dni).cloned()
+                .unwrap_or(FacturacionSocio {dni, plan: PlanFacturacion::Mensual, plan_siguiente: None, meses_cubiertos: 0});
+        }
+
+        ///Avanza un mes la facturación del socio. Si su última cuota todavía cubre este mes, lo descuenta y retorna None;
+        ///si no, aplica el plan siguiente si lo había cambiado y retorna el plan con el que debe emitirse su cuota.
+        fn iniciar_ciclo_facturacion (&mut self, dni: u32) -> Option<PlanFacturacion> {
+            let Some (facturacion) = self.facturacion_socios.iter_mut().find(|facturacion| facturacion.dni == dni) else {
+                return Some(PlanFacturacion::Mensual);
+            };
+            if facturacion.meses_cubiertos > 0 {
+                facturacion.meses_cubiertos -= 1;
+                return None;
+            }
+            if let Some (plan) = facturacion.plan_siguiente.take() {
+                facturacion.plan = plan;
+            }
+            facturacion.meses_cubiertos = facturacion.plan.meses() - 1;
+            return Some(facturacion.plan.clone());
+        }
+
+        ///Calcula el precio de una cuota del plan recibido a partir de la cuota mensual del socio: la cuota mensual por la
+        ///cantidad de meses del plan, menos el descuento del plan.
+        fn precio_plan (&self, mensual: u128, plan: &PlanFacturacion) -> u128 {
+            let bruto = verificar_aritmetica(Monto(mensual).multiplicar(plan.meses() as u128));
+            let neto = verificar_aritmetica(bruto.multiplicar((100 - self.descuentos_plan.get_descuento(plan)) as u128));
+            return neto.valor() / 100;
+        }
+
+        ///El método configurar_politica_morosidad permite al Owner definir a partir de cuantos pagos vencidos impagos, o de
+        ///cuantos dias de atraso del más antiguo, un socio pasa a ser moroso y a partir de cuantos queda suspendido. Un
+        ///umbral en 0 no se aplica. Arrojará un panic si no es Owner, o si algún umbral de suspensión es menor al de
+        ///morosidad correspondiente. Retorna true.
+        #[ink(message)]
+        pub fn configurar_politica_morosidad (&mut self, pagos_para_moroso: u32, dias_para_moroso: u64, pagos_para_suspension: u32, dias_para_suspension: u64) -> bool {
+            return self.configurar_politica_morosidad_priv(pagos_para_moroso, dias_para_moroso, pagos_para_suspension, dias_para_suspension);
+        }
+
+        fn configurar_politica_morosidad_priv (&mut self, pagos_para_moroso: u32, dias_para_moroso: u64, pagos_para_suspension: u32, dias_para_suspension: u64) -> bool {
+            self.verificar_no_pausado();
+            if !self.es_admin() {
+                return panic!("No cuenta con rango Owner para realizar esta operacion!");
+            }
+            if ((pagos_para_suspension > 0) && (pagos_para_suspension < pagos_para_moroso)) || ((dias_para_suspension > 0) && (dias_para_suspension < dias_para_moroso)) {
+                return panic!("Los umbrales de suspension no pueden ser menores a los de morosidad!");
+            }
+            let anterior = self.politica_morosidad.clone();
+            self.politica_morosidad = PoliticaMorosidad::new(pagos_para_moroso, dias_para_moroso, pagos_para_suspension, dias_para_suspension);
+            self.registrar_auditoria(AccionAdministrativa::CambioPoliticaMorosidad, Some(ValorAuditado::PoliticaMorosidad(anterior)), Some(ValorAuditado::PoliticaMorosidad(self.politica_morosidad.clone())));
+            return true;
+        }
+
+        ///El método get_politica_morosidad devuelve los umbrales de morosidad y suspensión vigentes.
+        #[ink(message)]
+        pub fn get_politica_morosidad (&self) -> PoliticaMorosidad {
+            return self.politica_morosidad.clone();
+        }
+
+        ///El método get_estado_socio devuelve el estado del socio con el dni ingresado según la politica de morosidad:
+        ///activo, moroso o suspendido. Arrojará un panic si no se cuenta con los permisos necesarios o si el socio no existe.
+        #[ink(message)]
+        pub fn get_estado_socio (&self, dni: u32) -> EstadoSocio {
+            return self.get_estado_socio_priv(dni);
+        }
+
+        fn get_estado_socio_priv (&self, dni: u32) -> EstadoSocio {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            return self.estado_socio(dni);
+        }
+
+        ///El método get_socios_por_estado devuelve los DNI de todos los socios que se encuentran en el estado ingresado,
+        ///por ejemplo, para listar a los suspendidos. Sin los permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_socios_por_estado (&self, estado: EstadoSocio) -> Vec<u32> {
+            return self.get_socios_por_estado_priv(estado);
+        }
+
+        fn get_socios_por_estado_priv (&self, estado: EstadoSocio) -> Vec<u32> {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            return self.socios.iter().map(|socio| socio.dni).filter(|dni| self.estado_socio(*dni) == estado).collect();
+        }
+
+        ///Calcula el estado del socio según la politica de morosidad, a partir de sus pagos vencidos impagos que no
+        ///fueron refinanciados y de las cuotas vencidas impagas de sus planes de cuotas vigentes.
+        fn estado_socio (&self, dni: u32) -> EstadoSocio {
+            let ahora = self.env().block_timestamp();
+            let en_plan = self.pagos_en_plan();
+            let mut vencimientos: Vec<u64> = self.pagos.iter().enumerate()
+                .filter(|(pos, pago)| (pago.dni == dni) && pago.fecha_pago.is_none() && (pago.fecha_venci < ahora) && !en_plan.contains(pos))
+                .map(|(_, pago)| pago.fecha_venci).collect();
+            for plan in self.planes_cuotas.iter().filter(|plan| (plan.dni == dni) && (plan.estado == EstadoPlan::Vigente)) {
+                vencimientos.extend(plan.cuotas.iter().filter(|cuota| cuota.fecha_pago.is_none() && (cuota.fecha_venci < ahora)).map(|cuota| cuota.fecha_venci));
+            }
+            let Some (mas_antiguo) = vencimientos.iter().min() else {
+                return EstadoSocio::Activo;
+            };
+            let dias_atraso = (ahora - mas_antiguo) / 1.from_dias();
+            return self.politica_morosidad.estado_para(vencimientos.len() as u32, dias_atraso);
+        }
+
+        ///El método estado_de_cuenta devuelve el estado de cuenta del socio con el dni ingresado entre las fechas desde y
+        ///hasta, ambas incluidas: los cargos, bonificaciones, recargos, descuentos, pagos y reversiones del periodo en orden
+        ///cronológico, con el saldo acumulado, junto a la deuda pendiente, el vencimiento impago más antiguo y el próximo
+        ///vencimiento a la fecha de la consulta. Los asientos se registran desde que existe la cuenta corriente, por lo que
+        ///los movimientos anteriores (o los de una instantanea importada) no figuran. Puede consultarlo quien tenga los
+        ///permisos necesarios, un lector o la cuenta titular del carnet del socio. Arrojará un panic si el socio no existe,
+        ///si desde es posterior a hasta o si no se cuenta con los permisos.
+        #[ink(message)]
+        pub fn estado_de_cuenta (&self, dni: u32, desde: u64, hasta: u64) -> EstadoDeCuenta {
+            return self.estado_de_cuenta_priv(dni, desde, hasta);
+        }
+
+        fn estado_de_cuenta_priv (&self, dni: u32, desde: u64, hasta: u64) -> EstadoDeCuenta {
+            let titular = self.es_titular_carnet(dni);
+            if !(self.verificar_permisos() || self.es_lector() || titular) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            return self.armar_estado_de_cuenta(dni, desde, hasta);
+        }
+
+        ///El método mi_estado_de_cuenta es la consulta de autoservicio del estado de cuenta: devuelve el del socio cuyo
+        ///carnet vigente tiene como titular a la cuenta que llama, entre las fechas desde y hasta. Arrojará un panic si la
+        ///cuenta no es titular de ningún carnet vigente o si desde es posterior a hasta.
+        #[ink(message)]
+        pub fn mi_estado_de_cuenta (&self, desde: u64, hasta: u64) -> EstadoDeCuenta {
+            return self.mi_estado_de_cuenta_priv(desde, hasta);
+        }
+
+        fn mi_estado_de_cuenta_priv (&self, desde: u64, hasta: u64) -> EstadoDeCuenta {
+            let caller = self.env().caller();
+            let Some (carnet) = self.carnets.iter().find(|carnet| !carnet.revocado && (carnet.titular == Some(caller))) else {
+                return panic!("La cuenta no es titular de ningun carnet vigente!");
+            };
+            return self.armar_estado_de_cuenta(carnet.dni, desde, hasta);
+        }
+
+        ///Arma el estado de cuenta del socio a partir de sus asientos y de su deuda pendiente.
+        fn armar_estado_de_cuenta (&self, dni: u32, desde: u64, hasta: u64) -> EstadoDeCuenta {
+            if !self.socios.iter().any(|socio| socio.dni == dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            if desde > hasta {
+                return panic!("La fecha desde no puede ser posterior a la fecha hasta!");
+            }
+            let efecto = |asiento: &AsientoCuenta| if asiento.tipo.es_debito() { asiento.monto as i128 } else { -(asiento.monto as i128) };
+            let asientos = self.asientos.iter().filter(|asiento| (asiento.dni == dni) && (asiento.fecha <= hasta));
+            let saldo_inicial: i128 = asientos.clone().filter(|asiento| asiento.fecha < desde).map(efecto).sum();
+            let mut saldo = saldo_inicial;
+            let mut lineas = Vec::new();
+            for asiento in asientos.filter(|asiento| asiento.fecha >= desde) {
+                saldo += efecto(asiento);
+                lineas.push(LineaEstadoCuenta {asiento: asiento.clone(), saldo});
+            }
+            let ahora = self.env().block_timestamp();
+            let en_plan = self.pagos_en_plan();
+            let mut pendientes: Vec<(u64, u128)> = self.pagos.iter().enumerate()
+                .filter(|(pos, pago)| (pago.dni == dni) && pago.fecha_pago.is_none() && !en_plan.contains(pos))
+                .map(|(_, pago)| (pago.fecha_venci, pago.monto)).collect();
+            for plan in self.planes_cuotas.iter().filter(|plan| (plan.dni == dni) && (plan.estado == EstadoPlan::Vigente)) {
+                pendientes.extend(plan.cuotas.iter().filter(|cuota| cuota.fecha_pago.is_none()).map(|cuota| (cuota.fecha_venci, cuota.monto)));
+            }
+            let mut deuda_total = Monto(0);
+            for (_, monto) in &pendientes {
+                deuda_total = verificar_aritmetica(deuda_total.sumar(Monto(*monto)));
+            }
+            return EstadoDeCuenta {
+                dni,
+                saldo_inicial,
+                lineas,
+                saldo_final: saldo,
+                deuda_total: deuda_total.valor(),
+                vencido_mas_antiguo: pendientes.iter().map(|(venci, _)| *venci).filter(|venci| *venci < ahora).min(),
+                proximo_vencimiento: pendientes.iter().map(|(venci, _)| *venci).filter(|venci| *venci >= ahora).min(),
+            };
+        }
+
+        ///Registra un asiento en la cuenta corriente del socio con la fecha actual. Los montos en 0 no se registran.
+        fn registrar_asiento (&mut self, tipo: TipoAsiento, dni: u32, monto: u128, referencia: Option<u32>) {
+            if monto > 0 {
+                self.asientos.push(AsientoCuenta {tipo, dni, fecha: self.env().block_timestamp(), monto, referencia});
+            }
+        }
+
+        ///El método emitir_libre_deuda emite un certificado de libre deuda para el socio con el dni ingresado, valido desde
+        ///hoy y durante la vigencia de certificados configurada. La sal, elegida al azar por quien lo solicita y que el socio
+        ///conserva para acreditar que el certificado es suyo, oculta el DNI en el certificado; si el socio se registró con la
+        ///protección de datos se usa el compromiso de su registro y la sal se ignora. Puede emitirlo quien tenga los permisos
+        ///necesarios o la cuenta titular del carnet del socio. Arrojará un panic si no se cuenta con los permisos, si el socio no existe o
+        ///si tiene algún pago pendiente, vencido o no, incluidos los refinanciados en un plan de cuotas, o alguna reserva
+        ///vigente cuyo arancel todavía no se sumó a una cuota. Retorna el certificado emitido.
+        #[ink(message)]
+        pub fn emitir_libre_deuda (&mut self, dni: u32, sal: [u8; 32]) -> CertificadoLibreDeuda {
+            return self.emitir_libre_deuda_priv(dni, sal);
+        }
+
+        fn emitir_libre_deuda_priv (&mut self, dni: u32, sal: [u8; 32]) -> CertificadoLibreDeuda {
+            self.verificar_no_pausado();
+            let titular = self.es_titular_carnet(dni);
+            if !(self.verificar_permisos() || titular) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let Some (socio) = self.socios.iter().find(|socio| socio.dni == dni) else {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            };
+            if self.pagos.iter().any(|pago| (pago.dni == dni) && pago.fecha_pago.is_none()) {
+                return panic!("El socio tiene pagos pendientes!");
+            }
+            if self.reservas.iter().any(|reserva| (reserva.dni == dni) && (reserva.cobro == CobroReserva::ProximaCuota) && !reserva.cobrada && !reserva.cancelada) {
+                return panic!("El socio tiene reservas pendientes de cobro!");
+            }
+            let hash_dni = match &socio.compromiso {
+                Some (compromiso) => compromiso.dni,
+                None => CertificadoLibreDeuda::calcular_hash_dni(dni, &sal),
+            };
+            let id = self.certificados.len() as u32 + 1;
+            let emitido = self.env().block_timestamp();
+            let valido_hasta = nucleo_facturacion::Timestamp(emitido).sumar_saturado(self.vigencia_certificados).valor();
+            let hash = CertificadoLibreDeuda::calcular_hash(id, hash_dni, emitido, valido_hasta);
+            let certificado = CertificadoLibreDeuda {id, hash_dni, emitido, valido_hasta, hash, revocado: false};
+            self.certificados.push(certificado.clone());
+            return certificado;
+        }
+
+        ///El método verificar_libre_deuda permite a cualquiera, por ejemplo otro club o la organización de un torneo,
+        ///comprobar un certificado de libre deuda: retorna true solo si su contenido coincide con su hash y con el
+        ///certificado emitido por el club, si no fue revocado y si la fecha actual está dentro de su validez. No requiere
+        ///permisos.
+        #[ink(message)]
+        pub fn verificar_libre_deuda (&self, certificado: CertificadoLibreDeuda) -> bool {
+            return self.verificar_libre_deuda_priv(certificado);
+        }
+
+        fn verificar_libre_deuda_priv (&self, certificado: CertificadoLibreDeuda) -> bool {
+            let hash = CertificadoLibreDeuda::calcular_hash(certificado.id, certificado.hash_dni, certificado.emitido, certificado.valido_hasta);
+            if hash != certificado.hash {
+                return false;
+            }
+            let Some (emitido) = self.certificados.iter().find(|emitido| emitido.id == certificado.id) else {
+                return false;
+            };
+            let vigente = self.env().block_timestamp() <= emitido.valido_hasta;
+            return (emitido.hash == certificado.hash) && !emitido.revocado && vigente;
+        }
+
+        ///El método revocar_libre_deuda permite al Owner o a un Staff revocar el certificado con el numero ingresado, por
+        ///ejemplo si se emitió por error. Arrojará un panic si no se cuenta con los permisos, si el certificado no existe o
+        ///si ya estaba revocado. Retorna true.
+        #[ink(message)]
+        pub fn revocar_libre_deuda (&mut self, id: u32) -> bool {
+            return self.revocar_libre_deuda_priv(id);
+        }
+
+        fn revocar_libre_deuda_priv (&mut self, id: u32) -> bool {
+            self.verificar_no_pausado();
+            if !(self.es_admin() || self.es_staff()) {
+                return panic!("No se cuenta con los permisos necesarios!");
+            }
+            let Some (certificado) = self.certificados.iter_mut().find(|certificado| certificado.id == id) else {
+                return panic!("No se ha encontrado un certificado con el numero ingresado!");
+            };
+            if certificado.revocado {
+                return panic!("El certificado ya fue revocado!");
+            }
+            certificado.revocado = true;
+            self.registrar_auditoria(AccionAdministrativa::RevocacionLibreDeuda, None, Some(ValorAuditado::Cantidad(id as u64)));
+            return true;
+        }
+
+        ///Este método permite al Owner modificar la validez, en dias, de los certificados de libre deuda que se emitan de
+        ///ahora en más. De no ser Owner, arrojará un panic. Retorna true.
+        #[ink(message)]
+        pub fn actualizar_vigencia_certificados (&mut self, dias: u64) -> bool {
+            return self.actualizar_vigencia_certificados_priv(dias);
+        }
+
+        fn actualizar_vigencia_certificados_priv (&mut self, dias: u64) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                let anterior = self.vigencia_certificados;
+                self.vigencia_certificados = verificar_aritmetica(nucleo_facturacion::duracion_dias(dias));
+                self.registrar_auditoria(AccionAdministrativa::CambioVigenciaCertificados, Some(ValorAuditado::Cantidad(anterior)), Some(ValorAuditado::Cantidad(self.vigencia_certificados)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///El método registrar_apto_medico registra el apto médico del socio con el dni ingresado, con su fecha de emisión,
+        ///su fecha de vencimiento y opcionalmente el hash del documento que lo respalda. Si el socio ya tenía uno, lo
+        ///reemplaza. Arrojará un panic si no se cuenta con los permisos necesarios, si el socio no existe o si el
+        ///vencimiento no es posterior a la emisión. Retorna true.
+        #[ink(message)]
+        pub fn registrar_apto_medico (&mut self, dni: u32, emitido: u64, vence: u64, documento: Option<Hash>) -> bool {
+            return self.registrar_apto_medico_priv(dni, emitido, vence, documento);
+        }
+
+        fn registrar_apto_medico_priv (&mut self, dni: u32, emitido: u64, vence: u64, documento: Option<Hash>) -> bool {
+            self.verificar_no_pausado();
+            if !self.verificar_permisos() {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            if vence <= emitido {
+                return panic!("El vencimiento del apto medico debe ser posterior a su emision!");
+            }
+            self.aptos_medicos.retain(|apto| apto.dni != dni);
+            self.aptos_medicos.push(AptoMedico {dni, emitido, vence, documento});
+            return true;
+        }
+
+        ///El método get_apto_medico devuelve el apto médico registrado del socio con el dni ingresado, vigente o no, o
+        ///None si nunca se le registró uno. Sin los permisos necesarios, o si el socio no existe, arroja un panic.
+        #[ink(message)]
+        pub fn get_apto_medico (&self, dni: u32) -> Option<AptoMedico> {
+            return self.get_apto_medico_priv(dni);
+        }
+
+        fn get_apto_medico_priv (&self, dni: u32) -> Option<AptoMedico> {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            return self.aptos_medicos.iter().find(|apto| apto.dni == dni).cloned();
+        }
+
+        ///El método habilitado_para_deporte indica si el socio con el dni ingresado puede realizar actividades deportivas,
+        ///es decir, si no es de categoría C y tiene un apto médico vigente a la fecha. Las acciones deportivas del club se
+        ///bloquean mientras no lo esté. Sin los permisos necesarios, o si el socio no existe, arroja un panic.
+        #[ink(message)]
+        pub fn habilitado_para_deporte (&self, dni: u32) -> bool {
+            return self.habilitado_para_deporte_priv(dni);
+        }
+
+        fn habilitado_para_deporte_priv (&self, dni: u32) -> bool {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !self.existe_socio(dni) {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            }
+            return self.apto_para_deporte(dni);
+        }
+
+        ///El método get_aptos_por_vencer devuelve los aptos médicos que, estando vigentes hoy, vencen dentro de la cantidad
+        ///de dias ingresada, ordenados del más próximo a vencer al más lejano, para avisar a los socios a tiempo. Sin los
+        ///permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_aptos_por_vencer (&self, dias: u64) -> Vec<AptoMedico> {
+            return self.get_aptos_por_vencer_priv(dias);
+        }
+
+        fn get_aptos_por_vencer_priv (&self, dias: u64) -> Vec<AptoMedico> {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let hoy = self.env().block_timestamp();
+            let limite = nucleo_facturacion::Timestamp(hoy).sumar_saturado(verificar_aritmetica(nucleo_facturacion::duracion_dias(dias))).valor();
+            let mut aptos: Vec<AptoMedico> = self.aptos_medicos.iter().filter(|apto| apto.esta_vigente(hoy) && (apto.vence <= limite)).cloned().collect();
+            aptos.sort_by_key(|apto| apto.vence);
+            return aptos;
+        }
+
+        ///Retorna true si el socio con el dni recibido puede practicar deportes: no es de categoría C y su apto médico
+        ///está vigente a la fecha del bloque.
+        fn apto_para_deporte (&self, dni: u32) -> bool {
+            let hoy = self.env().block_timestamp();
+            let practica = self.socios.iter().any(|socio| (socio.dni == dni) && (socio.categoria != CategoriasSocios::C));
+            return practica && self.aptos_medicos.iter().any(|apto| (apto.dni == dni) && apto.esta_vigente(hoy));
+        }
+
+        ///El método crear_clase permite al Owner o a un Staff crear una clase del deporte con el id ingresado, dictada por
+        ///la cuenta profesor, en los horarios semanales ingresados como tuplas (id de dia, minuto de inicio, minuto de fin)
+        ///y con un cupo máximo de inscriptos. Arrojará un panic si no se cuenta con los permisos, si el deporte o algún
+        ///horario no es valido, si no se ingresa ningún horario o si el cupo es 0. Retorna el numero de la clase creada.
+        #[ink(message)]
+        pub fn crear_clase (&mut self, id_deporte: u32, profesor: AccountId, horarios: Vec<(u32, u32, u32)>, cupo: u32) -> u32 {
+            return self.crear_clase_priv(id_deporte, profesor, horarios, cupo);
+        }
+
+        fn crear_clase_priv (&mut self, id_deporte: u32, profesor: AccountId, horarios: Vec<(u32, u32, u32)>, cupo: u32) -> u32 {
+            self.verificar_no_pausado();
+            if !(self.es_admin() || self.es_staff()) {
+                return panic!("No se cuenta con los permisos necesarios!");
+            }
+            let Some (deporte) = Deporte::deporte_from_id(&Some(id_deporte)) else {
+                return panic!("El ID de deporte no es valido!");
+            };
+            if horarios.is_empty() || (cupo == 0) {
+                return panic!("La clase debe tener al menos un horario y un cupo mayor a 0!");
+            }
+            let horarios = horarios.into_iter().map(|(id_dia, inicio, fin)| HorarioClase::new(id_dia, inicio, fin)).collect();
+            let id = self.clases.len() as u32 + 1;
+            self.clases.push(Clase {id, deporte, profesor, horarios, cupo, inscriptos: Vec::new(), espera: Vec::new()});
+            self.registrar_auditoria(AccionAdministrativa::CreacionClase, None, Some(ValorAuditado::Cantidad(id as u64)));
+            return id;
+        }
+
+        ///El método actualizar_cupo_clase permite al Owner o a un Staff modificar el cupo de la clase con el numero
+        ///ingresado. Si el cupo aumenta, pasan a inscriptos los primeros de la lista de espera; si disminuye, los ya
+        ///inscriptos conservan su lugar. Arrojará un panic si no se cuenta con los permisos, si la clase no existe o si el
+        ///cupo es 0. Retorna true.
+        #[ink(message)]
+        pub fn actualizar_cupo_clase (&mut self, id_clase: u32, cupo: u32) -> bool {
+            return self.actualizar_cupo_clase_priv(id_clase, cupo);
+        }
+
+        fn actualizar_cupo_clase_priv (&mut self, id_clase: u32, cupo: u32) -> bool {
+            self.verificar_no_pausado();
+            if !(self.es_admin() || self.es_staff()) {
+                return panic!("No se cuenta con los permisos necesarios!");
+            }
+            if cupo == 0 {
+                return panic!("La clase debe tener al menos un horario y un cupo mayor a 0!");
+            }
+            let Some (clase) = self.clases.iter_mut().find(|clase| clase.id == id_clase) else {
+                return panic!("No se ha encontrado una clase con el numero ingresado!");
+            };
+            clase.cupo = cupo;
+            clase.promover_espera();
+            return true;
+        }
+
+        ///El método inscribir_en_clase inscribe al socio con el dni ingresado en la clase con el numero ingresado, o lo
+        ///agrega al final de la lista de espera si la clase está llena. Puede hacerlo quien tenga los permisos necesarios
+        ///o la cuenta titular del carnet del socio. Solo pueden inscribirse los socios cuya categoría incluye el deporte de
+        ///la clase (la A todos, la B solo el elegido y la C ninguno), que no tengan pagos ni cuotas de planes vencidos y
+        ///cuyo apto médico esté vigente. Arrojará un panic si no se cumple alguna de esas condiciones, si la clase o el
+        ///socio no existen o si el socio ya está inscripto o esperando. Retorna el estado de la inscripción.
+        #[ink(message)]
+        pub fn inscribir_en_clase (&mut self, id_clase: u32, dni: u32) -> EstadoInscripcion {
+            return self.inscribir_en_clase_priv(id_clase, dni);
+        }
+
+        fn inscribir_en_clase_priv (&mut self, id_clase: u32, dni: u32) -> EstadoInscripcion {
+            self.verificar_no_pausado();
+            if !(self.verificar_permisos() || self.es_titular_carnet(dni)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let Some (socio) = self.socios.iter().find(|socio| socio.dni == dni) else {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            };
+            let Some (pos_clase) = self.clases.iter().position(|clase| clase.id == id_clase) else {
+                return panic!("No se ha encontrado una clase con el numero ingresado!");
+            };
+            if !socio.incluye_deporte(&self.clases[pos_clase].deporte) {
+                return panic!("La categoria del socio no incluye el deporte de la clase!");
+            }
+            if self.tiene_deuda_vencida(dni) {
+                return panic!("El socio tiene pagos vencidos!");
+            }
+            if !self.apto_para_deporte(dni) {
+                return panic!("El socio no tiene un apto medico vigente!");
+            }
+            let clase = &mut self.clases[pos_clase];
+            if clase.inscriptos.contains(&dni) || clase.espera.contains(&dni) {
+                return panic!("El socio ya se encuentra inscripto o en la lista de espera de la clase!");
+            }
+            if clase.inscriptos.len() < clase.cupo as usize {
+                clase.inscriptos.push(dni);
+                return EstadoInscripcion::Inscripto;
+            }
+            clase.espera.push(dni);
+            return EstadoInscripcion::EnEspera(clase.espera.len() as u32);
+        }
+
+        ///El método abandonar_clase da de baja al socio con el dni ingresado de la clase con el numero ingresado, ya sea
+        ///que estuviera inscripto o en la lista de espera. Si deja un lugar libre, lo ocupa automáticamente el primero de
+        ///la lista de espera. Puede hacerlo quien tenga los permisos necesarios o la cuenta titular del carnet del socio.
+        ///Arrojará un panic si no se cuenta con los permisos, si la clase no existe o si el socio no estaba en ella.
+        ///Retorna true.
+        #[ink(message)]
+        pub fn abandonar_clase (&mut self, id_clase: u32, dni: u32) -> bool {
+            return self.abandonar_clase_priv(id_clase, dni);
+        }
+
+        fn abandonar_clase_priv (&mut self, id_clase: u32, dni: u32) -> bool {
+            self.verificar_no_pausado();
+            if !(self.verificar_permisos() || self.es_titular_carnet(dni)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let Some (clase) = self.clases.iter_mut().find(|clase| clase.id == id_clase) else {
+                return panic!("No se ha encontrado una clase con el numero ingresado!");
+            };
+            let cantidad = clase.inscriptos.len() + clase.espera.len();
+            clase.inscriptos.retain(|inscripto| *inscripto != dni);
+            clase.espera.retain(|esperando| *esperando != dni);
+            if cantidad == clase.inscriptos.len() + clase.espera.len() {
+                return panic!("El socio no se encuentra inscripto ni en la lista de espera de la clase!");
+            }
+            clase.promover_espera();
+            return true;
+        }
+
+        ///El método get_clases devuelve las clases del deporte con el id ingresado, o todas si se ingresa None, con sus
+        ///inscriptos y listas de espera. Sin los permisos necesarios arroja un panic.
+        #[ink(message)]
+        pub fn get_clases (&self, id_deporte: Option<u32>) -> Vec<Clase> {
+            return self.get_clases_priv(id_deporte);
+        }
+
+        fn get_clases_priv (&self, id_deporte: Option<u32>) -> Vec<Clase> {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let deporte = Deporte::deporte_from_id(&id_deporte);
+            return self.clases.iter().filter(|clase| deporte.is_none() || (deporte.as_ref() == Some(&clase.deporte))).cloned().collect();
+        }
+
+        ///El método registrar_profesor permite al Owner registrar como profesor a la cuenta ingresada, con su nombre y los
+        ///ids de los deportes que enseña. Si la cuenta fue profesor y se la había quitado, la reactiva con los datos
+        ///nuevos. Un profesor no es Staff: solo puede ver los inscriptos y registrar la asistencia de sus propias clases.
+        ///Arrojará un panic si no se es Owner, si la cuenta ya es un profesor activo, si no se ingresa ningún deporte o si
+        ///alguno no es valido.
+        ///Retorna true.
+        #[ink(message)]
+        pub fn registrar_profesor (&mut self, cuenta: AccountId, nombre: String, ids_deportes: Vec<u32>) -> bool {
+            return self.registrar_profesor_priv(cuenta, nombre, ids_deportes);
+        }
+
+        fn registrar_profesor_priv (&mut self, cuenta: AccountId, nombre: String, ids_deportes: Vec<u32>) -> bool {
+            self.verificar_no_pausado();
+            if !self.es_admin() || self.profesores.iter().any(|profesor| (profesor.cuenta == cuenta) && profesor.activo) {
+                return panic!("No cuenta con rango Owner o la cuenta ingresada ya es un profesor activo!");
+            }
+            if ids_deportes.is_empty() {
+                return panic!("El profesor debe enseñar al menos un deporte!");
+            }
+            let mut deportes: Vec<Deporte> = Vec::new();
+            for id in ids_deportes {
+                let Some (deporte) = Deporte::deporte_from_id(&Some(id)) else {
+                    return panic!("El ID de deporte no es valido!");
+                };
+                if !deportes.contains(&deporte) {
+                    deportes.push(deporte);
+                }
+            }
+            self.profesores.retain(|profesor| profesor.cuenta != cuenta);
+            self.profesores.push(Profesor {cuenta, nombre, deportes, activo: true});
+            self.registrar_auditoria(AccionAdministrativa::AgregarProfesor, None, Some(ValorAuditado::Cuenta(cuenta)));
+            return true;
+        }
+
+        ///El método quitar_profesor permite al Owner dar de baja al profesor con la cuenta ingresada, que pierde el acceso
+        ///a sus clases pero se conserva inactivo en el registro. Arrojará un panic si no se es Owner o si la cuenta no es
+        ///un profesor activo. Retorna true.
+        #[ink(message)]
+        pub fn quitar_profesor (&mut self, cuenta: AccountId) -> bool {
+            return self.quitar_profesor_priv(cuenta);
+        }
+
+        fn quitar_profesor_priv (&mut self, cuenta: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                if let Some (profesor) = self.profesores.iter_mut().find(|profesor| (profesor.cuenta == cuenta) && profesor.activo) {
+                    profesor.activo = false;
+                    self.registrar_auditoria(AccionAdministrativa::QuitarProfesor, Some(ValorAuditado::Cuenta(cuenta)), None);
+                    return true;
+                }
+            }
+            return panic!("No se cuenta con rango Owner o la cuenta ingresada no es un profesor activo!");
+        }
+
+        ///El método get_profesores devuelve todos los profesores registrados, activos o no. Sin los permisos necesarios
+        ///arroja un panic.
+        #[ink(message)]
+        pub fn get_profesores (&self) -> Vec<Profesor> {
+            return self.get_profesores_priv();
+        }
+
+        fn get_profesores_priv (&self) -> Vec<Profesor> {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            return self.profesores.clone();
+        }
+
+        ///El método get_clase devuelve la clase con el numero ingresado, con sus inscriptos y su lista de espera. Además de
+        ///quien tenga los permisos necesarios o un lector, puede consultarla el profesor activo que la dicta. Arrojará un
+        ///panic si la clase no existe o si no se cuenta con los permisos.
+        #[ink(message)]
+        pub fn get_clase (&self, id_clase: u32) -> Clase {
+            return self.get_clase_priv(id_clase);
+        }
+
+        fn get_clase_priv (&self, id_clase: u32) -> Clase {
+            let Some (clase) = self.clases.iter().find(|clase| clase.id == id_clase) else {
+                return panic!("No se ha encontrado una clase con el numero ingresado!");
+            };
+            if !(self.verificar_permisos() || self.es_lector() || self.es_profesor_de(clase)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            return clase.clone();
+        }
+
+        ///El método get_mis_clases devuelve las clases que dicta el profesor que llama. Arrojará un panic si quien llama no
+        ///es un profesor activo.
+        #[ink(message)]
+        pub fn get_mis_clases (&self) -> Vec<Clase> {
+            return self.get_mis_clases_priv();
+        }
+
+        fn get_mis_clases_priv (&self) -> Vec<Clase> {
+            if !self.profesores.iter().any(|profesor| (profesor.cuenta == self.env().caller()) && profesor.activo) {
+                return panic!("La cuenta no es un profesor activo!");
+            }
+            return self.clases.iter().filter(|clase| self.es_profesor_de(clase)).cloned().collect();
+        }
+
+        ///El método registrar_asistencia_clase registra que el socio con el dni ingresado asistió en la fecha ingresada a la
+        ///clase con el numero ingresado. Puede hacerlo quien tenga los permisos necesarios o el profesor activo que dicta
+        ///la clase. Arrojará un panic si la clase no existe, si no se cuenta con los permisos, si el socio no está
+        ///inscripto en la clase o si la fecha es posterior a la actual. Retorna true.
+        #[ink(message)]
+        pub fn registrar_asistencia_clase (&mut self, id_clase: u32, dni: u32, fecha: u64) -> bool {
+            return self.registrar_asistencia_clase_priv(id_clase, dni, fecha);
+        }
+
+        fn registrar_asistencia_clase_priv (&mut self, id_clase: u32, dni: u32, fecha: u64) -> bool {
+            self.verificar_no_pausado();
+            let Some (clase) = self.clases.iter().find(|clase| clase.id == id_clase) else {
+                return panic!("No se ha encontrado una clase con el numero ingresado!");
+            };
+            if !(self.verificar_permisos() || self.es_profesor_de(clase)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !clase.inscriptos.contains(&dni) {
+                return panic!("El socio no se encuentra inscripto en la clase!");
+            }
+            if fecha > self.env().block_timestamp() {
+                return panic!("La fecha de la asistencia no puede ser posterior a la actual!");
+            }
+            let asistencia = Asistencia {dni, deporte: clase.deporte.clone(), fecha, clase: Some(id_clase), registrado_por: self.env().caller()};
+            self.asistencias.push(asistencia);
+            return true;
+        }
+
+        ///El método agregar_dispositivo_acceso permite al Owner habilitar la cuenta de un dispositivo de control de acceso
+        ///(un molinete o lector de carnets), que solo puede registrar ingresos de socios. Arrojará un panic si no se es
+        ///Owner o si la cuenta ya es un dispositivo. Retorna true.
+        #[ink(message)]
+        pub fn agregar_dispositivo_acceso (&mut self, dispositivo: AccountId) -> bool {
+            return self.agregar_dispositivo_acceso_priv(dispositivo);
+        }
+
+        fn agregar_dispositivo_acceso_priv (&mut self, dispositivo: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) && (!self.dispositivos_acceso.contains(&dispositivo)) {
+                self.dispositivos_acceso.push(dispositivo);
+                self.registrar_auditoria(AccionAdministrativa::AgregarDispositivo, None, Some(ValorAuditado::Cuenta(dispositivo)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner o la cuenta ingresada ya es un dispositivo de acceso!");
+        }
+
+        ///El método quitar_dispositivo_acceso permite al Owner deshabilitar la cuenta de un dispositivo de control de
+        ///acceso. Arrojará un panic si no se es Owner o si la cuenta no es un dispositivo. Retorna true.
+        #[ink(message)]
+        pub fn quitar_dispositivo_acceso (&mut self, dispositivo: AccountId) -> bool {
+            return self.quitar_dispositivo_acceso_priv(dispositivo);
+        }
+
+        fn quitar_dispositivo_acceso_priv (&mut self, dispositivo: AccountId) -> bool {
+            self.verificar_no_pausado();
+            if (self.es_admin()) && (self.dispositivos_acceso.contains(&dispositivo)) {
+                self.dispositivos_acceso.retain(|cuenta| cuenta != &dispositivo);
+                self.registrar_auditoria(AccionAdministrativa::QuitarDispositivo, Some(ValorAuditado::Cuenta(dispositivo)), None);
+                return true;
+            }
+            return panic!("No se cuenta con rango Owner o la cuenta ingresada no es un dispositivo de acceso!");
+        }
+
+        ///El método registrar_ingreso registra que el socio con el dni ingresado usó en la fecha ingresada las
+        ///instalaciones o asistió al entrenamiento del deporte con el id ingresado. Puede llamarlo quien tenga los permisos
+        ///necesarios, un dispositivo de control de acceso, o un profesor activo si el socio está inscripto en una de sus
+        ///clases de ese deporte. Arrojará un panic si no se cuenta con los permisos, si el socio no existe, si su categoría
+        ///no incluye el deporte, si está suspendido o tiene pagos vencidos, si su apto médico no está vigente o si la fecha
+        ///es posterior a la actual. Retorna true.
+        #[ink(message)]
+        pub fn registrar_ingreso (&mut self, dni: u32, id_deporte: u32, fecha: u64) -> bool {
+            return self.registrar_ingreso_priv(dni, id_deporte, fecha);
+        }
+
+        fn registrar_ingreso_priv (&mut self, dni: u32, id_deporte: u32, fecha: u64) -> bool {
+            self.verificar_no_pausado();
+            let Some (deporte) = Deporte::deporte_from_id(&Some(id_deporte)) else {
+                return panic!("El ID de deporte no es valido!");
+            };
+            let profesor = self.clases.iter().any(|clase| (clase.deporte == deporte) && clase.inscriptos.contains(&dni) && self.es_profesor_de(clase));
+            if !(self.verificar_permisos() || self.dispositivos_acceso.contains(&self.env().caller()) || profesor) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let Some (socio) = self.socios.iter().find(|socio| socio.dni == dni) else {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            };
+            if !socio.incluye_deporte(&deporte) {
+                return panic!("La categoria del socio no incluye el deporte ingresado!");
+            }
+            if self.estado_socio(dni) == EstadoSocio::Suspendido {
+                return panic!("El socio se encuentra suspendido!");
+            }
+            if self.tiene_deuda_vencida(dni) {
+                return panic!("El socio tiene pagos vencidos!");
+            }
+            if !self.apto_para_deporte(dni) {
+                return panic!("El socio no tiene un apto medico vigente!");
+            }
+            if fecha > self.env().block_timestamp() {
+                return panic!("La fecha de la asistencia no puede ser posterior a la actual!");
+            }
+            self.asistencias.push(Asistencia {dni, deporte, fecha, clase: None, registrado_por: self.env().caller()});
+            return true;
+        }
+
+        ///El método get_asistencias devuelve las asistencias del socio con el dni ingresado, a clases o de ingreso a las
+        ///instalaciones, con fecha entre desde y hasta inclusive, ordenadas por fecha. Puede consultarlas quien tenga los
+        ///permisos necesarios, un lector o la cuenta titular del carnet del socio. Arrojará un panic si no se cuenta con
+        ///los permisos o si desde es posterior a hasta.
+        #[ink(message)]
+        pub fn get_asistencias (&self, dni: u32, desde: u64, hasta: u64) -> Vec<Asistencia> {
+            return self.get_asistencias_priv(dni, desde, hasta);
+        }
+
+        fn get_asistencias_priv (&self, dni: u32, desde: u64, hasta: u64) -> Vec<Asistencia> {
+            if !(self.verificar_permisos() || self.es_lector() || self.es_titular_carnet(dni)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if desde > hasta {
+                return panic!("La fecha desde no puede ser posterior a la fecha hasta!");
+            }
+            let mut asistencias: Vec<Asistencia> = self.asistencias.iter().filter(|asistencia| (asistencia.dni == dni) && (desde..=hasta).contains(&asistencia.fecha)).cloned().collect();
+            asistencias.sort_by_key(|asistencia| asistencia.fecha);
+            return asistencias;
+        }
+
+        ///El método get_asistencias_mensuales devuelve, para el mes calendario (UTC) ingresado, cuantas asistencias tuvo
+        ///cada socio en cada deporte, ordenadas por dni y deporte. Si se ingresa un dni, solo devuelve las de ese socio.
+        ///Sin los permisos necesarios, o si el mes no está entre 1 y 12, arroja un panic.
+        #[ink(message)]
+        pub fn get_asistencias_mensuales (&self, anio: u32, mes: u8, dni: Option<u32>) -> Vec<AsistenciasMensuales> {
+            return self.get_asistencias_mensuales_priv(anio, mes, dni);
+        }
+
+        fn get_asistencias_mensuales_priv (&self, anio: u32, mes: u8, dni: Option<u32>) -> Vec<AsistenciasMensuales> {
+            if !(self.verificar_permisos() || self.es_lector()) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            if !(1..=12).contains(&mes) {
+                return panic!("El mes ingresado no es valido!");
+            }
+            let mut conteos: Vec<AsistenciasMensuales> = Vec::new();
+            for asistencia in self.asistencias.iter().filter(|asistencia| dni.is_none_or(|dni| asistencia.dni == dni) && (nucleo_facturacion::anio_y_mes(asistencia.fecha) == (anio, mes))) {
+                match conteos.iter_mut().find(|conteo| (conteo.dni == asistencia.dni) && (conteo.deporte == asistencia.deporte)) {
+                    Some (conteo) => conteo.cantidad += 1,
+                    None => conteos.push(AsistenciasMensuales {dni: asistencia.dni, deporte: asistencia.deporte.clone(), cantidad: 1}),
+                }
+            }
+            conteos.sort_by_key(|conteo| (conteo.dni, conteo.deporte.clone() as u8));
+            return conteos;
+        }
+
+        ///Retorna true si quien llama es el profesor de la clase recibida, está activo y enseña el deporte de la clase.
+        fn es_profesor_de (&self, clase: &Clase) -> bool {
+            let caller = self.env().caller();
+            return (clase.profesor == caller) && self.profesores.iter().any(|profesor| (profesor.cuenta == caller) && profesor.activo && profesor.deportes.contains(&clase.deporte));
+        }
+
+        ///El método crear_instalacion permite al Owner o a un Staff crear una instalación reservable, con su nombre, el id
+        ///del deporte que se practica en ella, su hora UTC de apertura y de cierre, y el arancel por hora. Arrojará un panic
+        ///si no se cuenta con los permisos, si el deporte no es valido o si el horario no abre antes de cerrar dentro del
+        ///dia. Retorna el numero de la instalación creada.
+        #[ink(message)]
+        pub fn crear_instalacion (&mut self, nombre: String, id_deporte: u32, apertura: u32, cierre: u32, tarifa_hora: u128) -> u32 {
+            return self.crear_instalacion_priv(nombre, id_deporte, apertura, cierre, tarifa_hora);
+        }
+
+        fn crear_instalacion_priv (&mut self, nombre: String, id_deporte: u32, apertura: u32, cierre: u32, tarifa_hora: u128) -> u32 {
+            self.verificar_no_pausado();
+            if !(self.es_admin() || self.es_staff()) {
+                return panic!("No se cuenta con los permisos necesarios!");
+            }
+            let Some (deporte) = Deporte::deporte_from_id(&Some(id_deporte)) else {
+                return panic!("El ID de deporte no es valido!");
+            };
+            if (apertura >= cierre) || (cierre > 24) {
+                return panic!("El horario de la instalacion no es valido!");
+            }
+            let id = self.instalaciones.len() as u32 + 1;
+            self.instalaciones.push(Instalacion {id, nombre, deporte, apertura, cierre, tarifa_hora});
+            self.registrar_auditoria(AccionAdministrativa::CreacionInstalacion, None, Some(ValorAuditado::Cantidad(id as u64)));
+            return id;
+        }
+
+        ///El método get_instalaciones devuelve todas las instalaciones reservables del club. No requiere permisos.
+        #[ink(message)]
+        pub fn get_instalaciones (&self) -> Vec<Instalacion> {
+            return self.instalaciones.clone();
+        }
+
+        ///Este método permite al Owner modificar con cuantas horas de anticipación al inicio del turno, como mínimo, puede
+        ///cancelarse una reserva. De no ser Owner, arrojará un panic. Retorna true.
+        #[ink(message)]
+        pub fn actualizar_plazo_cancelacion (&mut self, horas: u64) -> bool {
+            return self.actualizar_plazo_cancelacion_priv(horas);
+        }
+
+        fn actualizar_plazo_cancelacion_priv (&mut self, horas: u64) -> bool {
+            self.verificar_no_pausado();
+            if self.es_admin() {
+                let anterior = self.plazo_cancelacion;
+                self.plazo_cancelacion = verificar_aritmetica(horas.checked_mul(1.from_horas()).ok_or(ErrorAritmetico::Desbordamiento));
+                self.registrar_auditoria(AccionAdministrativa::CambioPlazoCancelacion, Some(ValorAuditado::Cantidad(anterior)), Some(ValorAuditado::Cantidad(self.plazo_cancelacion)));
+                return true;
+            }
+            return panic!("No cuenta con rango Owner para realizar esta operacion!");
+        }
+
+        ///El método reservar_instalacion reserva para el socio con el dni ingresado la instalación con el numero ingresado,
+        ///durante la cantidad de horas ingresada a partir de inicio, que debe ser una hora en punto. Puede hacerlo quien
+        ///tenga los permisos necesarios o la cuenta titular del carnet del socio. El arancel (tarifa por hora por cantidad
+        ///de horas) se suma a la próxima cuota del socio o, si cobro_inmediato es true, se cobra en el momento y se emite su
+        ///recibo; el cobro inmediato solo puede registrarlo quien tenga los permisos necesarios. Arrojará un panic si no se
+        ///cuenta con los permisos, si la instalación o el socio no existen, si la categoría del socio no incluye el
+        ///deporte, si tiene pagos vencidos, si su apto médico no está vigente, si el turno no está en el futuro dentro del
+        ///horario de la instalación o si se superpone con otra reserva. Retorna la reserva creada.
+        #[ink(message)]
+        pub fn reservar_instalacion (&mut self, id_instalacion: u32, dni: u32, inicio: u64, horas: u32, cobro_inmediato: bool) -> Reserva {
+            return self.reservar_instalacion_priv(id_instalacion, dni, inicio, horas, cobro_inmediato);
+        }
+
+        fn reservar_instalacion_priv (&mut self, id_instalacion: u32, dni: u32, inicio: u64, horas: u32, cobro_inmediato: bool) -> Reserva {
+            self.verificar_no_pausado();
+            let permisos = self.verificar_permisos();
+            if !(permisos || (self.es_titular_carnet(dni) && !cobro_inmediato)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let Some (instalacion) = self.instalaciones.iter().find(|instalacion| instalacion.id == id_instalacion).cloned() else {
+                return panic!("No se ha encontrado una instalacion con el numero ingresado!");
+            };
+            let Some (socio) = self.socios.iter().find(|socio| socio.dni == dni) else {
+                return panic!("No se ha encontrado un socio con el DNI provisto!");
+            };
+            if !socio.incluye_deporte(&instalacion.deporte) {
+                return panic!("La categoria del socio no incluye el deporte ingresado!");
+            }
+            if self.tiene_deuda_vencida(dni) {
+                return panic!("El socio tiene pagos vencidos!");
+            }
+            if !self.apto_para_deporte(dni) {
+                return panic!("El socio no tiene un apto medico vigente!");
+            }
+            let hora = 1.from_horas();
+            let hora_del_dia = ((inicio / hora) % 24) as u32;
+            let en_horario = (horas > 0) && (hora_del_dia >= instalacion.apertura) && (hora_del_dia.saturating_add(horas) <= instalacion.cierre);
+            if !inicio.is_multiple_of(hora) || !en_horario || (inicio <= self.env().block_timestamp()) {
+                return panic!("El turno debe ser futuro, empezar en una hora en punto y estar dentro del horario de la instalacion!");
+            }
+            let fin = verificar_aritmetica(nucleo_facturacion::Timestamp(inicio).sumar(hora * horas as u64)).valor();
+            if self.reservas.iter().any(|reserva| (reserva.instalacion == id_instalacion) && !reserva.cancelada && (reserva.inicio < fin) && (inicio < reserva.fin)) {
+                return panic!("El turno se superpone con otra reserva de la instalacion!");
+            }
+            let monto = verificar_aritmetica(Monto(instalacion.tarifa_hora).multiplicar(horas as u128)).valor();
+            let id = self.reservas.len() as u32 + 1;
+            let cobro = if cobro_inmediato { CobroReserva::Inmediato } else { CobroReserva::ProximaCuota };
+            let mut reserva = Reserva {id, instalacion: id_instalacion, dni, inicio, fin, monto, cobro, cobrada: false, cancelada: false};
+            if cobro_inmediato {
+                self.registrar_asiento(TipoAsiento::Cargo, dni, monto, Some(id));
+                self.emitir_recibo(&Pago::new(dni, monto, inicio, Some(self.env().block_timestamp()), false), OrigenRecibo::Reserva(id));
+                reserva.cobrada = true;
+            }
+            self.reservas.push(reserva.clone());
+            return reserva;
+        }
+
+        ///El método cancelar_reserva cancela la reserva con el numero ingresado, liberando el turno. Puede hacerlo quien
+        ///tenga los permisos necesarios o la cuenta titular del carnet del socio que reservó, hasta el plazo de cancelación
+        ///antes del inicio del turno. Si el arancel todavía no se había sumado a una cuota, ya no se cobra; el arancel ya
+        ///cobrado, en el momento o en una cuota, se descuenta del próximo pago que se le emita al socio. Arrojará un panic si no se cuenta con los permisos, si la reserva no existe o ya estaba
+        ///cancelada, o si venció el plazo de cancelación. Retorna true.
+        #[ink(message)]
+        pub fn cancelar_reserva (&mut self, id_reserva: u32) -> bool {
+            return self.cancelar_reserva_priv(id_reserva);
+        }
+
+        fn cancelar_reserva_priv (&mut self, id_reserva: u32) -> bool {
+            self.verificar_no_pausado();
+            let Some (pos) = self.reservas.iter().position(|reserva| reserva.id == id_reserva) else {
+                return panic!("No se ha encontrado una reserva con el numero ingresado!");
+            };
+            if !(self.verificar_permisos() || self.es_titular_carnet(self.reservas[pos].dni)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let ahora = self.env().block_timestamp();
+            let reserva = &mut self.reservas[pos];
+            if reserva.cancelada {
+                return panic!("La reserva ya fue cancelada!");
+            }
+            if nucleo_facturacion::Timestamp(ahora).sumar_saturado(self.plazo_cancelacion).valor() > reserva.inicio {
+                return panic!("Vencio el plazo para cancelar la reserva!");
+            }
+            reserva.cancelada = true;
+            if reserva.cobrada {
+                let (dni, monto) = (reserva.dni, reserva.monto);
+                self.acreditar_reintegro(dni, monto);
+            }
+            return true;
+        }
+
+        ///El método get_reservas_socio devuelve todas las reservas del socio con el dni ingresado, incluidas las
+        ///canceladas, ordenadas por inicio del turno. Puede consultarlas quien tenga los permisos necesarios, un lector o la
+        ///cuenta titular del carnet del socio. De lo contrario, arroja un panic.
+        #[ink(message)]
+        pub fn get_reservas_socio (&self, dni: u32) -> Vec<Reserva> {
+            return self.get_reservas_socio_priv(dni);
+        }
+
+        fn get_reservas_socio_priv (&self, dni: u32) -> Vec<Reserva> {
+            if !(self.verificar_permisos() || self.es_lector() || self.es_titular_carnet(dni)) {
+                return panic!("No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!");
+            }
+            let mut reservas: Vec<Reserva> = self.reservas.iter().filter(|reserva| reserva.dni == dni).cloned().collect();
+            reservas.sort_by_key(|reserva| reserva.inicio);
+            return reservas;
+        }
+
+        ///El método get_turnos_ocupados devuelve el inicio y el fin de los turnos reservados, y no cancelados, de la
+        ///instalación con el numero ingresado que se superponen con el intervalo entre desde y hasta, ordenados por inicio,
+        ///para consultar la disponibilidad sin exponer quien reservó. No requiere permisos.
+        #[ink(message)]
+        pub fn get_turnos_ocupados (&self, id_instalacion: u32, desde: u64, hasta: u64) -> Vec<(u64, u64)> {
+            return self.get_turnos_ocupados_priv(id_instalacion, desde, hasta);
+        }
+
+        fn get_turnos_ocupados_priv (&self, id_instalacion: u32, desde: u64, hasta: u64) -> Vec<(u64, u64)> {
+            let mut turnos: Vec<(u64, u64)> = self.reservas.iter()
+                .filter(|reserva| (reserva.instalacion == id_instalacion) && !reserva.cancelada && (reserva.inicio < hasta) && (desde < reserva.fin))
+                .map(|reserva| (reserva.inicio, reserva.fin)).collect();
+            turnos.sort();
+            return turnos;
+        }
+
+        ///Marca como cobradas las reservas del socio cuyo arancel se suma a su próxima cuota, registra el cargo de cada una
+        ///en su cuenta corriente y retorna la suma de sus aranceles.
+        fn cobrar_reservas (&mut self, dni: u32) -> u128 {
+            let mut total = Monto(0);
+            let mut cargos: Vec<(u128, u32)> = Vec::new();
+            for reserva in self.reservas.iter_mut().filter(|reserva| (reserva.dni == dni) && (reserva.cobro == CobroReserva::ProximaCuota) && !reserva.cobrada && !reserva.cancelada) {
+                reserva.cobrada = true;
+                total = verificar_aritmetica(total.sumar(Monto(reserva.monto)));
+                cargos.push((reserva.monto, reserva.id));
+            }
+            for (monto, id) in cargos {
+                self.registrar_asiento(TipoAsiento::Cargo, dni, monto, Some(id));
+            }
+            return total.valor();
+        }
+
+        ///Suma el monto recibido al reintegro pendiente del socio, que se descontará del próximo pago que se le emita.
+        fn acreditar_reintegro (&mut self, dni: u32, monto: u128) {
+            if monto == 0 {
+                return;
+            }
+            let pendiente = self.reintegros_pendientes.iter().find(|(dni_reintegro, _)| *dni_reintegro == dni).map(|(_, reintegro)| *reintegro).unwrap_or(0);
+            self.reintegros_pendientes.retain(|(dni_reintegro, _)| *dni_reintegro != dni);
+            self.reintegros_pendientes.push((dni, verificar_aritmetica(Monto(pendiente).sumar(Monto(monto))).valor()));
+        }
+
+        ///Descuenta del monto de un pago a emitir el reintegro pendiente del socio, hasta cubrir el monto, registra el
+        ///reintegro aplicado en su cuenta corriente y retorna el monto resultante.
+        fn aplicar_reintegro (&mut self, dni: u32, monto: u128) -> u128 {
+            let Some (pos) = self.reintegros_pendientes.iter().position(|(dni_reintegro, _)| *dni_reintegro == dni) else {
+                return monto;
+            };
+            let reintegro = self.reintegros_pendientes.remove(pos).1.min(monto);
+            self.registrar_asiento(TipoAsiento::Reintegro, dni, reintegro, None);
+            return monto - reintegro;
+        }
+
+        fn instantanea (&self) -> Instantanea {
+            return Instantanea {
+                version: VERSION_INSTANTANEA,
+                costos: self.costos.clone(),
+                socios: self.socios.clone(),
+                pagos: self.pagos.clone(),
+                owner: self.owner,
+                permitidos: self.permitidos.clone(),
+                politica_activada: self.politica_activada,
+                emision_ultimo_pago: self.emision_ultimo_pago,
+                proteccion_datos: self.proteccion_datos,
+                recibos: self.recibos.clone(),
+                ultimo_nro_recibo: self.ultimo_nro_recibo,
+                movimientos: self.movimientos.clone(),
+                ventana_reversion: self.ventana_reversion,
+                retencion_auditoria: self.retencion_auditoria,
+                guardianes: self.guardianes.clone(),
+                lectores: self.lectores.clone(),
+                token_pago: self.token_pago,
+                puntos_categoria: self.puntos_categoria.clone(),
+                vigencia_puntos: self.vigencia_puntos,
+                valor_punto: self.valor_punto,
+                lotes_puntos: self.lotes_puntos.clone(),
+                movimientos_puntos: self.movimientos_puntos.clone(),
+                canjes_pendientes: self.canjes_pendientes.clone(),
+                grupos_familiares: self.grupos_familiares.clone(),
+                carnets: self.carnets.clone(),
+                planes_cuotas: self.planes_cuotas.clone(),
+                descuentos_plan: self.descuentos_plan.clone(),
+                facturacion_socios: self.facturacion_socios.clone(),
+                politica_morosidad: self.politica_morosidad.clone(),
+                asientos: self.asientos.clone(),
+                certificados: self.certificados.clone(),
+                vigencia_certificados: self.vigencia_certificados,
+                aptos_medicos: self.aptos_medicos.clone(),
+                clases: self.clases.clone(),
+                profesores: self.profesores.clone(),
+                asistencias: self.asistencias.clone(),
+                dispositivos_acceso: self.dispositivos_acceso.clone(),
+                instalaciones: self.instalaciones.clone(),
+                reservas: self.reservas.clone(),
+                plazo_cancelacion: self.plazo_cancelacion,
+                reintegros_pendientes: self.reintegros_pendientes.clone(),
+            };
+        }
+
+        ///El método get_metadatos_instantanea codifica el estado actual del club como una Instantanea y devuelve sus
+        ///metadatos para un tamaño de chunk dado (tamanio_chunk, en bytes): cantidad total de bytes y de chunks, hash y
+        ///cantidades de socios, pagos y recibos. Solo el Owner puede llamarlo, y arrojará un panic si el tamaño es 0.
+        #[ink(message)]
+        pub fn get_metadatos_instantanea (&self, tamanio_chunk: u32) -> MetadatosInstantanea {
+            return self.get_metadatos_instantanea_priv(tamanio_chunk);
+        }
+
+        fn get_metadatos_instantanea_priv (&self, tamanio_chunk: u32) -> MetadatosInstantanea {
+            if !self.es_admin() {
+                return panic!("No cuenta con rango Owner para realizar esta operacion!");
+            }
+            if tamanio_chunk == 0 {
+                return panic!("El tamaño de chunk debe ser mayor a 0!");
+            }
+            let bytes = self.instantanea().encode();
+            let total_bytes = bytes.len() as u32;
+            return MetadatosInstantanea {
+                version: VERSION_INSTANTANEA,
+                total_bytes,
+                tamanio_chunk,
+                cantidad_chunks: total_bytes.div_ceil(tamanio_chunk),
+                hash: hash_blake2x256(&bytes),
+                cantidad_socios: self.socios.len() as u32,
+                cantidad_pagos: self.pagos.len() as u32,
+                cantidad_recibos: self.recibos.len() as u32,
+            };
+        }
+
+        ///El método exportar_instantanea devuelve el chunk número indice (comenzando en 0) de la Instantanea del club
+        ///codificada en SCALE, partida en chunks de tamanio_chunk bytes. Concatenando todos los chunks se obtiene la
+        ///instantanea completa, cuya integridad se verifica con get_metadatos_instantanea. Solo el Owner puede llamarlo, y
+        ///arrojará un panic si el tamaño es 0 o el indice no existe.
+        #[ink(message)]
+        pub fn exportar_instantanea (&self, indice: u32, tamanio_chunk: u32) -> Vec<u8> {
+            return self.exportar_instantanea_priv(indice, tamanio_chunk);
+        }
+
+        fn exportar_instantanea_priv (&self, indice: u32, tamanio_chunk: u32) -> Vec<u8> {
+            if !self.es_admin() {
+                return panic!("No cuenta con rango Owner para realizar esta operacion!");
+            }
+            if tamanio_chunk == 0 {
+                return panic!("El tamaño de chunk debe ser mayor a 0!");
+            }
+            let bytes = self.instantanea().encode();
+            let desde = (indice as usize).saturating_mul(tamanio_chunk as usize);
+            if desde >= bytes.len() {
+                return panic!("El indice de chunk ingresado no existe!");
+            }
+            let hasta = bytes.len().min(desde + tamanio_chunk as usize);
+            return bytes[desde..hasta].to_vec();
+        }
+
+        ///El método iniciar_importacion comienza a reconstruir el estado del club a partir de una Instantanea exportada por
+        ///otro despliegue, recibiendo sus metadatos. Solo el Owner puede llamarlo, y solo sobre un club nuevo, sin socios
+        ///ni pagos. Arrojará un panic si no se cumplen esas condiciones o si la version de la instantanea no es la que
+        ///maneja este contrato. Si ya había una importación en curso, se descarta. Retorna true.
+        #[ink(message)]
+        pub fn iniciar_importacion (&mut self, metadatos: MetadatosInstantanea) -> bool {
+            return self.iniciar_importacion_priv(metadatos);
+        }
+
+        fn iniciar_importacion_priv (&mut self, metadatos: MetadatosInstantanea) -> bool {
+            self.verificar_no_pausado();
+            if !self.es_admin() {
+                return panic!("No cuenta con rango Owner para realizar esta operacion!");
+            }
+            if !self.socios.is_empty() || !self.pagos.is_empty() {
+                return panic!("Solo se puede importar una instantanea en un club sin socios ni pagos!");
+            }
+            if metadatos.version != VERSION_INSTANTANEA {
+                return panic!("La version de la instantanea no es compatible con este contrato!");
+            }
+            self.importacion = Some(ImportacionEnCurso {metadatos, bytes: Vec::new(), proximo_chunk: 0});
+            return true;
+        }
+
+        ///El método importar_chunk agrega a la importación en curso el chunk número indice, que debe ser el siguiente al
+        ///último recibido. Solo el Owner puede llamarlo. Arrojará un panic si no hay una importación en curso, si el chunk
+        ///no es el esperado o si se superan los bytes declarados en los metadatos. Retorna la cantidad de bytes recibidos.
+        #[ink(message)]
+        pub fn importar_chunk (&mut self, indice: u32, bytes: Vec<u8>) -> u32 {
+            return self.importar_chunk_priv(indice, bytes);
+        }
+
+        fn importar_chunk_priv (&mut self, indice: u32, bytes: Vec<u8>) -> u32 {
+            self.verificar_no_pausado();
+            if !self.es_admin() {
+                return panic!("No cuenta con rango Owner para realizar esta operacion!");
+            }
+            let Some (importacion) = self.importacion.as_mut() else {
+                return panic!("No hay una importacion en curso!");
+            };
+            if indice != importacion.proximo_chunk {
+                return panic!("El chunk ingresado no es el siguiente esperado!");
+            }
+            if importacion.bytes.len() + bytes.len() > importacion.metadatos.total_bytes as usize {
+                return panic!("Se superaron los bytes declarados en los metadatos de la instantanea!");
+            }
+            importacion.bytes.extend_from_slice(&bytes);
+            importacion.proximo_chunk += 1;
+            return importacion.bytes.len() as u32;
+        }
+
+        ///El método finalizar_importacion verifica la integridad de la instantanea recibida (cantidad de bytes, hash,
+        ///version y cantidades de socios, pagos y recibos contra los metadatos) y, si es correcta, reemplaza el estado del
+        ///club por el de la instantanea. El owner del club sigue siendo el actual, ya que la cuenta que importa puede no
+        ///ser la misma que figuraba en el despliegue original. Los carnets se importan con sus numeros y titulares, por lo
+        ///que no se emiten nuevos. Solo el Owner puede llamarlo, y arrojará un panic si alguna verificación falla, en cuyo
+        ///caso la importación se mantiene para poder reintentarla. Retorna true.
+        #[ink(message)]
+        pub fn finalizar_importacion (&mut self) -> bool {
+            return self.finalizar_importacion_priv();
+        }
+
+        fn finalizar_importacion_priv (&mut self) -> bool {
+            self.verificar_no_pausado();
+            if !self.es_admin() {
+                return panic!("No cuenta con rango Owner para realizar esta operacion!");
+            }
+            let Some (importacion) = self.importacion.as_ref() else {
+                return panic!("No hay una importacion en curso!");
+            };
+            let metadatos = &importacion.metadatos;
+            if (importacion.bytes.len() as u32 != metadatos.total_bytes) || (hash_blake2x256(&importacion.bytes) != metadatos.hash) {
+                return panic!("Los bytes recibidos no coinciden con los metadatos de la instantanea!");
+            }
+            let Ok (instantanea) = <Instantanea as scale::Decode>::decode(&mut &importacion.bytes[..]) else {
+                return panic!("No se pudo decodificar la instantanea!");
+            };
+            if (instantanea.version != metadatos.version) || (instantanea.socios.len() as u32 != metadatos.cantidad_socios) || (instantanea.pagos.len() as u32 != metadatos.cantidad_pagos) || (instantanea.recibos.len() as u32 != metadatos.cantidad_recibos) {
+                return panic!("El contenido de la instantanea no coincide con sus metadatos!");
+            }
+            self.costos = instantanea.costos;
+            self.socios = instantanea.socios;
+            self.pagos = instantanea.pagos;
+            self.permitidos = instantanea.permitidos;
+            self.politica_activada = instantanea.politica_activada;
+            self.emision_ultimo_pago = instantanea.emision_ultimo_pago;
+            self.proteccion_datos = instantanea.proteccion_datos;
+            self.recibos = instantanea.recibos;
+            self.ultimo_nro_recibo = instantanea.ultimo_nro_recibo;
+            self.movimientos = instantanea.movimientos;
+            self.ventana_reversion = instantanea.ventana_reversion;
+            self.retencion_auditoria = instantanea.retencion_auditoria;
+            self.guardianes = instantanea.guardianes;
+            self.lectores = instantanea.lectores;
+            self.token_pago = instantanea.token_pago;
+            self.puntos_categoria = instantanea.puntos_categoria;
+            self.vigencia_puntos = instantanea.vigencia_puntos;
+            self.valor_punto = instantanea.valor_punto;
+            self.lotes_puntos = instantanea.lotes_puntos;
+            self.movimientos_puntos = instantanea.movimientos_puntos;
+            self.canjes_pendientes = instantanea.canjes_pendientes;
+            self.grupos_familiares = instantanea.grupos_familiares;
+            self.carnets = instantanea.carnets;
+            self.planes_cuotas = instantanea.planes_cuotas;
+            self.descuentos_plan = instantanea.descuentos_plan;
+            self.facturacion_socios = instantanea.facturacion_socios;
+            self.politica_morosidad = instantanea.politica_morosidad;
+            self.asientos = instantanea.asientos;
+            self.certificados = instantanea.certificados;
+            self.vigencia_certificados = instantanea.vigencia_certificados;
+            self.aptos_medicos = instantanea.aptos_medicos;
+            self.clases = instantanea.clases;
+            self.profesores = instantanea.profesores;
+            self.asistencias = instantanea.asistencias;
+            self.dispositivos_acceso = instantanea.dispositivos_acceso;
+            self.instalaciones = instantanea.instalaciones;
+            self.reservas = instantanea.reservas;
+            self.plazo_cancelacion = instantanea.plazo_cancelacion;
+            self.reintegros_pendientes = instantanea.reintegros_pendientes;
+            self.importacion = None;
+            self.registrar_auditoria(AccionAdministrativa::ImportacionEstado, None, Some(ValorAuditado::Cantidad(self.socios.len() as u64)));
+            return true;
+        }
+
+            fn get_milisegundos_actuales(&self)->u64{
+                return self.env().block_timestamp();
+            }       
+    }
+
+    #[cfg(test)]
+    #[allow(unused_variables, unused_mut, clippy::bool_assert_comparison, clippy::vec_init_then_push)]
+    mod tests{
+    use super::*;
+    use std::cell::RefCell;
+    //zona de testing
+
+    //arma un club "a mano" con los campos indicados, dejando el resto del storage con los valores que asigna el constructor
+    fn club_armado(costos: CostosCategoria, socios: Vec<Socio>, pagos: Vec<Pago>, owner: AccountId, permitidos: Vec<AccountId>, politica_activada: bool, emision_ultimo_pago: Option<u64>) -> ClubSemRust {
+        let mut club = ClubSemRust::new_priv(0, 0, 0, 0, 0);
+        club.costos = costos;
+        club.socios = socios;
+        club.pagos = pagos;
+        club.owner = owner;
+        club.permitidos = permitidos;
+        club.politica_activada = politica_activada;
+        club.emision_ultimo_pago = emision_ultimo_pago;
+        return club;
+    }
+
+    //arma un club con los costos de siempre, con el owner (0x1) como caller, y registra a David Starsky (30537974) en la
+    //categoria y deporte indicados, quedando pendiente el pago de su alta
+    fn club_con_socio(id_categoria: u32, id_deporte: Option<u32>) -> ClubSemRust {
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x1; 32]));
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        club.registrar_socio(30537974, "David Starsky".to_string(), id_categoria, id_deporte);
+        return club;
+    }
+
+    //Testing de pago
+    // Para testear los new, simplemente hago un new de cada pago y en cada test chequeo que la variable del struct pago corresponda con el valor ingresado
+    #[ink::test]
+    fn pago_new_check_dni_test (){
+        let p1 = Pago::new(44933856,2000,1688639827,None,false);
+        assert_eq!(p1.dni,44933856);
+    }
+    #[ink::test]
+    fn pago_new_check_monto_test (){
+        let p1 = Pago::new(44933856,2000,1688639827,None,false);
+        assert_eq!(p1.monto,2000);
+    }
+    #[ink::test]
+    fn pago_new_check_fecha_vencimiento_test (){
+        let p1 = Pago::new(44933856,2000,1688639827,None,false);
+        assert_eq!(p1.fecha_venci,1688639827);
+    }
+    #[ink::test]
+    fn pago_new_check_fecha_pago_none_test (){
+        let p1 = Pago::new(44933856,2000,1688639827,None,false);
+        assert_eq!(p1.fecha_pago,None);
+    }
+    #[ink::test]
+    fn pago_new_check_fecha_pago_some_test (){
+        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),false);
+        assert_eq!(p1.fecha_pago,Some(1688553427));
+    }
+    #[ink::test]
+    fn pago_new_check_bonificacion_false_test (){
+        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),false);
+        assert_eq!(p1.bonificado,false);
+    }
+    #[ink::test]
+    fn pago_new_check_bonificacion_true_test (){
+        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
+        assert_eq!(p1.bonificado,true);
+    }
+    //Para chequear los getters, hago el new de pago, creo una variable x_recibido y compruebo que x_recibido se corresponda con el valor ingresado previamente en el new
+    #[ink::test]
+    fn get_dni_socio_test(){
+        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
+        let dni_recibido = p1.get_dni_socio();
+        assert_eq!(dni_recibido,44933856);
+    }
+    #[ink::test]
+    fn get_monto_test(){
+        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
+        let monto_recibido = p1.get_monto();
+        assert_eq!(monto_recibido,2000);
+    }
+    #[ink::test]
+    fn get_fecha_venci_test(){
+        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
+        let fecha_recibida = p1.get_fecha_venci();
+        assert_eq!(fecha_recibida,1688639827);
+    }
+    #[ink::test]
+    fn get_fecha_pago_some_test(){
+        let p1 = Pago::new(44933856,2000,1688639827,Some(1688553427),true);
+        let fecha_recibida = p1.get_fecha_pago();
+        assert_eq!(fecha_recibida,Some(1688553427));
+    }
+    #[ink::test]
+    fn get_fecha_pago_none_test(){
+        let p1 = Pago::new(44933856,2000,1688639827,None,true);
+        let fecha_recibida = p1.get_fecha_pago();
+        assert_eq!(fecha_recibida,None);
+    }
+
+   ///Para chequear que el set_fecha_pago se ejecute correctamente
+   ///creo un nuevo pago, le realizo un set_fecha de pago
+   ///y luego utilizo una variable auxiliar fecha_recibida
+   ///con el get_fecha_pago (ya testeado previamente)
+   ///luego compruebo que fecha_recibida sea igual a lo que
+   ///ingrese previamente en el set fecha pago
+    #[ink::test]
+    fn set_fecha_pago_test(){
+        let mut p1 = Pago::new(44933856,2000,1688639827,None,true);
+        p1.set_fecha_pago(1688553427);
+        let fecha_recibida = p1.get_fecha_pago();
+        assert_eq!(fecha_recibida,Some(1688553427));
+    }
+    //testing de costos categoria
+
+    // para testear los new, simplemente hago un new de cada costo categoria y en cada test chequeo que la variable del struct CostosCategoria corresponda con el valor ingresado
+    #[ink::test]
+    fn costos_categoria_new_valor_a_test(){
+        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        assert_eq!(c1.a,1000);
+    }
+    #[ink::test]
+    fn costos_categoria_new_valor_b_test(){
+        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        assert_eq!(c1.b,3000);
+    }
+    #[ink::test]
+    fn costos_categoria_new_valor_c_test(){
+        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        assert_eq!(c1.c,5000);
+    }
+    #[ink::test]
+    fn costos_categoria_new_valor_beneficio_test(){
+        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        assert_eq!(c1.beneficio,400);
+    }
+    #[ink::test]
+    fn costos_categoria_new_pagos_consecutivoss_test(){
+        let c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        assert_eq!(c1.pagos_consecutivos_para_beneficio,3);
+    }
+    //para chequear los setters, creo una nueva variable CostosCategoria y seteo
+    //en diferentes tests sus diferentes variables. Finalmente, compruebo que la variable seteada
+    //se corresponda con el nuevo valor seteado
+    #[ink::test]
+    fn set_costo_a_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        c1.set_costo(2500, &CategoriasSocios::A).unwrap();
+        assert_eq!(c1.a,2500);
+    }
+    #[ink::test]
+    fn set_costo_b_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        c1.set_costo(2500, &CategoriasSocios::B).unwrap();
+        assert_eq!(c1.b,2500);
+    }
+    #[ink::test]
+    fn set_costo_c_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        c1.set_costo(2500, &CategoriasSocios::C).unwrap();
+        assert_eq!(c1.c,2500);
+    }
+    ///para los getters creo una variable CostosCategoria 
+    ///realizo el get que quiero testear y compruebo que se
+    ///corresponda con el valor ingresado en el new
+    #[ink::test]
+    fn get_costo_a_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        let costo_recibido = c1.get_costo(&CategoriasSocios::A);
+        assert_eq!(costo_recibido,1000);
+    }
+    #[ink::test]
+    fn get_costo_b_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        let costo_recibido = c1.get_costo(&CategoriasSocios::B);
+        assert_eq!(costo_recibido,3000);
+    }
+    #[ink::test]
+    fn get_costo_c_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        let costo_recibido = c1.get_costo(&CategoriasSocios::C);
+        assert_eq!(costo_recibido,5000);
+    }
+    #[ink::test]
+    fn set_beneficio_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        c1.set_beneficio(500).unwrap();
+        assert_eq!(c1.beneficio,500);
+    }
+    ///los setters rechazan los valores que dejarian el beneficio por encima del costo de alguna categoria
+    #[ink::test]
+    fn costos_categoria_invariante_beneficio_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        assert_eq!(c1.set_beneficio(1001), Err(ErrorCostos::BeneficioSuperaCosto));
+        assert_eq!(c1.set_costo(399, &CategoriasSocios::C), Err(ErrorCostos::BeneficioSuperaCosto));
+        assert_eq!(c1, CostosCategoria::new(1000,3000,5000,400,3), "Un valor rechazado no debio modificar los costos");
+        assert_eq!(c1.set_beneficio(1000), Ok(()));
+        assert_eq!(c1.get_costo_minimo(), 1000);
+    }
+    #[ink::test]
+    #[should_panic(expected = "El beneficio no puede superar el costo de ninguna categoria!")]
+    fn new_beneficio_mayor_al_costo_test(){
+        ClubSemRust::new(5000, 3000, 2000, 2500, 3);
+    }
+    #[ink::test]
+    #[should_panic(expected = "El beneficio no puede superar el costo de ninguna categoria!")]
+    fn actualizar_costo_categoria_menor_al_beneficio_test(){
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        club.actualizar_costos_categoria(3, 999);
+    }
+    #[ink::test]
+    #[should_panic(expected = "El resultado de la operacion excede el maximo representable!")]
+    fn actualizar_ventana_reversion_desbordada_test(){
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        club.actualizar_ventana_reversion(u64::MAX);
+    }
+    #[ink::test]
+    fn set_pagos_consecutivos_para_beneficio_test(){
+        let mut c1 = CostosCategoria::new(1000,3000,5000,400,3);
+        c1.set_pagos_consecutivos_para_beneficio(5);
+        assert_eq!(c1.pagos_consecutivos_para_beneficio,5);
+    }
+
+    //testing de clubSemRust
+
+
+   /// para testear el new, lo divido en varios tests diferentes para hacerlo lo mas unitario posible
+   /// primero creo un club con valores de costos, beneficio y dias para el beneficio, luego entre cada test  
+   /// chequeo que se instancien los valores correctamente y que los valores default como el vec de socios,
+   /// el vec de pagos o la politica se instancien correctamente  
+    #[ink::test]
+    fn club_sem_rust_new_costoa_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        assert_eq!(club1.costos.a,1000);
+    }
+    #[ink::test]
+    fn club_sem_rust_new_costob_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
         assert_eq!(club1.costos.b,2000);
     }
     #[ink::test]
@@ -1077,1618 +5258,4193 @@ pub mod ClubSemRust {
         assert_eq!(club1.costos.c,5000);
     }
     #[ink::test]
-    fn club_sem_rust_new_valor_beneficio_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    fn club_sem_rust_new_valor_beneficio_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        assert_eq!(club1.costos.beneficio,400);
+    }
+    #[ink::test]
+    fn club_sem_rust_new_pagos_consecutivos_beneficio_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        assert_eq!(club1.costos.pagos_consecutivos_para_beneficio,3);
+    }
+    #[ink::test]
+    fn club_sem_rust_new_emision_ultimo_pago_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        assert_eq!(club1.emision_ultimo_pago,None);
+    }
+    #[ink::test]
+    fn club_sem_rust_new_owner_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let caller = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        assert_eq!(club1.owner,caller);
+    }
+    #[ink::test]
+    fn club_sem_rust_new_pagos_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        assert_eq!(club1.pagos.is_empty(),true);
+    }
+    #[ink::test]
+    fn club_sem_rust_new_socios_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        assert_eq!(club1.socios.is_empty(),true);
+    }
+    #[ink::test]
+    fn club_sem_rust_new_permitidos_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        assert_eq!(club1.permitidos.is_empty(),true);
+    }
+    #[ink::test]
+    fn club_sem_rust_new_politica_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        assert_eq!(club1.politica_activada,true);
+    }
+    //para chequear el get_politica_autorizacion creo un nuevo ClubSemRust (al hacer el new, la politica de autorizacion se inicializa en true)
+    //y luego creo una variable esperado en la cual recibo el resultado de get_politica_autorizacion, finalmente, compruebo que esta sea igual a true 
+    #[ink::test]
+    fn get_politica_autorizacion_owner_call_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let esperado=club1.get_politica_autorizacion();
+        assert_eq!(esperado,true);
+    }
+   ///para comprobar que el codigo paniquea si la politica de autorizacion esta activada
+   /// y se invoca al metodo get_politica_autorizacion sin ser staff o owner
+   /// simplemente creo una variable owner con la cual llamo primero, y realizo el new
+   /// y posteriormente le paso el caller a un Not owner (un accountId diferente al que creo la variable club1)
+   /// y llamo al metodo get_politica_autorizacion 
+    #[ink::test]
+    #[should_panic]
+    fn get_politica_autorizacion_not_permited_call_test(){
+        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let not_an_owner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_an_owner);
+        let esperado=club1.get_politica_autorizacion();
+    }
+   /// el quitar_staff devuelve un booleano si se pudo quitar la cuenta correctamente
+   /// para chequear que este metodo se comporte de manera correcta, creo un club
+   /// agrego al staff un accoundId llamado cuenta1, compruebo que se haya agregado,
+   /// chequeando que el len de permitidos sea igual a 1
+   /// y luego llamo al metodo quitar_staff finalmente, para asegurarme que 
+   /// se elimino, chequeo que el metodo devuelva true y la len de permitidos sea 0
+    #[ink::test]
+    fn quitar_staff_admin_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let cuenta1 = AccountId::from([0x2;32]);
+        club1.agregar_staff(cuenta1);
+        assert_eq!(club1.permitidos.len(),1);
+        let ok = club1.quitar_staff(cuenta1);
+        assert_eq!(ok,true); 
+        assert_eq!(club1.permitidos.len(),0);
+    }
+    ///para comprobar este panic, creo el ClubSemRust con un accountId
+    ///y agrego una persona a staff, luego le paso el caller a otro accountId (not an owner)
+    ///e intento llamar a quitar_staff, como no es un owner, panickea
+    #[ink::test]
+    #[should_panic]
+    fn quitar_staff_not_admin_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let cuenta1 = AccountId::from([0x2;32]);
+        club1.agregar_staff(cuenta1);
+        let not_an_owner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_an_owner);
+        let ok = club1.quitar_staff(cuenta1); 
+    }
+    ///en este test creo una variable accountId (que no se encuentra en staff)
+    ///y llamo a quitar_staff con esta variable que cree, al no encontrarse, panickea
+    #[ink::test]
+    #[should_panic]
+    fn quitar_staff_no_encontrado_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let cuenta1 = AccountId::from([0x2;32]);
+        let ok = club1.quitar_staff(cuenta1);
+    }
+
+   /// Para chequear el metodo actualizar_costos_categoria, creo un ClubSemRust
+   /// y le actualizo el costo una a una a cada categoria en los diferentes tests
+   /// al final de ellos, obtengo en una variable nuevo_costo el valor de costos.x
+   /// (x es el costos que estoy chequeando) y luego comparo nuevo_costo con el costo
+   /// asignado recientemente
+    #[ink::test]
+    fn actualizar_costos_categoria_a_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        club1.actualizar_costos_categoria(1, 1500);
+        let nuevo_costo = club1.costos.a;
+        assert_eq!(nuevo_costo,1500);
+    }
+    #[ink::test]
+    fn actualizar_costos_categoria_b_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        club1.actualizar_costos_categoria(2, 1500);
+        let nuevo_costo = club1.costos.b;
+        assert_eq!(nuevo_costo,1500);
+    }
+    #[ink::test]
+    fn actualizar_costos_categoria_c_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        club1.actualizar_costos_categoria(3, 1500);
+        let nuevo_costo = club1.costos.c;
+        assert_eq!(nuevo_costo,1500);
+    }
+
+    ///en los siguientes casos especiales, chequeo que los panics de una actualizacion de una categoria inexistente
+    ///y alguien intentando actualizar una categoria sin tener los permisos necesarios, se disparen correctamente
+    #[ink::test]
+    #[should_panic]
+    fn actualizar_costos_categoria_inexistente_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        club1.actualizar_costos_categoria(5, 1500);
+    }
+    #[ink::test]
+    #[should_panic]
+    fn actualizar_costos_categoria_no_tiene_permisos_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let notowner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(notowner);
+        club1.actualizar_costos_categoria(2, 1500);
+    }
+    ///para chequear el get socio, creo una variable de tipo Socio s1 y luego
+    ///creo otra variable de tipo Socio s2 igual a get_socio con el dni que use previamente con s1
+    ///finalmente chequeo que s1 y s2 sean iguales para ccomprobar que el get socio funciono correctamente
+    #[ink::test]
+    fn get_socio_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let s1 = Socio::new("nico beiser".to_string(), 44956748, CategoriasSocios::C, None);
+        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
+        let s2 = club1.get_socio(44956748);
+        assert_eq!(s1,s2.unwrap());
+    }
+    ///cuando se hace getsocio con un dni que no existe en la lista de socios, retorna None
+    ///por esa razon, realizo el getsocio con un dni inexistente, y compruebo que lo retornado sea igual a None
+    #[ink::test]
+    fn get_socio_no_existente_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
+        let socio_no_existente = club1.get_socio(44444);
+        assert_eq!(socio_no_existente,None);
+    }
+   /// get_socio no es una funcion que pueda hacer cualquiera, para testear el siguiente panick
+   /// creo una variable clubsemrust, le registro un socio, y luego cambio el caller a un not_owner
+   /// el not_owner intenta hacer el get_socio y dispara el panick
+    #[ink::test]
+    #[should_panic]
+    fn get_socio_not_admin_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let s1 = Socio::new("nico beiser".to_string(), 44956748, CategoriasSocios::C, None);
+        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
+        let not_owner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
+        let s2 = club1.get_socio(44956748);
+    }
+   ///para este test, realizo lo mismo que en el test previo, con la diferencia que antes de cambiar de caller
+   ///cambio la politica de autorizacion, permitiendo que cualquier persona, independientemente de si es socio o no
+   ///pueda llamar al get_socio, de esta manera el metodo no genera un panic
+    #[ink::test]
+    fn get_socio_not_admin_politica_desactivada_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+        let owner = AccountId::from([0x1;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        let s1 = Socio::new("nico beiser".to_string(), 44956748, CategoriasSocios::C, None);
+        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
+        club1.toggle_politica_autorizacion();
+        let not_owner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
+        let s2 = club1.get_socio(44956748);
+        assert_eq!(s1,s2.unwrap());
+    }
+
+
+   ///para este test, creo una variable clubSemRust y registro un socio.
+   ///luego, utilizo el comando set_blocl_timestamp para poner el timestamp en
+   ///30 dias posteriores a la emision del ultimo pago (logica entre las lineas 1035 y 1040)
+   ///una vez terminado esto, compruebo que el vec de pagos totales del socio que registre sea 1, ya que al registrarlo se crea un nuevo pago pendiente
+   ///posteriormente emito el pago mensual y compruebo que el vec de pagos sea 2, y finalmente, realizo el pago y compruebo que el vec siga siendo dos, ya que es pagos totales
+   ///y no deberia interferir si esta pago o pendiente
+    #[ink::test]
+    fn get_pagos_totales_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        assert_eq!(club1.costos.beneficio,400);
+        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
+        let treintadias = 30.from_dias();
+        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
+        em += treintadias;
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
+        let vec_esperado1 = club1.get_pagos_totales(Some(44956748));
+        assert_eq!(vec_esperado1.len(),1);
+        club1.emitir_pago_mensual();
+        let vec_esperado2 = club1.get_pagos_totales(Some(44956748));
+        assert_eq!(vec_esperado2.len(),2);
+        club1.realizar_pago(44956748, 5000);
+        let vec_esperado3 = club1.get_pagos_totales(Some(44956748));
+        assert_eq!(vec_esperado3.len(),2);
     }
+    ///en este test intento llamar al metodo get_pagos_totales luego de cambiar el caller
+    ///a un accountId que no es owner ni staff, este test panickea como es de esperar
     #[ink::test]
-    fn club_sem_rust_new_pagos_consecutivos_beneficio_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    #[should_panic]
+    fn get_pagos_totales_not_an_owner_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        assert_eq!(club1.costos.pagos_consecutivos_para_beneficio,3);
+        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
+        let not_an_owner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_an_owner);
+        club1.get_pagos_totales(Some(44956748));
     }
+    ///por la logica en la cual esta basada el get_pagos_totales, si el dni no se encuentra en los socios, simplemente retornara un vec vacio
+    ///por ello, para testear este caso, compruebo que el vec que retorna al ingresar un dni inexistente en mi vec de socios tiene longitud 0
     #[ink::test]
-    fn club_sem_rust_new_emision_ultimo_pago_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    fn get_pagos_totales_dni_inexistente_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        assert_eq!(club1.emision_ultimo_pago,None);
-    }
-    #[ink::test]
-    fn club_sem_rust_new_owner_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let caller = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
-        assert_eq!(club1.owner,caller);
+        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
+        let vec_esperado1 = club1.get_pagos_totales(Some(1111111));
+        assert_eq!(vec_esperado1.len(),0);
     }
+    ///Por la logica en la cual esta basada el get_pagos_totales, si no se ingresa un dni, retornará una copia del 
+    ///Vec de pagos del club, así de esta forma, verifico que la longitud del Vec recibido, sea la misma que la del 
+    ///Vec de pagos del club.
     #[ink::test]
-    fn club_sem_rust_new_pagos_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    fn get_pagos_totales_sin_dni_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        assert_eq!(club1.pagos.is_empty(),true);
+        club1.registrar_socio(30320763, "Bouregard Duke".to_string(), 3, None);
+        let vec_esperado1 = club1.get_pagos_totales(None);
+        assert_eq!(vec_esperado1.len(), club1.pagos.len());
     }
+
+    ///para chequear que no panickee cuando la politica de autorizacion esta desactivada, creo un nuevo club, le agrego un socio
+    ///y desactivo la politica, cambio el caller, y llamo al get_pagos_totales. Finalmente compruebo que la informacion 
+    ///llegue correctamente esperando un vec de longitud 1
     #[ink::test]
-    fn club_sem_rust_new_socios_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    fn get_pagos_totales_not_an_owner_politica_desactivada_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        assert_eq!(club1.socios.is_empty(),true);
+        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
+        club1.toggle_politica_autorizacion();
+        let not_an_owner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_an_owner);
+        let vec_esperado1 = club1.get_pagos_totales(Some(44956748));
+        assert_eq!(vec_esperado1.len(),1);
     }
+
+    ///para chequear que el emitir pagos se realice correctamente hay que hacer enfasis en ciertos casos especiales
+    ///primero y principal la emision de pago normal, para esto creo un socio (nicolas beiserman) y luego realizo un for de 1..3
+    ///emitiendo pagos, luego al salir del for emito otro pago mas (cada vez que emito un pago lo hago avanzando 30 dias, sino el programa
+    ///arrojaria un panic) una vez que ya envie el pago despues del for, me traigo en mi variable v1 el vec de pagos totales(con un metodo
+    ///ya testeado previamente) y chequeo que este vec tenga una len de 4 (el pago de la creacion, 2 pagos del for y 1 al salir del for)
+    ///por otro lado, chequeo el bonificado, aqui dice que cada 3 pagos el 4to debera ser bonificado, por eso primero pago el primer Pago
+    ///de registrarme, y realizo un for de 1..3 emitiendo pagos y pagandolos consecutivamente en tiempo y forma, al salir del for
+    ///emito un ultimo pago el cual deberia ser con bonificacion de 400 pesos, para chequear esto, creo la variable esperado que contiene
+    ///el valor de la categoria elegida - la bonificacion, luego utilizo el previamente mencionado vec de pagos totales y compruebo que
+    ///el monto del ultimo pago emitido sea igual a lo que esperaba. Por otra parte tambien testeo que no se le de un bonificado a los que no pagan
+    ///para eso cree el socio fran basterrechea, el cual no paga nunca durante todo el test. Luego de testear el ultimo pago de nicolas beiserman
+    ///compruebo que estos pagos se le hayan atribuido a la cuenta de fran basterrechea, comprobando su len(debe ser igual a 4) y luego
+    ///tomo su ultimo pago y compruebo de la misma manera que hice con nicolas beiserman, el monto a pagar(en este caso sera el monto de su categoria sin descuentos)
     #[ink::test]
-    fn club_sem_rust_new_permitidos_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    fn emitir_pago_mensual_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        assert_eq!(club1.permitidos.is_empty(),true);
+        club1.registrar_socio(44987654, "nicolas beiserman".to_string(),3, None);
+        club1.registrar_socio(41943654, "fran basterrechea".to_string(),1, None);
+        let treintadias = 30.from_dias();
+        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
+        club1.realizar_pago(44987654, 5000);
+        for i in 1..3{
+            em += treintadias;
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
+            club1.emitir_pago_mensual();
+            club1.realizar_pago(44987654, 5000);
+        }
+        em += treintadias;
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
+        club1.emitir_pago_mensual();
+        club1.realizar_pago(44987654, 4600);
+        let v1 = club1.get_pagos_totales(Some(44987654));
+        assert_eq!(v1.len(),4);
+        let ultimo_pago = &v1[v1.len()-1];
+        let esperado = 5000-400;
+        assert_eq!(ultimo_pago.monto,esperado);
+        let v1 = club1.get_pagos_totales(Some(41943654));
+        assert_eq!(v1.len(),4);
+        let ultimo_pago = &v1[v1.len()-1];
+        let esperado = 1000;
+        assert_eq!(ultimo_pago.monto,esperado);
     }
     #[ink::test]
-    fn club_sem_rust_new_politica_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    #[should_panic]
+    fn emitir_pago_mensual_caso_aun_no_se_han_emitido_pagos_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        assert_eq!(club1.politica_activada,true);
+        club1.emitir_pago_mensual();
     }
-    //para chequear el get_politica_autorizacion creo un nuevo ClubSemRust (al hacer el new, la politica de autorizacion se inicializa en true)
-    //y luego creo una variable esperado en la cual recibo el resultado de get_politica_autorizacion, finalmente, compruebo que esta sea igual a true 
+   ///para este test creo un clubsemrust, registro un socio y hago que solo pasen 10 dias
+   ///en el timestamp, con lo cual, luego realizo el emitir_pago_mensual y el programa
+   ///panickea ya que aun no han pasado los 30 dias correspondientes
     #[ink::test]
-    fn get_politica_autorizacion_owner_call_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    #[should_panic]
+    fn emitir_pago_mensual_caso_aun_no_han_pasado_30_dias_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let esperado=club1.get_politica_autorizacion();
-        assert_eq!(esperado,true);
+        club1.registrar_socio(44987654, "nicolas beiserman".to_string(),3, None);
+        let diezdias = 10.from_dias();
+        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
+        em += diezdias;
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
+        club1.emitir_pago_mensual();
     }
-   ///para comprobar que el codigo paniquea si la politica de autorizacion esta activada
-   /// y se invoca al metodo get_politica_autorizacion sin ser staff o owner
-   /// simplemente creo una variable owner con la cual llamo primero, y realizo el new
-   /// y posteriormente le paso el caller a un Not owner (un accountId diferente al que creo la variable club1)
-   /// y llamo al metodo get_politica_autorizacion 
+   ///para este test, creo el clubsemrust, registro un socio, hago que pasen 30 dias en
+   ///el timestamp y luego cambio el caller a un accoundId diferente del owner
+   ///intento emitir el pago mensual y el programa panickea 
     #[ink::test]
     #[should_panic]
-    fn get_politica_autorizacion_not_permited_call_test(){
-        let club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
+    fn emitir_pago_mensual_caso_not_owner_test(){
+        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let not_an_owner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_an_owner);
-        let esperado=club1.get_politica_autorizacion();
+        club1.registrar_socio(44987654, "nicolas beiserman".to_string(),3, None);
+        let treintadias = 30.from_dias();
+        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
+        em += treintadias;
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
+        let not_owner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
+        club1.emitir_pago_mensual();
     }
-   /// el quitar_staff devuelve un booleano si se pudo quitar la cuenta correctamente
-   /// para chequear que este metodo se comporte de manera correcta, creo un club
-   /// agrego al staff un accoundId llamado cuenta1, compruebo que se haya agregado,
-   /// chequeando que el len de permitidos sea igual a 1
-   /// y luego llamo al metodo quitar_staff finalmente, para asegurarme que 
-   /// se elimino, chequeo que el metodo devuelva true y la len de permitidos sea 0
+   ///para este test se creo el mismo escenario que el caso anterior pero antes de cambiar el
+   ///caller, se desactivo la politica de autorizacion, con lo cual el pago se deberia emitir
+   ///correctamente. Por ulitmo, para comprobar que el pago se emitio, compruebo la longitud
+   ///del vec de pagos, este, deberia tener 2 elementos(el pago del registrar socio y el emitido)
     #[ink::test]
-    fn quitar_staff_admin_test(){
+    fn emitir_pago_mensual_caso_not_owner_politica_desactivada_test(){
         let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
         let owner = AccountId::from([0x1;32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let cuenta1 = AccountId::from([0x2;32]);
-        club1.agregar_staff(cuenta1);
-        assert_eq!(club1.permitidos.len(),1);
-        let ok = club1.quitar_staff(cuenta1);
-        assert_eq!(ok,true); 
-        assert_eq!(club1.permitidos.len(),0);
+        club1.registrar_socio(44987654, "nicolas beiserman".to_string(),3, None);
+        let treintadias = 30.from_dias();
+        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
+        em += treintadias;
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
+        club1.toggle_politica_autorizacion();
+        let not_owner = AccountId::from([0x2;32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
+        club1.emitir_pago_mensual();
+        let v1 = club1.pagos;
+        assert_eq!(v1.len(),2);
     }
-    ///para comprobar este panic, creo el ClubSemRust con un accountId
-    ///y agrego una persona a staff, luego le paso el caller a otro accountId (not an owner)
-    ///e intento llamar a quitar_staff, como no es un owner, panickea
+
+    //test CategoriasSocios
+    #[ink::test]
+    fn categoria_from_id_correctas_test() {
+        //creo las categroias existentes, y la nula
+        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
+        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let cat_c = CategoriasSocios::categoria_from_id(&3).unwrap();
+
+        //testeo
+        assert_eq! (cat_a, CategoriasSocios::A, "Debio ser categoria A");
+        assert_eq! (cat_b, CategoriasSocios::B, "Debio ser categoria B");
+        assert_eq! (cat_c, CategoriasSocios::C, "Debio ser categoria C");
+    }
+
     #[ink::test]
     #[should_panic]
-    fn quitar_staff_not_admin_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let cuenta1 = AccountId::from([0x2;32]);
-        club1.agregar_staff(cuenta1);
-        let not_an_owner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_an_owner);
-        let ok = club1.quitar_staff(cuenta1); 
+    fn categoria_from_id_incorrecta_test() {
+        //pido una categoria inexistente, esperando el panic
+        let cat = CategoriasSocios::categoria_from_id(&4);
+    }
+
+    //tests Socio
+
+    #[ink::test]
+    fn new_socio_cat_a_y_c_test() {
+        //creo los datos de un socio categoria c
+        let ape_y_nom_c = "Michael Knight".to_string();
+        let dni_c = 35198260;
+        let cat_c = CategoriasSocios::categoria_from_id(&3).unwrap();
+        let deporte_c = None;
+
+        //creo al socio categoria c
+        let socio_c = Socio::new(ape_y_nom_c.clone(), dni_c, cat_c.clone(), deporte_c.clone());
+
+        //creo los datos de un socio categoria a
+        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
+        let dni_a = 31195032;
+        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
+        let deporte_a = None;
+
+        //creo al socio categoria a
+        let socio_a = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+
+        //testeo
+        assert_eq!(ape_y_nom_c, socio_c.apellido_y_nombre, "Deberia ser el mismo nombre en socio_c");
+        assert_eq!(dni_c, socio_c.dni, "Deberia ser el mismo nombre en socio_c");
+        assert_eq!(cat_c, socio_c.categoria, "Deberia ser la misma categoria en socio_c");
+        assert_eq!(deporte_c, socio_c.deporte, "Deberia ser el mismo deporte en socio_c");
+
+        assert_eq!(ape_y_nom_a, socio_a.apellido_y_nombre, "Deberia ser el mismo nombre en socio_a");
+        assert_eq!(dni_a, socio_a.dni, "Deberia ser el mismo nombre en socio_a");
+        assert_eq!(cat_a, socio_a.categoria, "Deberia ser la misma categoria en socio_a");
+        assert_eq!(deporte_a, socio_a.deporte, "Deberia ser el mismo deporte en socio_a");
     }
-    ///en este test creo una variable accountId (que no se encuentra en staff)
-    ///y llamo a quitar_staff con esta variable que cree, al no encontrarse, panickea
+
     #[ink::test]
-    #[should_panic]
-    fn quitar_staff_no_encontrado_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let cuenta1 = AccountId::from([0x2;32]);
-        let ok = club1.quitar_staff(cuenta1);
+    fn new_socio_cat_b_test() {
+        //creo los datos de un socio categoria b
+        let ape_y_nom = "David Starsky".to_string();
+        let dni = 30537974;
+        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte = Deporte::deporte_from_id(&Some(2));
+
+        //creo al socio categoria b
+        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+
+        //testeo
+        assert_eq!(ape_y_nom, socio.apellido_y_nombre, "Deberia ser el mismo nombre en socio");
+        assert_eq!(dni, socio.dni, "Deberia ser el mismo nombre en socio");
+        assert_eq!(cat, socio.categoria, "Deberia ser la misma categoria en socio");
+        assert_eq!(deporte, socio.deporte, "Deberia ser el mismo deporte en socio");
     }
 
-   /// Para chequear el metodo actualizar_costos_categoria, creo un ClubSemRust
-   /// y le actualizo el costo una a una a cada categoria en los diferentes tests
-   /// al final de ellos, obtengo en una variable nuevo_costo el valor de costos.x
-   /// (x es el costos que estoy chequeando) y luego comparo nuevo_costo con el costo
-   /// asignado recientemente
     #[ink::test]
-    fn actualizar_costos_categoria_a_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.actualizar_costos_categoria(1, 1500);
-        let nuevo_costo = club1.costos.a;
-        assert_eq!(nuevo_costo,1500);
+    fn get_apellido_y_nombre_test() {
+        //creo un socio
+        let ape_y_nom = "David Starsky".to_string();
+        let dni = 30537974;
+        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte = Deporte::deporte_from_id(&Some(2));
+        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+
+        assert_eq!(socio.get_apellido_y_nombre(), ape_y_nom, "Debio ser el mismo nombre");
     }
+
     #[ink::test]
-    fn actualizar_costos_categoria_b_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.actualizar_costos_categoria(2, 1500);
-        let nuevo_costo = club1.costos.b;
-        assert_eq!(nuevo_costo,1500);
+    fn get_dni_test() {
+        //creo un socio
+        let ape_y_nom = "David Starsky".to_string();
+        let dni = 30537974;
+        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte = Deporte::deporte_from_id(&Some(2));
+        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+
+        assert_eq!(socio.get_dni(), dni, "Debio ser el mismo DNI");
     }
+
     #[ink::test]
-    fn actualizar_costos_categoria_c_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.actualizar_costos_categoria(3, 1500);
-        let nuevo_costo = club1.costos.c;
-        assert_eq!(nuevo_costo,1500);
+    fn get_categoria_test() {
+        //creo un socio
+        let ape_y_nom = "David Starsky".to_string();
+        let dni = 30537974;
+        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte = Deporte::deporte_from_id(&Some(2));
+        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+
+        assert_eq!(socio.get_categoria(), cat, "Debio ser la misma categoria");
     }
 
-    ///en los siguientes casos especiales, chequeo que los panics de una actualizacion de una categoria inexistente
-    ///y alguien intentando actualizar una categoria sin tener los permisos necesarios, se disparen correctamente
     #[ink::test]
-    #[should_panic]
-    fn actualizar_costos_categoria_inexistente_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.actualizar_costos_categoria(5, 1500);
+    fn get_deporte_cat_test() {
+        //creo un socio categoria b
+        let ape_y_nom = "David Starsky".to_string();
+        let dni = 30537974;
+        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte = Deporte::deporte_from_id(&Some(2));
+        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+
+        assert_eq!(socio.get_deporte(), deporte, "Debio ser el mismo deporte");
     }
+
+    //tests club
+
     #[ink::test]
-    #[should_panic]
-    fn actualizar_costos_categoria_no_tiene_permisos_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn set_owner_correcto_test() {
+        //seteo un caller
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let notowner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(notowner);
-        club1.actualizar_costos_categoria(2, 1500);
+
+        //creo un club, y cambio su owner
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        let nuevo_owner = AccountId::from([0x2; 32]);
+        club.set_owner(nuevo_owner);  
+
+        //compruebo
+        assert_eq!(club.owner, nuevo_owner, "Debio haber cambiado el owner, ya que el llamado lo realiza el owner");
     }
-    ///para chequear el get socio, creo una variable de tipo Socio s1 y luego
-    ///creo otra variable de tipo Socio s2 igual a get_socio con el dni que use previamente con s1
-    ///finalmente chequeo que s1 y s2 sean iguales para ccomprobar que el get socio funciono correctamente
+
     #[ink::test]
-    fn get_socio_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    #[should_panic]
+    fn set_owner_incorrecto_test() {
+        //seteo un caller
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let s1 = Socio::new("nico beiser".to_string(), 44956748, CategoriasSocios::C, None);
-        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
-        let s2 = club1.get_socio(44956748);
-        assert_eq!(s1,s2.unwrap());
+
+        //creo un club, cambio al caller, y llamo a set_owner, esperando el panic 
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        let no_permitido = AccountId::from([0x2; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
+
+        club.set_owner(no_permitido);
     }
-    ///cuando se hace getsocio con un dni que no existe en la lista de socios, retorna None
-    ///por esa razon, realizo el getsocio con un dni inexistente, y compruebo que lo retornado sea igual a None
+
     #[ink::test]
-    fn get_socio_no_existente_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn agregar_staff_correcto_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
-        let socio_no_existente = club1.get_socio(44444);
-        assert_eq!(socio_no_existente,None);
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //agrego un staff y verifico
+        assert_eq!(club.agregar_staff(AccountId::from([0x2; 32])), true, "Debio agregar al staff sin problemas");
     }
-   /// get_socio no es una funcion que pueda hacer cualquiera, para testear el siguiente panick
-   /// creo una variable clubsemrust, le registro un socio, y luego cambio el caller a un not_owner
-   /// el not_owner intenta hacer el get_socio y dispara el panick
+
     #[ink::test]
     #[should_panic]
-    fn get_socio_not_admin_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let s1 = Socio::new("nico beiser".to_string(), 44956748, CategoriasSocios::C, None);
-        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
-        let not_owner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
-        let s2 = club1.get_socio(44956748);
-    }
-   ///para este test, realizo lo mismo que en el test previo, con la diferencia que antes de cambiar de caller
-   ///cambio la politica de autorizacion, permitiendo que cualquier persona, independientemente de si es socio o no
-   ///pueda llamar al get_socio, de esta manera el metodo no genera un panic
-    #[ink::test]
-    fn get_socio_not_admin_politica_desactivada_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn agregar_staff_existente_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        let s1 = Socio::new("nico beiser".to_string(), 44956748, CategoriasSocios::C, None);
-        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
-        club1.toggle_politica_autorizacion();
-        let not_owner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
-        let s2 = club1.get_socio(44956748);
-        assert_eq!(s1,s2.unwrap());
-    }
 
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
 
-   ///para este test, creo una variable clubSemRust y registro un socio.
-   ///luego, utilizo el comando set_blocl_timestamp para poner el timestamp en
-   ///30 dias posteriores a la emision del ultimo pago (logica entre las lineas 1035 y 1040)
-   ///una vez terminado esto, compruebo que el vec de pagos totales del socio que registre sea 1, ya que al registrarlo se crea un nuevo pago pendiente
-   ///posteriormente emito el pago mensual y compruebo que el vec de pagos sea 2, y finalmente, realizo el pago y compruebo que el vec siga siendo dos, ya que es pagos totales
-   ///y no deberia interferir si esta pago o pendiente
-    #[ink::test]
-    fn get_pagos_totales_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
-        let treintadias = 30.from_dias();
-        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
-        em += treintadias;
-        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
-        let vec_esperado1 = club1.get_pagos_totales(Some(44956748));
-        assert_eq!(vec_esperado1.len(),1);
-        club1.emitir_pago_mensual();
-        let vec_esperado2 = club1.get_pagos_totales(Some(44956748));
-        assert_eq!(vec_esperado2.len(),2);
-        club1.realizar_pago(44956748, 5000);
-        let vec_esperado3 = club1.get_pagos_totales(Some(44956748));
-        assert_eq!(vec_esperado3.len(),2);
+        //agrego un staff
+        let staff = AccountId::from([0x2; 32]);
+        club.agregar_staff(staff);
+
+        //intento agregar el mismo, esperando el panic
+        club.agregar_staff(staff);
     }
-    ///en este test intento llamar al metodo get_pagos_totales luego de cambiar el caller
-    ///a un accountId que no es owner ni staff, este test panickea como es de esperar
+
     #[ink::test]
     #[should_panic]
-    fn get_pagos_totales_not_an_owner_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn agregar_staff_incorrecto_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
-        let not_an_owner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_an_owner);
-        club1.get_pagos_totales(Some(44956748));
-    }
-    ///por la logica en la cual esta basada el get_pagos_totales, si el dni no se encuentra en los socios, simplemente retornara un vec vacio
-    ///por ello, para testear este caso, compruebo que el vec que retorna al ingresar un dni inexistente en mi vec de socios tiene longitud 0
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //cambio el caller, del owner a un posible staff, y llamo a agregar_staff, esperando el panic
+        let staff = AccountId::from([0x2; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+
+        club.agregar_staff(staff);
+    }        
+
     #[ink::test]
-    fn get_pagos_totales_dni_inexistente_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn es_staff_correcto_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
-        let vec_esperado1 = club1.get_pagos_totales(Some(1111111));
-        assert_eq!(vec_esperado1.len(),0);
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //creo y agrego un staff
+        let staff = AccountId::from([0x2; 32]);
+        club.agregar_staff(staff);
+
+        //seteo el staff agregado como caller, para realizar la comparacion con el metodo es_staff
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+
+        assert_eq! (club.es_staff(), true, "Debio ser true, ya que el staff se ha agregado");
     }
-    ///Por la logica en la cual esta basada el get_pagos_totales, si no se ingresa un dni, retornará una copia del 
-    ///Vec de pagos del club, así de esta forma, verifico que la longitud del Vec recibido, sea la misma que la del 
-    ///Vec de pagos del club.
+
     #[ink::test]
-    fn get_pagos_totales_sin_dni_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn es_staff_incorrecto_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(30320763, "Bouregard Duke".to_string(), 3, None);
-        let vec_esperado1 = club1.get_pagos_totales(None);
-        assert_eq!(vec_esperado1.len(), club1.pagos.len());
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //agrego un staff
+        club.agregar_staff(AccountId::from([0x2; 32]));
+
+        //llamo a es_staff con el owner
+        assert_eq! (club.es_staff(), false, "Debio ser false, ya que el caller no es staff");
     }
 
-    ///para chequear que no panickee cuando la politica de autorizacion esta desactivada, creo un nuevo club, le agrego un socio
-    ///y desactivo la politica, cambio el caller, y llamo al get_pagos_totales. Finalmente compruebo que la informacion 
-    ///llegue correctamente esperando un vec de longitud 1
     #[ink::test]
-    fn get_pagos_totales_not_an_owner_politica_desactivada_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn verificar_permisos_owner_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44956748, "nico beiser".to_string(), 3, None);
-        club1.toggle_politica_autorizacion();
-        let not_an_owner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_an_owner);
-        let vec_esperado1 = club1.get_pagos_totales(Some(44956748));
-        assert_eq!(vec_esperado1.len(),1);
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //agrego un staff
+        club.agregar_staff(AccountId::from([0x2; 32]));
+
+        assert_eq! (club.verificar_permisos(), true, "Debio ser verdadero");
     }
 
-    ///para chequear que el emitir pagos se realice correctamente hay que hacer enfasis en ciertos casos especiales
-    ///primero y principal la emision de pago normal, para esto creo un socio (nicolas beiserman) y luego realizo un for de 1..3
-    ///emitiendo pagos, luego al salir del for emito otro pago mas (cada vez que emito un pago lo hago avanzando 30 dias, sino el programa
-    ///arrojaria un panic) una vez que ya envie el pago despues del for, me traigo en mi variable v1 el vec de pagos totales(con un metodo
-    ///ya testeado previamente) y chequeo que este vec tenga una len de 4 (el pago de la creacion, 2 pagos del for y 1 al salir del for)
-    ///por otro lado, chequeo el bonificado, aqui dice que cada 3 pagos el 4to debera ser bonificado, por eso primero pago el primer Pago
-    ///de registrarme, y realizo un for de 1..3 emitiendo pagos y pagandolos consecutivamente en tiempo y forma, al salir del for
-    ///emito un ultimo pago el cual deberia ser con bonificacion de 400 pesos, para chequear esto, creo la variable esperado que contiene
-    ///el valor de la categoria elegida - la bonificacion, luego utilizo el previamente mencionado vec de pagos totales y compruebo que
-    ///el monto del ultimo pago emitido sea igual a lo que esperaba. Por otra parte tambien testeo que no se le de un bonificado a los que no pagan
-    ///para eso cree el socio fran basterrechea, el cual no paga nunca durante todo el test. Luego de testear el ultimo pago de nicolas beiserman
-    ///compruebo que estos pagos se le hayan atribuido a la cuenta de fran basterrechea, comprobando su len(debe ser igual a 4) y luego
-    ///tomo su ultimo pago y compruebo de la misma manera que hice con nicolas beiserman, el monto a pagar(en este caso sera el monto de su categoria sin descuentos)
     #[ink::test]
-    fn emitir_pago_mensual_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn verificar_permisos_staff_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44987654, "nicolas beiserman".to_string(),3, None);
-        club1.registrar_socio(41943654, "fran basterrechea".to_string(),1, None);
-        let treintadias = 30.from_dias();
-        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
-        club1.realizar_pago(44987654, 5000);
-        for i in 1..3{
-            em += treintadias;
-            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
-            club1.emitir_pago_mensual();
-            club1.realizar_pago(44987654, 5000);
-        }
-        em += treintadias;
-        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
-        club1.emitir_pago_mensual();
-        club1.realizar_pago(44987654, 4600);
-        let v1 = club1.get_pagos_totales(Some(44987654));
-        assert_eq!(v1.len(),4);
-        let ultimo_pago = &v1[v1.len()-1];
-        let esperado = 5000-400;
-        assert_eq!(ultimo_pago.monto,esperado);
-        let v1 = club1.get_pagos_totales(Some(41943654));
-        assert_eq!(v1.len(),4);
-        let ultimo_pago = &v1[v1.len()-1];
-        let esperado = 1000;
-        assert_eq!(ultimo_pago.monto,esperado);
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //agrego un staff y lo seteo como caller
+        let staff = AccountId::from([0x2; 32]);
+        club.agregar_staff(staff);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+
+        assert_eq! (club.verificar_permisos(), true, "Debio ser verdadero");
     }
+
     #[ink::test]
-    #[should_panic]
-    fn emitir_pago_mensual_caso_aun_no_se_han_emitido_pagos_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn verificar_permisos_no_permitido_con_politica_desactivada_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.emitir_pago_mensual();
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //desactivo la politica
+        club.toggle_politica_autorizacion();
+
+        //cambio al owner por un no permitido, y verifico
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+
+        assert_eq! (club.verificar_permisos(), true, "Debio ser verdadero, ya que desactive la politica");
     }
-   ///para este test creo un clubsemrust, registro un socio y hago que solo pasen 10 dias
-   ///en el timestamp, con lo cual, luego realizo el emitir_pago_mensual y el programa
-   ///panickea ya que aun no han pasado los 30 dias correspondientes
+
     #[ink::test]
-    #[should_panic]
-    fn emitir_pago_mensual_caso_aun_no_han_pasado_30_dias_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn verificar_permisos_no_permitido_con_politica_activada_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44987654, "nicolas beiserman".to_string(),3, None);
-        let diezdias = 10.from_dias();
-        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
-        em += diezdias;
-        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
-        club1.emitir_pago_mensual();
+
+        let club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //cambio al owner por un no permitido, y verifico
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+
+        assert_eq! (club.verificar_permisos(), false, "Debio ser falso, ya que la politica está activada");
     }
-   ///para este test, creo el clubsemrust, registro un socio, hago que pasen 30 dias en
-   ///el timestamp y luego cambio el caller a un accoundId diferente del owner
-   ///intento emitir el pago mensual y el programa panickea 
+
     #[ink::test]
-    #[should_panic]
-    fn emitir_pago_mensual_caso_not_owner_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn actualizar_pagos_consecutivos_para_descuento_owner_correcto_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44987654, "nicolas beiserman".to_string(),3, None);
-        let treintadias = 30.from_dias();
-        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
-        em += treintadias;
-        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
-        let not_owner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
-        club1.emitir_pago_mensual();
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //agrego un staff
+        club.agregar_staff(AccountId::from([0x2; 32]));
+
+        //llamo a la actualizacion como owner, y verifico
+        club.actualizar_pagos_consecutivos_para_descuento(2);
+
+        assert_eq!(club.costos.pagos_consecutivos_para_beneficio, 2, "Debió actualizarse, ya que se llamo al metodo con el owner");
     }
-   ///para este test se creo el mismo escenario que el caso anterior pero antes de cambiar el
-   ///caller, se desactivo la politica de autorizacion, con lo cual el pago se deberia emitir
-   ///correctamente. Por ulitmo, para comprobar que el pago se emitio, compruebo la longitud
-   ///del vec de pagos, este, deberia tener 2 elementos(el pago del registrar socio y el emitido)
+
     #[ink::test]
-    fn emitir_pago_mensual_caso_not_owner_politica_desactivada_test(){
-        let mut club1 = ClubSemRust::new(1000, 2000, 5000, 400, 3);
-        let owner = AccountId::from([0x1;32]);
+    fn actualizar_pagos_consecutivos_para_descuento_staff_correcto_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-        club1.registrar_socio(44987654, "nicolas beiserman".to_string(),3, None);
-        let treintadias = 30.from_dias();
-        let mut em:u64 = club1.emision_ultimo_pago.unwrap();
-        em += treintadias;
-        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(em);
-        club1.toggle_politica_autorizacion();
-        let not_owner = AccountId::from([0x2;32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
-        club1.emitir_pago_mensual();
-        let v1 = club1.pagos;
-        assert_eq!(v1.len(),2);
-    }
 
-    //test CategoriasSocios
-    #[ink::test]
-    fn categoria_from_id_correctas_test() {
-        //creo las categroias existentes, y la nula
-        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
-        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let cat_c = CategoriasSocios::categoria_from_id(&3).unwrap();
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
 
-        //testeo
-        assert_eq! (cat_a, CategoriasSocios::A, "Debio ser categoria A");
-        assert_eq! (cat_b, CategoriasSocios::B, "Debio ser categoria B");
-        assert_eq! (cat_c, CategoriasSocios::C, "Debio ser categoria C");
+        //agrego un staff
+        club.agregar_staff(AccountId::from([0x2; 32]));
+
+        //modifico el caller, para llamar como staff, y verifico
+        let staff = AccountId::from([0x2; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+
+        club.actualizar_pagos_consecutivos_para_descuento(2);
+        assert_eq!(club.costos.pagos_consecutivos_para_beneficio, 2, "Debió actualizarse, ya que se llamo al metodo con un staff");
     }
 
     #[ink::test]
     #[should_panic]
-    fn categoria_from_id_incorrecta_test() {
-        //pido una categoria inexistente, esperando el panic
-        let cat = CategoriasSocios::categoria_from_id(&4);
-    }
+    fn actualizar_pagos_consecutivos_para_descuento_sin_permisos_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-    //tests Socio
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //cambio el caller por uno no permitido, y realizo el llamado, esperando que paniquee
+        let no_permitido = AccountId::from([0x2; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
+
+        club.actualizar_pagos_consecutivos_para_descuento(3);
+    }
 
     #[ink::test]
-    fn new_socio_cat_a_y_c_test() {
-        //creo los datos de un socio categoria c
-        let ape_y_nom_c = "Michael Knight".to_string();
-        let dni_c = 35198260;
-        let cat_c = CategoriasSocios::categoria_from_id(&3).unwrap();
-        let deporte_c = None;
+    #[should_panic]
+    fn realizar_pago_llamado_sin_permisos_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        //creo al socio categoria c
-        let socio_c = Socio::new(ape_y_nom_c.clone(), dni_c, cat_c.clone(), deporte_c.clone());
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
 
-        //creo los datos de un socio categoria a
-        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
-        let dni_a = 31195032;
-        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
-        let deporte_a = None;
+        //cambio el caller por uno no permitido, y realizo el llamado, esperando que paniquee
+        let no_permitido = AccountId::from([0x2; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
 
-        //creo al socio categoria a
-        let socio_a = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+        club.realizar_pago(30, 5000);
+    }
 
-        //testeo
-        assert_eq!(ape_y_nom_c, socio_c.apellido_y_nombre, "Deberia ser el mismo nombre en socio_c");
-        assert_eq!(dni_c, socio_c.dni, "Deberia ser el mismo nombre en socio_c");
-        assert_eq!(cat_c, socio_c.categoria, "Deberia ser la misma categoria en socio_c");
-        assert_eq!(deporte_c, socio_c.deporte, "Deberia ser el mismo deporte en socio_c");
+    #[ink::test]
+    #[should_panic]
+    fn realizar_pago_socio_inexistente_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        assert_eq!(ape_y_nom_a, socio_a.apellido_y_nombre, "Deberia ser el mismo nombre en socio_a");
-        assert_eq!(dni_a, socio_a.dni, "Deberia ser el mismo nombre en socio_a");
-        assert_eq!(cat_a, socio_a.categoria, "Deberia ser la misma categoria en socio_a");
-        assert_eq!(deporte_a, socio_a.deporte, "Deberia ser el mismo deporte en socio_a");
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //llamo a realizar un pago de un socio inexiste
+        club.realizar_pago(30, 5000);
     }
 
     #[ink::test]
-    fn new_socio_cat_b_test() {
-        //creo los datos de un socio categoria b
+    fn realizar_pago_correcto_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //registro un socio
         let ape_y_nom = "David Starsky".to_string();
         let dni = 30537974;
-        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte = Deporte::deporte_from_id(&Some(2));
+        club.registrar_socio(dni, ape_y_nom, 2, Some(2));
 
-        //creo al socio categoria b
-        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+        //realizo el pago, del pago pendiente recien creado
+        let pago = club.realizar_pago(dni, 3000);
 
-        //testeo
-        assert_eq!(ape_y_nom, socio.apellido_y_nombre, "Deberia ser el mismo nombre en socio");
-        assert_eq!(dni, socio.dni, "Deberia ser el mismo nombre en socio");
-        assert_eq!(cat, socio.categoria, "Deberia ser la misma categoria en socio");
-        assert_eq!(deporte, socio.deporte, "Deberia ser el mismo deporte en socio");
+        //comparo el pago obetnido, con los datos esperados (menos la fecha de vencimiento)
+        assert_eq! (pago.bonificado, false, "No debio ser bonificado, ya que es el primer pago realizado por el socio");
+        assert_eq! (pago.dni, dni, "Debio ser el mismo DNI");
+        assert_eq! (pago.monto, 3000, "Debio ser el mismo monto");
+        assert_ne! (pago.fecha_pago, None, "No debio ser None, ya que deberia contener una fecha de pago");
     }
 
     #[ink::test]
-    fn get_apellido_y_nombre_test() {
-        //creo un socio
+    #[should_panic]
+    fn realizar_pago_sin_permisos_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+
+        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //registro un socio
         let ape_y_nom = "David Starsky".to_string();
         let dni = 30537974;
-        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte = Deporte::deporte_from_id(&Some(2));
-        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+        club.registrar_socio(dni, ape_y_nom, 2, Some(2));
 
-        assert_eq!(socio.get_apellido_y_nombre(), ape_y_nom, "Debio ser el mismo nombre");
+        //realizo el pago, del pago pendiente recien creado
+        club.realizar_pago(dni, 3000);
+
+        //realizo otro pago, esperando el panic, ya que no hay pagos pendientes
+        club.realizar_pago(dni, 3000);
     }
 
     #[ink::test]
-    fn get_dni_test() {
-        //creo un socio
-        let ape_y_nom = "David Starsky".to_string();
-        let dni = 30537974;
-        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte = Deporte::deporte_from_id(&Some(2));
-        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+    #[should_panic] 
+    fn consulta_de_pagos_sin_permisos_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        assert_eq!(socio.get_dni(), dni, "Debio ser el mismo DNI");
+        let club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //cambio el caller por un no permitido
+        let no_permitido = AccountId::from([0x2; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
+
+        //llamo al metodo esperando el panic
+        club.consulta_de_pagos(None);
     }
 
     #[ink::test]
-    fn get_categoria_test() {
-        //creo un socio
-        let ape_y_nom = "David Starsky".to_string();
-        let dni = 30537974;
-        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte = Deporte::deporte_from_id(&Some(2));
-        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+    #[should_panic]
+    fn consulta_de_pagos_dni_no_existente_test() {
+        //seteo un caller y creo un club
+        let owner = AccountId::from([0x1; 32]);
+        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        assert_eq!(socio.get_categoria(), cat, "Debio ser la misma categoria");
+        let club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+
+        //llamo al metodo esperando el panic
+        club.consulta_de_pagos(Some(30));
     }
 
     #[ink::test]
-    fn get_deporte_cat_test() {
+    fn consulta_de_pagos_dni_existente_test() {
+        //creo el club "a mano"
+
+        //creo el vec de pagos
+        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
+        let pago2 = Pago::new(30, 3000, 30, Some(15), false);
+        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
+        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
+        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
+        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
+
+        let mut pagos = Vec::new();
+
+        pagos.push(pago1);
+        pagos.push(pago2);
+        pagos.push(pago3);
+        pagos.push(pago4);
+        pagos.push(pago5);
+        pagos.push(pago6);
+
+        //creo a los socios
         //creo un socio categoria b
-        let ape_y_nom = "David Starsky".to_string();
-        let dni = 30537974;
-        let cat = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte = Deporte::deporte_from_id(&Some(2));
-        let socio = Socio::new(ape_y_nom.clone(), dni, cat.clone(), deporte.clone());
+        let ape_y_nom_b = "David Starsky".to_string();
+        let dni_b = 30;
+        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte_b = Deporte::deporte_from_id(&Some(2));
+        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
 
-        assert_eq!(socio.get_deporte(), deporte, "Debio ser el mismo deporte");
-    }
+        //creo un socio categoria a
+        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
+        let dni_a = 32;
+        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
+        let deporte_a = None;
+        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
 
-    //tests club
+        //los agrego
+        let mut socios = Vec::new();
 
-    #[ink::test]
-    fn set_owner_correcto_test() {
-        //seteo un caller
+        socios.push(starsky.clone());
+        socios.push(hutch.clone());
+
+        //creo los costos
+        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 3);
+
+        //creo al owner y lo seteo como el caller
         let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        //creo un club, y cambio su owner
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
-        let nuevo_owner = AccountId::from([0x2; 32]);
-        club.set_owner(nuevo_owner);  
+        //creo los permitidos
+        let permitidos = Vec::new();
 
-        //compruebo
-        assert_eq!(club.owner, nuevo_owner, "Debio haber cambiado el owner, ya que el llamado lo realiza el owner");
+        //creo la politica de autorizacion
+        let politica_activada = true;
+
+        //creo la ultima emision
+        let emision_ultimo_pago = None;
+
+        //creo al club
+        let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+
+        //comienzo a crear el vec de tuplas esperado
+        //creo el vec de los pagos
+        let mut vec_montos1: Vec<u128> = Vec::new();
+        for _i in 0..3 {
+            vec_montos1.push(3000);
+        }
+
+        //creo la tupla esperada
+        let tupla1: (Socio, Vec<u128>) = (starsky, vec_montos1);
+
+        //creo el vec de tuplas que quiero obtener
+        let mut vec_tuplas_esperado = Vec::new();
+        vec_tuplas_esperado.push(tupla1);
+
+        //obtengo la tupla de consulta_de_pagos
+        let vec_tuplas_obtenido = club.consulta_de_pagos(Some(dni_b));
+
+        //comparo lo obtenido y esperado
+        assert_eq! (vec_tuplas_obtenido, vec_tuplas_esperado, "Debio ser la misma tupla");  
     }
 
-    #[ink::test]
-    #[should_panic]
-    fn set_owner_incorrecto_test() {
-        //seteo un caller
+    #[ink::test]
+    fn consulta_de_pagos_sin_dni_test() {
+        //creo el club "a mano"
+
+        //creo el vec de pagos
+        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
+        let pago2 = Pago::new(30, 3000, 30, Some(15), false);
+        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
+        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
+        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
+        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
+
+        let mut pagos = Vec::new();
+
+        pagos.push(pago1);
+        pagos.push(pago2);
+        pagos.push(pago3);
+        pagos.push(pago4);
+        pagos.push(pago5);
+        pagos.push(pago6);
+
+        //creo a los socios
+        //creo un socio categoria b
+        let ape_y_nom_b = "David Starsky".to_string();
+        let dni_b = 30;
+        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte_b = Deporte::deporte_from_id(&Some(2));
+        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
+
+        //creo un socio categoria a
+        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
+        let dni_a = 32;
+        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
+        let deporte_a = None;
+        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+
+        //los agrego
+        let mut socios = Vec::new();
+
+        socios.push(starsky.clone());
+        socios.push(hutch.clone());
+
+        //creo los costos
+        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 3);
+
+        //creo al owner y lo seteo como el caller
         let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        //creo un club, cambio al caller, y llamo a set_owner, esperando el panic 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
-
-        let no_permitido = AccountId::from([0x2; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
+        //creo los permitidos
+        let permitidos = Vec::new();
 
-        club.set_owner(no_permitido);
-    }
+        //creo la politica de autorizacion
+        let politica_activada = true;
 
-    #[ink::test]
-    fn agregar_staff_correcto_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        //creo la ultima emision
+        let emision_ultimo_pago = None;
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo el club
+        let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
 
-        //agrego un staff y verifico
-        assert_eq!(club.agregar_staff(AccountId::from([0x2; 32])), true, "Debio agregar al staff sin problemas");
-    }
+        //comienzo a crear el vec de tuplas esperado 
+        //creo el vec de los pagos de ambos socios
+        let mut vec_montos_hutch: Vec<u128> = Vec::new();
+        let mut vec_montos_starsky: Vec<u128> = Vec::new();
+        for _i in 0..3 {
+            vec_montos_starsky.push(3000);
+            vec_montos_hutch.push(5000);
+        }
 
-    #[ink::test]
-    #[should_panic]
-    fn agregar_staff_existente_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        //creo las tupla esperadas
+        let tupla1 = (starsky, vec_montos_starsky);
+        let tupla2 = (hutch, vec_montos_hutch);
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo el vec de tuplas que quiero obtener
+        let mut vec_tuplas_esperado = Vec::new();
+        vec_tuplas_esperado.push(tupla1);
+        vec_tuplas_esperado.push(tupla2);
 
-        //agrego un staff
-        let staff = AccountId::from([0x2; 32]);
-        club.agregar_staff(staff);
+        //obtengo la tupla de consulta_de_pagos
+        let vec_tuplas_obtenido = club.consulta_de_pagos(None);
 
-        //intento agregar el mismo, esperando el panic
-        club.agregar_staff(staff);
+        //comparo lo obtenido y esperado
+        assert_eq! (vec_tuplas_obtenido, vec_tuplas_esperado, "Debieron ser las mismas tuplas");  
     }
 
     #[ink::test]
-    #[should_panic]
-    fn agregar_staff_incorrecto_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+    fn corroborar_bonificacion_sin_pagos_suficientes_test() {
+        //creo el club "a mano"
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo el vec de pagos
+        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
+        let pago2 = Pago::new(30, 3000, 30, Some(15), false);
+        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
+        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
+        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
+        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
 
-        //cambio el caller, del owner a un posible staff, y llamo a agregar_staff, esperando el panic
-        let staff = AccountId::from([0x2; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+        let mut pagos = Vec::new();
 
-        club.agregar_staff(staff);
-    }        
+        pagos.push(pago1);
+        pagos.push(pago2);
+        pagos.push(pago3);
+        pagos.push(pago4);
+        pagos.push(pago5);
+        pagos.push(pago6);
 
-    #[ink::test]
-    fn es_staff_correcto_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        //creo a los socios
+        //creo un socio categoria b
+        let ape_y_nom_b = "David Starsky".to_string();
+        let dni_b = 30;
+        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte_b = Deporte::deporte_from_id(&Some(2));
+        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo un socio categoria a
+        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
+        let dni_a = 32;
+        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
+        let deporte_a = None;
+        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
 
-        //creo y agrego un staff
-        let staff = AccountId::from([0x2; 32]);
-        club.agregar_staff(staff);
+        //los agrego
+        let mut socios = Vec::new();
 
-        //seteo el staff agregado como caller, para realizar la comparacion con el metodo es_staff
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+        socios.push(starsky.clone());
+        socios.push(hutch.clone());
 
-        assert_eq! (club.es_staff(), true, "Debio ser true, ya que el staff se ha agregado");
-    }
+        //creo los costos 
+        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 4);
 
-    #[ink::test]
-    fn es_staff_incorrecto_test() {
-        //seteo un caller y creo un club
+        //creo al owner y lo seteo como el caller
         let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo los permitidos
+        let permitidos = Vec::new();
 
-        //agrego un staff
-        club.agregar_staff(AccountId::from([0x2; 32]));
+        //creo la politica de autorizacion
+        let politica_activada = true;
 
-        //llamo a es_staff con el owner
-        assert_eq! (club.es_staff(), false, "Debio ser false, ya que el caller no es staff");
+        //creo la ultima emision
+        let emision_ultimo_pago = None;
+
+        //creo el club
+        let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+
+        //llamo al metodo de corroborar_bonificacion, sabiendo que la cantidad de pagos necesarios
+        //para el beneficio, no alcanza aún
+
+        assert_eq! (club.corroborar_bonificacion(30), false, "No debió otorgar el beneficio");
     }
 
     #[ink::test]
-    fn verificar_permisos_owner_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+    fn corroborar_bonificacion_con_pagos_suficientes_test() {
+        //creo el club "a mano"
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo el vec de pagos
+        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
+        let pago2 = Pago::new(30, 3000, 30, Some(15), false);
+        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
+        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
+        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
+        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
 
-        //agrego un staff
-        club.agregar_staff(AccountId::from([0x2; 32]));
+        let mut pagos = Vec::new();
 
-        assert_eq! (club.verificar_permisos(), true, "Debio ser verdadero");
-    }
+        pagos.push(pago1);
+        pagos.push(pago2);
+        pagos.push(pago3);
+        pagos.push(pago4);
+        pagos.push(pago5);
+        pagos.push(pago6);
 
-    #[ink::test]
-    fn verificar_permisos_staff_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        //creo a los socios
+        //creo un socio categoria b
+        let ape_y_nom_b = "David Starsky".to_string();
+        let dni_b = 30;
+        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte_b = Deporte::deporte_from_id(&Some(2));
+        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo un socio categoria a
+        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
+        let dni_a = 32;
+        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
+        let deporte_a = None;
+        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
 
-        //agrego un staff y lo seteo como caller
-        let staff = AccountId::from([0x2; 32]);
-        club.agregar_staff(staff);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+        //los agrego
+        let mut socios = Vec::new();
 
-        assert_eq! (club.verificar_permisos(), true, "Debio ser verdadero");
-    }
+        socios.push(starsky.clone());
+        socios.push(hutch.clone());
 
-    #[ink::test]
-    fn verificar_permisos_no_permitido_con_politica_desactivada_test() {
-        //seteo un caller y creo un club
+        //creo los costos 
+        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 2);
+
+        //creo al owner y lo seteo como el caller
         let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo los permitidos
+        let permitidos = Vec::new();
 
-        //desactivo la politica
-        club.toggle_politica_autorizacion();
+        //creo la politica de autorizacion
+        let politica_activada = true;
 
-        //cambio al owner por un no permitido, y verifico
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+        //creo la ultima emision
+        let emision_ultimo_pago = None;
 
-        assert_eq! (club.verificar_permisos(), true, "Debio ser verdadero, ya que desactive la politica");
+        //creo el club
+        let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+
+        //llamo al metodo de corroborar_bonificacion, sabiendo que la cantidad de pagos necesarios
+        //para el beneficio, alcanza
+
+        assert_eq! (club.corroborar_bonificacion(30), true, "Debió otorgar el beneficio");
     }
 
     #[ink::test]
-    fn verificar_permisos_no_permitido_con_politica_activada_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+    fn corroborar_bonificacion_con_pago_pendiente_test() {
+        //creo el club "a mano"
 
-        let club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo el vec de pagos
+        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
+        let pago2 = Pago::new(30, 3000, 30, None, false);
+        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
+        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
+        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
+        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
 
-        //cambio al owner por un no permitido, y verifico
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+        let mut pagos = Vec::new();
 
-        assert_eq! (club.verificar_permisos(), false, "Debio ser falso, ya que la politica está activada");
-    }
+        pagos.push(pago1);
+        pagos.push(pago2);
+        pagos.push(pago3);
+        pagos.push(pago4);
+        pagos.push(pago5);
+        pagos.push(pago6);
 
-    #[ink::test]
-    fn actualizar_pagos_consecutivos_para_descuento_owner_correcto_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        //creo a los socios
+        //creo un socio categoria b
+        let ape_y_nom_b = "David Starsky".to_string();
+        let dni_b = 30;
+        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte_b = Deporte::deporte_from_id(&Some(2));
+        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo un socio categoria a
+        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
+        let dni_a = 32;
+        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
+        let deporte_a = None;
+        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
 
-        //agrego un staff
-        club.agregar_staff(AccountId::from([0x2; 32]));
+        //los agrego
+        let mut socios = Vec::new();
 
-        //llamo a la actualizacion como owner, y verifico
-        club.actualizar_pagos_consecutivos_para_descuento(2);
+        socios.push(starsky.clone());
+        socios.push(hutch.clone());
 
-        assert_eq!(club.costos.pagos_consecutivos_para_beneficio, 2, "Debió actualizarse, ya que se llamo al metodo con el owner");
-    }
+        //creo los costos 
+        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 2);
 
-    #[ink::test]
-    fn actualizar_pagos_consecutivos_para_descuento_staff_correcto_test() {
-        //seteo un caller y creo un club
+        //creo al owner y lo seteo como el caller
         let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo los permitidos
+        let permitidos = Vec::new();
 
-        //agrego un staff
-        club.agregar_staff(AccountId::from([0x2; 32]));
+        //creo la politica de autorizacion
+        let politica_activada = true;
 
-        //modifico el caller, para llamar como staff, y verifico
-        let staff = AccountId::from([0x2; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+        //creo la ultima emision
+        let emision_ultimo_pago = None;
 
-        club.actualizar_pagos_consecutivos_para_descuento(2);
-        assert_eq!(club.costos.pagos_consecutivos_para_beneficio, 2, "Debió actualizarse, ya que se llamo al metodo con un staff");
+        //creo el club
+        let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+
+        //llamo al metodo de corroborar_bonificacion, sabiendo que la cantidad de pagos necesarios
+        //para el beneficio, alcanza
+
+        assert_eq! (club.corroborar_bonificacion(30), false, "No debió otorgar el beneficio");
     }
 
     #[ink::test]
-    #[should_panic]
-    fn actualizar_pagos_consecutivos_para_descuento_sin_permisos_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+    fn corroborar_bonificacion_con_pago_vencido_test() {
+        //creo el club "a mano"
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo el vec de pagos
+        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
+        let pago2 = Pago::new(30, 3000, 30, Some(31), false);
+        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
+        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
+        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
+        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
 
-        //cambio el caller por uno no permitido, y realizo el llamado, esperando que paniquee
-        let no_permitido = AccountId::from([0x2; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
+        let mut pagos = Vec::new();
 
-        club.actualizar_pagos_consecutivos_para_descuento(3);
-    }
+        pagos.push(pago1);
+        pagos.push(pago2);
+        pagos.push(pago3);
+        pagos.push(pago4);
+        pagos.push(pago5);
+        pagos.push(pago6);
 
-    #[ink::test]
-    #[should_panic]
-    fn realizar_pago_llamado_sin_permisos_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        //creo a los socios
+        //creo un socio categoria b
+        let ape_y_nom_b = "David Starsky".to_string();
+        let dni_b = 30;
+        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
+        let deporte_b = Deporte::deporte_from_id(&Some(2));
+        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo un socio categoria a
+        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
+        let dni_a = 32;
+        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
+        let deporte_a = None;
+        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
 
-        //cambio el caller por uno no permitido, y realizo el llamado, esperando que paniquee
-        let no_permitido = AccountId::from([0x2; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
+        //los agrego
+        let mut socios = Vec::new();
 
-        club.realizar_pago(30, 5000);
-    }
+        socios.push(starsky.clone());
+        socios.push(hutch.clone());
 
-    #[ink::test]
-    #[should_panic]
-    fn realizar_pago_socio_inexistente_test() {
-        //seteo un caller y creo un club
+        //creo los costos 
+        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 2);
+
+        //creo al owner y lo seteo como el caller
         let owner = AccountId::from([0x1; 32]);
         ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
-
-        //llamo a realizar un pago de un socio inexiste
-        club.realizar_pago(30, 5000);
-    }
+        //creo los permitidos
+        let permitidos = Vec::new();
 
-    #[ink::test]
-    fn realizar_pago_correcto_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+        //creo la politica de autorizacion
+        let politica_activada = true;
 
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+        //creo la ultima emision
+        let emision_ultimo_pago = None;
 
-        //registro un socio
-        let ape_y_nom = "David Starsky".to_string();
-        let dni = 30537974;
-        club.registrar_socio(dni, ape_y_nom, 2, Some(2));
+        //creo el club
+        let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
 
-        //realizo el pago, del pago pendiente recien creado
-        let pago = club.realizar_pago(dni, 3000);
+        //llamo al metodo de corroborar_bonificacion, sabiendo que la cantidad de pagos necesarios
+        //para el beneficio, alcanza
 
-        //comparo el pago obetnido, con los datos esperados (menos la fecha de vencimiento)
-        assert_eq! (pago.bonificado, false, "No debio ser bonificado, ya que es el primer pago realizado por el socio");
-        assert_eq! (pago.dni, dni, "Debio ser el mismo DNI");
-        assert_eq! (pago.monto, 3000, "Debio ser el mismo monto");
-        assert_ne! (pago.fecha_pago, None, "No debio ser None, ya que deberia contener una fecha de pago");
+        assert_eq! (club.corroborar_bonificacion(30), false, "No debió otorgar el beneficio");
     }
 
-    #[ink::test]
-    #[should_panic]
-    fn realizar_pago_sin_permisos_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-
-        let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+    
+   // test get_socios
+   #[ink::test]
+   fn get_socios_test(){
+       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol), compromiso : None};
+       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let mut socios : Vec<Socio> = Vec::new();
+       socios.push(s1);
+       socios.push(s2);
+       socios.push(s3);
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let club = club_armado(costos, socios.clone(), pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       let socios_devuelto = club.get_socios();
+       assert_eq!(socios_devuelto.clone(), socios.clone(), "Ocurrió un error al esperar el vector de socios {:#?}, se recibió {:#?}.", socios, socios_devuelto);
+   }
+   #[ink::test]
+   fn get_socios_vacio_test(){
+       let mut socios : Vec<Socio> = Vec::new();
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let club = club_armado(costos, socios.clone(), pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       let socios_devuelto = club.get_socios();
+       assert_eq!(socios_devuelto.clone(), socios.clone(), "Ocurrió un error al esperar el vector de socios vacio! Se recibió {:#?}", socios_devuelto);
+   }
+   #[ink::test]
+   #[should_panic]
+   fn get_socios_sin_permisos_test(){
+       let mut socios : Vec<Socio> = Vec::new();
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       let not_owner = AccountId::from([0x2; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       club.get_socios();
+   }
 
-        //registro un socio
-        let ape_y_nom = "David Starsky".to_string();
-        let dni = 30537974;
-        club.registrar_socio(dni, ape_y_nom, 2, Some(2));
+   // test toggle_politica_autorizacion
+   #[ink::test]
+   fn toggle_politica_autorizacion_test(){
+       let mut socios : Vec<Socio> = Vec::new();
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let mut club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       let valor_politica = club.get_politica_autorizacion();
+       assert_eq!(club.toggle_politica_autorizacion(), !valor_politica, "La función no cumplió su cometido!");
+   }
+   #[ink::test]
+   #[should_panic]
+   fn toggle_politica_autorizacion_sin_permisos_test(){
+       let mut socios : Vec<Socio> = Vec::new();
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       let not_owner = AccountId::from([0x2; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let mut club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       club.toggle_politica_autorizacion();
+   }
 
-        //realizo el pago, del pago pendiente recien creado
-        club.realizar_pago(dni, 3000);
+   // test es_admin
+   #[ink::test]
+   fn es_admin_es_test(){
+       let mut socios : Vec<Socio> = Vec::new();
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       assert_eq!(club.es_admin(), true, "El caller no es admin!");
+   }
+   #[ink::test]
+   fn es_admin_no_es_test(){
+       let mut socios : Vec<Socio> = Vec::new();
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       let not_owner = AccountId::from([0x2; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       assert_eq!(club.es_admin(), false, "El caller es admin!");
+   }
 
-        //realizo otro pago, esperando el panic, ya que no hay pagos pendientes
-        club.realizar_pago(dni, 3000);
-    }
+   // test actualizar_costo_beneficio
+   #[ink::test]
+   fn actualizar_costo_beneficio_test(){
+       let mut socios : Vec<Socio> = Vec::new();
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let mut club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       let nuevo_costo = 300;
+       assert_eq!(club.actualizar_costo_beneficio(nuevo_costo), true, "No se actualizó!")
+   }
+   #[ink::test]
+   #[should_panic]
+   fn actualizar_costo_beneficio_sin_permisos_test(){
+       let mut socios : Vec<Socio> = Vec::new();
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       let not_owner = AccountId::from([0x2; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let mut club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       let nuevo_costo = 300;
+       assert_eq!(club.actualizar_costo_beneficio(nuevo_costo), false, "Se actualizó el costo cuando no deberia!")
+   }
 
-    #[ink::test]
-    #[should_panic] 
-    fn consulta_de_pagos_sin_permisos_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+   // test existe_socio
+   #[ink::test]
+   fn existe_socio_test(){
+       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol), compromiso : None};
+       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let mut socios : Vec<Socio> = Vec::new();
+       socios.push(s1);
+       socios.push(s2);
+       socios.push(s3);
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       assert_eq!(club.existe_socio(222), true, "No se encontró el socio cuando el socio si existe!");
+   }
+   #[ink::test]
+   fn existe_socio_no_existe_test(){
+       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol), compromiso : None};
+       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let mut socios : Vec<Socio> = Vec::new();
+       socios.push(s1);
+       socios.push(s2);
+       socios.push(s3);
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       assert_eq!(club.existe_socio(7777), false, "Se encontró el socio cuando el socio no existe!");
+   }
+   
+   // test registrar_socio
+   #[ink::test]
+   #[should_panic]
+   fn registrar_socio_ya_existe_test(){
+       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol), compromiso : None};
+       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let mut socios : Vec<Socio> = Vec::new();
+       socios.push(s1);
+       socios.push(s2);
+       socios.push(s3);
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let mut club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       let resultado = club.registrar_socio(222, "Giordano Luca".to_string(), 2, Some(3));
+   }
+   #[ink::test]
+   #[should_panic]
+   fn registrar_socio_ya_existe_deporte_incorrecto_test(){
+       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol), compromiso : None};
+       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let mut socios : Vec<Socio> = Vec::new();
+       socios.push(s1);
+       socios.push(s2);
+       socios.push(s3);
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let mut club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       let resultado = club.registrar_socio(555, "Cerati Gustavo".to_string(), 2, Some(11));
+   }
+   #[ink::test]
+   #[should_panic]
+   fn registrar_socio_ya_existe_categoria_incorrecta_test(){
+       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol), compromiso : None};
+       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+       let mut socios : Vec<Socio> = Vec::new();
+       socios.push(s1);
+       socios.push(s2);
+       socios.push(s3);
+       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+       let pagos : Vec<Pago>= Vec::new();
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let permitidos : Vec<AccountId> = Vec::new();
+       let politica_activada = true;
+       let emision_ultimo_pago = None;
+       let mut club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago);
+       let resultado = club.registrar_socio(555, "Cerati Gustavo".to_string(), 45, Some(3));
+   }
+   #[ink::test]
+   #[should_panic]
+   fn registrar_socio_sin_permisos_test() {
+    let s1 = Socio{apellido_y_nombre : "David Starsky".to_string(), dni : 537, categoria : CategoriasSocios::B, deporte : Some(Deporte::Basquet), compromiso : None};
+    let s2 = Socio{apellido_y_nombre : "Kenneth Hutchinson".to_string(), dni : 540, categoria : CategoriasSocios::B, deporte : Some(Deporte::Basquet), compromiso : None};
+    let s3 = Socio{apellido_y_nombre : "Huggy Bear".to_string(), dni : 440, categoria : CategoriasSocios::A, deporte : None, compromiso : None};
+    let mut socios : Vec<Socio> = Vec::new();
+    socios.push(s1);
+    socios.push(s2);
+    socios.push(s3);
+    let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
+    let pagos : Vec<Pago>= Vec::new();
+    let owner = AccountId::from([0x1; 32]);
+    ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+    let permitidos : Vec<AccountId> = Vec::new();
+    let politica_activada = true;
+    let emision_ultimo_pago = None;
+    let mut club = club_armado(costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago); 
+    let no_permitido = AccountId::from([0x2; 32]);
+    ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
+    let resultado = club.registrar_socio(982, "Michael Knight".to_string(), 1, None);
+   }
+   //test ConversionFechas
+   #[ink::test]
+   fn conversion_fechas_test(){
+       assert_eq!(0.from_segundos(), 0);
+       assert_eq!(1.from_segundos(), 1000);
+       assert_eq!(10.from_segundos(), 10000);
+       assert_eq!(0.from_horas(), 0);
+       assert_eq!(1.from_horas(), 3600000);
+       assert_eq!(10.from_horas(), 36000000);
+       assert_eq!(0.from_dias(), 0);
+       assert_eq!(1.from_dias(), 86400000);
+       assert_eq!(10.from_dias(), 864000000);
+       assert_eq!(0.from_semanas(), 0);
+       assert_eq!(1.from_semanas(), 604800000);
+       assert_eq!(10.from_semanas(), 6048000000);
+       assert_eq!(0.from_meses(), 0);
+       assert_eq!(1.from_meses(), 2629743000);
+       assert_eq!(10.from_meses(), 26297430000);
+       assert_eq!(0.from_anios(), 0);
+       assert_eq!(1.from_anios(), 31556926000);
+       assert_eq!(10.from_anios(), 315569260000);
+   }
+   //tests deporte
+   #[ink::test]
+   fn deporte_from_id_test(){
+       assert_eq!(Deporte::deporte_from_id(&Some(1)), Some(Deporte::Futbol));
+       assert_eq!(Deporte::deporte_from_id(&Some(2)), Some(Deporte::Basquet));
+       assert_eq!(Deporte::deporte_from_id(&Some(3)), Some(Deporte::Rugby));
+       assert_eq!(Deporte::deporte_from_id(&Some(4)), Some(Deporte::Hockey));
+       assert_eq!(Deporte::deporte_from_id(&Some(5)), Some(Deporte::Natacion));
+       assert_eq!(Deporte::deporte_from_id(&Some(6)), Some(Deporte::Tenis));
+       assert_eq!(Deporte::deporte_from_id(&Some(7)), Some(Deporte::Paddle));
+       assert_eq!(Deporte::deporte_from_id(&Some(8)), Some(Deporte::Gimnasio));
+       assert_eq!(Deporte::deporte_from_id(&None), None);
+   }
 
-        let club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+   #[ink::test]
+   #[should_panic]
+   fn deporte_from_id_incorrecta_test(){
+       let dep = Deporte::deporte_from_id(&Some(10));
+   }
 
-        //cambio el caller por un no permitido
-        let no_permitido = AccountId::from([0x2; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
+   //tests proteccion de datos
+   ///para registrar un socio protegido, calculo los compromisos con una sal como lo haria el socio fuera de la cadena
+   ///y compruebo que el socio guardado no tenga apellido y nombre, que su dni sea el identificador derivado del
+   ///compromiso y que el pago pendiente quede vinculado a ese mismo identificador
+   #[ink::test]
+   fn registrar_socio_protegido_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let sal = [7u8; 32];
+       let compromiso_dni = CompromisoIdentidad::calcular_dni(30537974, &sal);
+       let compromiso_nombre = CompromisoIdentidad::calcular_apellido_y_nombre("David Starsky", &sal);
+       let pago = club.registrar_socio_protegido(compromiso_dni, compromiso_nombre, 2, Some(2)).unwrap();
+       let identificador = CompromisoIdentidad::new(compromiso_dni, compromiso_nombre).identificador();
+       let socio = club.get_socio(identificador).unwrap();
+       assert_eq!(socio.get_apellido_y_nombre(), String::new(), "No debio guardar el apellido y nombre");
+       assert_ne!(socio.get_dni(), 30537974, "No debio guardar el DNI en texto plano");
+       assert_eq!(pago.get_dni_socio(), socio.get_dni(), "El pago debio quedar vinculado al identificador del socio");
+       assert!(club.realizar_pago(identificador, 3000).get_fecha_pago().is_some(), "Debio poder pagarse con el identificador");
+   }
 
-        //llamo al metodo esperando el panic
-        club.consulta_de_pagos(None);
-    }
+   ///compruebo que el identificador de un socio protegido no pueda coincidir con el DNI de un socio en texto plano
+   #[ink::test]
+   fn identificador_protegido_separado_de_dni_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "Huggy Bear".to_string(), 1, None);
+       club.toggle_proteccion_datos();
+       let sal = [7u8; 32];
+       let compromiso_dni = CompromisoIdentidad::calcular_dni(30537974, &sal);
+       let compromiso_nombre = CompromisoIdentidad::calcular_apellido_y_nombre("David Starsky", &sal);
+       club.registrar_socio_protegido(compromiso_dni, compromiso_nombre, 2, Some(2));
+       let identificador = CompromisoIdentidad::new(compromiso_dni, compromiso_nombre).identificador();
+       assert_ne!(identificador & MARCA_IDENTIFICADOR_PROTEGIDO, 0, "El identificador debio tener encendida la marca");
+       assert_eq!(club.get_socios().len(), 2, "Ambos socios debieron quedar registrados");
+   }
 
-    #[ink::test]
-    #[should_panic]
-    fn consulta_de_pagos_dni_no_existente_test() {
-        //seteo un caller y creo un club
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+   ///dos compromisos distintos con el mismo identificador derivado quedan registrados, el segundo con el siguiente libre
+   #[ink::test]
+   fn registrar_socio_protegido_identificador_en_uso_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let mut bytes = [0xffu8; 32];
+       let primero = club.registrar_socio_protegido(Hash::from(bytes), Hash::from([1u8; 32]), 1, None).unwrap();
+       bytes[31] = 0;
+       let segundo = club.registrar_socio_protegido(Hash::from(bytes), Hash::from([2u8; 32]), 1, None).unwrap();
+       assert_eq!(primero.get_dni_socio(), u32::MAX);
+       assert_eq!(segundo.get_dni_socio(), MARCA_IDENTIFICADOR_PROTEGIDO, "Debio tomar el siguiente identificador libre");
+       assert_eq!(club.get_socios().len(), 2);
+   }
 
-        let club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+   #[ink::test]
+   #[should_panic(expected = "Ese socio existe!")]
+   fn registrar_socio_protegido_compromiso_repetido_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio_protegido(Hash::from([3u8; 32]), Hash::from([1u8; 32]), 1, None);
+       club.registrar_socio_protegido(Hash::from([3u8; 32]), Hash::from([2u8; 32]), 1, None);
+   }
 
-        //llamo al metodo esperando el panic
-        club.consulta_de_pagos(Some(30));
-    }
+   #[ink::test]
+   #[should_panic(expected = "El DNI ingresado no es valido!")]
+   fn registrar_socio_dni_con_marca_protegida_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974 | MARCA_IDENTIFICADOR_PROTEGIDO, "David Starsky".to_string(), 1, None);
+   }
 
-    #[ink::test]
-    fn consulta_de_pagos_dni_existente_test() {
-        //creo el club "a mano"
+   ///compruebo que con el dni y la sal correctos se encuentre al socio, y que con otra sal no
+   #[ink::test]
+   fn get_socio_protegido_y_verificar_membresia_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let sal = [7u8; 32];
+       let compromiso_dni = CompromisoIdentidad::calcular_dni(30537974, &sal);
+       let compromiso_nombre = CompromisoIdentidad::calcular_apellido_y_nombre("David Starsky", &sal);
+       club.registrar_socio_protegido(compromiso_dni, compromiso_nombre, 1, None);
+
+       //cambio el caller por un tercero sin permisos, que solo conoce el dni y la sal
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       let socio = club.get_socio_protegido(30537974, sal).unwrap();
+       assert_eq!(socio.get_compromiso().unwrap().get_dni(), compromiso_dni, "Debio ser el mismo compromiso");
+       assert_eq!(club.verificar_membresia(30537974, sal), true, "Debio verificar la membresia");
+       assert_eq!(club.verificar_membresia(30537974, [8u8; 32]), false, "Con otra sal no debio verificar la membresia");
+       assert_eq!(club.verificar_membresia(30537975, sal), false, "Con otro dni no debio verificar la membresia");
+   }
 
-        //creo el vec de pagos
-        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
-        let pago2 = Pago::new(30, 3000, 30, Some(15), false);
-        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
-        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
-        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
-        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
+   #[ink::test]
+   #[should_panic]
+   fn registrar_socio_protegido_ya_existe_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let sal = [7u8; 32];
+       let compromiso_dni = CompromisoIdentidad::calcular_dni(30537974, &sal);
+       let compromiso_nombre = CompromisoIdentidad::calcular_apellido_y_nombre("David Starsky", &sal);
+       club.registrar_socio_protegido(compromiso_dni, compromiso_nombre, 1, None);
+       club.registrar_socio_protegido(compromiso_dni, compromiso_nombre, 3, None);
+   }
 
-        let mut pagos = Vec::new();
+   ///con la proteccion de datos activada, el registro con datos en texto plano debe rechazarse
+   #[ink::test]
+   #[should_panic]
+   fn registrar_socio_con_proteccion_datos_activada_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       assert_eq!(club.toggle_proteccion_datos(), true);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+   }
 
-        pagos.push(pago1);
-        pagos.push(pago2);
-        pagos.push(pago3);
-        pagos.push(pago4);
-        pagos.push(pago5);
-        pagos.push(pago6);
+   #[ink::test]
+   #[should_panic]
+   fn toggle_proteccion_datos_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.toggle_proteccion_datos();
+   }
 
-        //creo a los socios
-        //creo un socio categoria b
-        let ape_y_nom_b = "David Starsky".to_string();
-        let dni_b = 30;
-        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte_b = Deporte::deporte_from_id(&Some(2));
-        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
+   //tests recibos
+   ///registro dos socios y realizo sus pagos, compruebo que los recibos tengan numeros correlativos,
+   ///los datos del pago, la cuenta que cobro y que se puedan consultar por numero, por socio y por fecha
+   #[ink::test]
+   fn realizar_pago_emite_recibo_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let staff = AccountId::from([0x2; 32]);
+       club.agregar_staff(staff);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 2, Some(2));
+       club.registrar_socio(31195032, "Kenneth Hutchinson".to_string(), 1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+       let pago = club.realizar_pago(30537974, 3000);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5000);
+       club.realizar_pago(31195032, 5000);
+
+       let recibo1 = club.get_recibo(1).unwrap();
+       assert_eq!(recibo1.dni, 30537974, "Debio ser el DNI del primer pago");
+       assert_eq!(recibo1.monto, 3000, "Debio ser el monto del primer pago");
+       assert_eq!(recibo1.periodo, pago.fecha_venci, "El periodo debio ser el vencimiento del pago");
+       assert_eq!(recibo1.fecha_pago, 1000, "Debio ser la fecha del pago");
+       assert_eq!(recibo1.cobrado_por, owner, "Debio cobrarlo el owner");
+       let recibo2 = club.get_recibo(2).unwrap();
+       assert_eq!(recibo2.cobrado_por, staff, "Debio cobrarlo el staff");
+       assert_eq!(club.get_recibo(3), None, "No debio existir un tercer recibo");
+
+       assert_eq!(club.get_recibos_socio(31195032), vec![recibo2.clone()]);
+       assert_eq!(club.get_recibos_por_fecha(0, 2000), vec![recibo1.clone()]);
+       assert_eq!(club.get_recibos_por_fecha(0, 5000).len(), 2);
+   }
 
-        //creo un socio categoria a
-        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
-        let dni_a = 32;
-        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
-        let deporte_a = None;
-        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+   ///compruebo que un recibo emitido se verifique, y que uno adulterado (aun con el hash recalculado) no
+   #[ink::test]
+   fn verificar_recibo_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       let recibo = club.get_recibo(1).unwrap();
+
+       //un tercero sin permisos puede verificar el recibo
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x3; 32]));
+       assert_eq!(club.verificar_recibo(recibo.clone()), true, "El recibo emitido debio ser genuino");
+
+       let mut adulterado = recibo.clone();
+       adulterado.monto = 1;
+       assert_eq!(club.verificar_recibo(adulterado.clone()), false, "Con el hash original no debio verificar");
+       let falso = Recibo::new(1, 30537974, 1, recibo.periodo, recibo.fecha_pago, owner, OrigenRecibo::Pago);
+       assert_eq!(club.verificar_recibo(falso), false, "Un recibo no emitido por el club no debio verificar");
+   }
 
-        //los agrego
-        let mut socios = Vec::new();
+   #[ink::test]
+   #[should_panic]
+   fn get_recibos_socio_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.get_recibos_socio(30537974);
+   }
 
-        socios.push(starsky.clone());
-        socios.push(hutch.clone());
+   ///si el numero de recibo llego al maximo, el cobro se rechaza en lugar de reiniciar la numeracion
+   #[ink::test]
+   #[should_panic(expected = "El resultado de la operacion excede el maximo representable!")]
+   fn emitir_recibo_numero_desbordado_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.ultimo_nro_recibo = u32::MAX;
+       club.realizar_pago(30537974, 5000);
+   }
 
-        //creo los costos
-        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 3);
+   //tests reversion de pagos
+   ///realizo un pago, lo revierto con reintegro y compruebo que el pago vuelva a estar pendiente,
+   ///que el recibo quede anulado y que el historial muestre el cobro, la reversion y el reintegro
+   #[ink::test]
+   fn revertir_pago_con_reintegro_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       let recibo = club.get_recibo(1).unwrap();
+
+       let pago = club.revertir_pago(1, "Se cobro al socio equivocado".to_string(), true);
+       assert_eq!(pago.get_fecha_pago(), None, "El pago debio volver a estar pendiente");
+       assert_eq!(club.get_recibo(1).unwrap().is_anulado(), true, "El recibo debio quedar anulado");
+       assert_eq!(club.verificar_recibo(recibo), false, "Un recibo anulado no debio verificar");
+
+       let historial = club.get_historial_pagos(Some(30537974));
+       assert_eq!(historial.len(), 3);
+       assert_eq!(historial[0].tipo, TipoMovimiento::Cobro);
+       assert_eq!(historial[1].tipo, TipoMovimiento::Reversion);
+       assert_eq!(historial[2].tipo, TipoMovimiento::Reintegro);
+       assert_eq!(historial[2].realizado_por, owner);
+       assert_eq!(historial[2].motivo, "Se cobro al socio equivocado".to_string());
+
+       //el pago pendiente puede volver a cobrarse, con un nuevo numero de recibo
+       club.realizar_pago(30537974, 5000);
+       assert_eq!(club.get_recibo(2).unwrap().is_anulado(), false);
+   }
 
-        //creo al owner y lo seteo como el caller
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+   #[ink::test]
+   #[should_panic]
+   fn revertir_pago_sin_motivo_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       club.revertir_pago(1, String::new(), false);
+   }
 
-        //creo los permitidos
-        let permitidos = Vec::new();
+   ///un staff puede revertir dentro de la ventana de reversion
+   #[ink::test]
+   fn revertir_pago_staff_dentro_de_ventana_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let staff = AccountId::from([0x2; 32]);
+       club.agregar_staff(staff);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(6.from_dias());
+       club.revertir_pago(1, "Monto equivocado".to_string(), false);
+       assert_eq!(club.get_historial_pagos(None).len(), 2);
+   }
 
-        //creo la politica de autorizacion
-        let politica_activada = true;
+   ///un staff no puede revertir fuera de la ventana de reversion
+   #[ink::test]
+   #[should_panic]
+   fn revertir_pago_staff_fuera_de_ventana_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let staff = AccountId::from([0x2; 32]);
+       club.agregar_staff(staff);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(8.from_dias());
+       club.revertir_pago(1, "Monto equivocado".to_string(), false);
+   }
 
-        //creo la ultima emision
-        let emision_ultimo_pago = None;
+   #[ink::test]
+   #[should_panic]
+   fn revertir_pago_recibo_anulado_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       club.revertir_pago(1, "Monto equivocado".to_string(), false);
+       club.revertir_pago(1, "Monto equivocado".to_string(), false);
+   }
 
-        //creo al club
-        let club = ClubSemRust {socios, pagos, costos, owner, permitidos, politica_activada, emision_ultimo_pago};
+   //tests auditoria
+   ///realizo distintas acciones administrativas y compruebo que queden registradas en la auditoria
+   ///con su actor, su numero de bloque y sus valores anteriores y nuevos
+   #[ink::test]
+   fn auditoria_registra_acciones_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let staff = AccountId::from([0x2; 32]);
+       club.agregar_staff(staff);
+       ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+       club.toggle_politica_autorizacion();
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(staff);
+       club.actualizar_costos_categoria(1, 5500);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+
+       let auditoria = club.get_auditoria(None, None);
+       assert_eq!(auditoria.len(), 4);
+       assert_eq!(auditoria[0].accion, AccionAdministrativa::AgregarStaff);
+       assert_eq!(auditoria[0].valor_nuevo, Some(ValorAuditado::Cuenta(staff)));
+       assert_eq!(auditoria[1].valor_anterior, Some(ValorAuditado::Booleano(true)));
+       assert_eq!(auditoria[1].valor_nuevo, Some(ValorAuditado::Booleano(false)));
+       assert_eq!(auditoria[1].bloque, auditoria[0].bloque + 1, "El toggle debio registrarse en el bloque siguiente");
+       assert_eq!(auditoria[2].actor, staff);
+       assert_eq!(auditoria[2].valor_anterior, Some(ValorAuditado::CostoCategoria(CategoriasSocios::A, 5000)));
+       assert_eq!(auditoria[2].valor_nuevo, Some(ValorAuditado::CostoCategoria(CategoriasSocios::A, 5500)));
+       assert_eq!(auditoria[3].valor_nuevo, Some(ValorAuditado::Dni(30537974)));
+
+       //filtro por actor y por tipo de accion
+       assert_eq!(club.get_auditoria(Some(staff), None).len(), 2);
+       assert_eq!(club.get_auditoria(Some(owner), Some(AccionAdministrativa::TogglePolitica)).len(), 1);
+       assert_eq!(club.get_auditoria(Some(staff), Some(AccionAdministrativa::TogglePolitica)).len(), 0);
+   }
 
-        //comienzo a crear el vec de tuplas esperado
-        //creo el vec de los pagos
-        let mut vec_montos1: Vec<u128> = Vec::new();
-        for _i in 0..3 {
-            vec_montos1.push(3000);
-        }
+   ///con una retencion de 2 entradas, solo se conservan las 2 ultimas, pero la numeracion continua
+   #[ink::test]
+   fn auditoria_retencion_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_retencion_auditoria(2);
+       club.actualizar_costo_beneficio(300);
+       club.actualizar_pagos_consecutivos_para_descuento(4);
+       club.toggle_politica_autorizacion();
+       let auditoria = club.get_auditoria(None, None);
+       assert_eq!(auditoria.len(), 2);
+       assert_eq!(auditoria[0].numero, 3);
+       assert_eq!(auditoria[0].accion, AccionAdministrativa::CambioPagosConsecutivos);
+       assert_eq!(auditoria[1].numero, 4);
+   }
 
-        //creo la tupla esperada
-        let tupla1: (Socio, Vec<u128>) = (starsky, vec_montos1);
+   #[ink::test]
+   #[should_panic]
+   fn get_auditoria_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.toggle_politica_autorizacion();
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.get_auditoria(None, None);
+   }
 
-        //creo el vec de tuplas que quiero obtener
-        let mut vec_tuplas_esperado = Vec::new();
-        vec_tuplas_esperado.push(tupla1);
+   //tests pausa de emergencia
+   ///arma un club con un staff, un guardian y un socio con un pago realizado y otro pendiente, y lo pausa como owner
+   fn club_pausado() -> ClubSemRust {
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.agregar_staff(AccountId::from([0x2; 32]));
+       club.agregar_guardian(AccountId::from([0x3; 32]));
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_socio(30537975, "Huggy Bear".to_string(), 1, None);
+       club.pausar("Error en la facturacion".to_string());
+       return club;
+   }
 
-        //obtengo la tupla de consulta_de_pagos
-        let vec_tuplas_obtenido = club.consulta_de_pagos(Some(dni_b));
+   ///un guardian puede pausar, se emite el evento y las consultas siguen funcionando mientras esta pausado
+   #[ink::test]
+   fn pausar_guardian_y_consultas_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let guardian = AccountId::from([0x3; 32]);
+       club.agregar_guardian(guardian);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(guardian);
+       assert_eq!(club.pausar("Error en la facturacion".to_string()), true);
+       assert_eq!(ink::env::test::recorded_events().count(), 1, "Debio emitirse el evento de pausa");
+       assert_eq!(club.get_estado_pausa(), (true, Some("Error en la facturacion".to_string())));
 
-        //comparo lo obtenido y esperado
-        assert_eq! (vec_tuplas_obtenido, vec_tuplas_esperado, "Debio ser la misma tupla");  
-    }
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       assert_eq!(club.get_socios().len(), 1);
+       assert_eq!(club.get_pagos_totales(None).len(), 1);
+       assert_eq!(club.consulta_de_pagos(Some(30537974)).len(), 1);
+       assert_eq!(club.get_recibo(1).is_some(), true);
+       assert_eq!(club.get_auditoria(None, Some(AccionAdministrativa::Pausa))[0].actor, guardian);
+   }
 
-    #[ink::test]
-    fn consulta_de_pagos_sin_dni_test() {
-        //creo el club "a mano"
+   ///el owner reanuda el contrato y los metodos vuelven a estar habilitados
+   #[ink::test]
+   fn reanudar_test(){
+       let mut club = club_pausado();
+       assert_eq!(club.reanudar(), true);
+       assert_eq!(club.get_estado_pausa(), (false, None));
+       assert_eq!(ink::env::test::recorded_events().count(), 2, "Debieron emitirse los eventos de pausa y reanudacion");
+       club.realizar_pago(30537975, 5000);
+   }
 
-        //creo el vec de pagos
-        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
-        let pago2 = Pago::new(30, 3000, 30, Some(15), false);
-        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
-        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
-        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
-        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
+   #[ink::test]
+   #[should_panic]
+   fn reanudar_guardian_test(){
+       let mut club = club_pausado();
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x3; 32]));
+       club.reanudar();
+   }
 
-        let mut pagos = Vec::new();
+   #[ink::test]
+   #[should_panic]
+   fn pausar_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.agregar_staff(AccountId::from([0x2; 32]));
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.pausar("Error".to_string());
+   }
 
-        pagos.push(pago1);
-        pagos.push(pago2);
-        pagos.push(pago3);
-        pagos.push(pago4);
-        pagos.push(pago5);
-        pagos.push(pago6);
+   #[ink::test]
+   #[should_panic]
+   fn pausar_sin_motivo_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.pausar(String::new());
+   }
 
-        //creo a los socios
-        //creo un socio categoria b
-        let ape_y_nom_b = "David Starsky".to_string();
-        let dni_b = 30;
-        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte_b = Deporte::deporte_from_id(&Some(2));
-        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
+   //con el contrato pausado, cada metodo que modifica el estado debe arrojar un panic, aun llamado por el owner
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn set_owner_pausado_test(){
+       let mut club = club_pausado();
+       club.set_owner(AccountId::from([0x5; 32]));
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn toggle_politica_autorizacion_pausado_test(){
+       let mut club = club_pausado();
+       club.toggle_politica_autorizacion();
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn agregar_staff_pausado_test(){
+       let mut club = club_pausado();
+       club.agregar_staff(AccountId::from([0x5; 32]));
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn quitar_staff_pausado_test(){
+       let mut club = club_pausado();
+       club.quitar_staff(AccountId::from([0x2; 32]));
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_costos_categoria_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_costos_categoria(1, 100);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_costo_beneficio_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_costo_beneficio(100);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_pagos_consecutivos_para_descuento_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_pagos_consecutivos_para_descuento(1);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn emitir_pago_mensual_pausado_test(){
+       let mut club = club_pausado();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn registrar_socio_pausado_test(){
+       let mut club = club_pausado();
+       club.registrar_socio(31195032, "Kenneth Hutchinson".to_string(), 1, None);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn registrar_socio_protegido_pausado_test(){
+       let mut club = club_pausado();
+       club.registrar_socio_protegido(CompromisoIdentidad::calcular_dni(31195032, &[1u8; 32]), CompromisoIdentidad::calcular_apellido_y_nombre("Kenneth Hutchinson", &[1u8; 32]), 1, None);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn toggle_proteccion_datos_pausado_test(){
+       let mut club = club_pausado();
+       club.toggle_proteccion_datos();
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn realizar_pago_pausado_test(){
+       let mut club = club_pausado();
+       club.realizar_pago(30537974, 5000);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn revertir_pago_pausado_test(){
+       let mut club = club_pausado();
+       club.revertir_pago(1, "Error".to_string(), false);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_ventana_reversion_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_ventana_reversion(3);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_retencion_auditoria_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_retencion_auditoria(10);
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn agregar_guardian_pausado_test(){
+       let mut club = club_pausado();
+       club.agregar_guardian(AccountId::from([0x5; 32]));
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn quitar_guardian_pausado_test(){
+       let mut club = club_pausado();
+       club.quitar_guardian(AccountId::from([0x3; 32]));
+   }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn pausar_pausado_test(){
+       let mut club = club_pausado();
+       club.pausar("Otra vez".to_string());
+   }
 
-        //creo un socio categoria a
-        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
-        let dni_a = 32;
-        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
-        let deporte_a = None;
-        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+   //tests operaciones en lote
+   ///registro un lote con elementos validos e invalidos sin todo_o_nada, y compruebo que se registren solo los validos,
+   ///informando el motivo de cada error
+   #[ink::test]
+   fn registrar_socios_en_lote_parcial_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       let lote = vec![
+           (31195032, "Kenneth Hutchinson".to_string(), 2, Some(1)),
+           (30537974, "David Starsky".to_string(), 1, None),
+           (440, "Huggy Bear".to_string(), 4, None),
+           (441, "Harold Dobey".to_string(), 2, Some(9)),
+           (31195032, "Kenneth Hutchinson".to_string(), 2, Some(1)),
+           (442, "Michael Knight".to_string(), 3, None),
+           (443 | MARCA_IDENTIFICADOR_PROTEGIDO, "Rick Hunter".to_string(), 1, None),
+       ];
+       let resultados = club.registrar_socios_en_lote(lote, false);
+       assert_eq!(resultados[0].as_ref().unwrap().get_monto(), 3000);
+       assert_eq!(resultados[1], Err(ErrorLote::SocioExistente));
+       assert_eq!(resultados[2], Err(ErrorLote::CategoriaInvalida));
+       assert_eq!(resultados[3], Err(ErrorLote::DeporteInvalido));
+       assert_eq!(resultados[4], Err(ErrorLote::DniRepetidoEnLote));
+       assert_eq!(resultados[5].as_ref().unwrap().get_dni_socio(), 442);
+       assert_eq!(resultados[6], Err(ErrorLote::DniInvalido));
+       assert_eq!(club.get_socios().len(), 3);
+       assert_eq!(club.get_socio(31195032).unwrap().get_deporte(), Some(Deporte::Futbol));
+   }
 
-        //los agrego
-        let mut socios = Vec::new();
+   ///con todo_o_nada, si un elemento falla no se registra ninguno
+   #[ink::test]
+   fn registrar_socios_en_lote_todo_o_nada_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let lote = vec![
+           (31195032, "Kenneth Hutchinson".to_string(), 1, None),
+           (440, "Huggy Bear".to_string(), 4, None),
+       ];
+       let resultados = club.registrar_socios_en_lote(lote, true);
+       assert_eq!(resultados, vec![Err(ErrorLote::LoteCancelado), Err(ErrorLote::CategoriaInvalida)]);
+       assert_eq!(club.get_socios().len(), 0);
+       assert_eq!(club.get_pagos_totales(None).len(), 0);
+   }
 
-        socios.push(starsky.clone());
-        socios.push(hutch.clone());
+   #[ink::test]
+   #[should_panic]
+   fn registrar_socios_en_lote_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.registrar_socios_en_lote(vec![(440, "Huggy Bear".to_string(), 1, None)], false);
+   }
 
-        //creo los costos
-        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 3);
+   ///pago en lote dos pagos pendientes del mismo socio, uno de un socio inexistente y uno sin pago pendiente,
+   ///compruebo que cada elemento del mismo socio salde un pago distinto y que se emitan sus recibos
+   #[ink::test]
+   fn realizar_pagos_en_lote_parcial_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.registrar_socio(31195032, "Kenneth Hutchinson".to_string(), 3, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       let lote = vec![(30537974, 5000), (30537974, 5000), (30537974, 5000), (1, 5000), (31195032, 2000)];
+       let resultados = club.realizar_pagos_en_lote(lote, false);
+       assert_eq!(resultados[0].is_ok(), true);
+       assert_eq!(resultados[1].is_ok(), true);
+       assert_ne!(resultados[0], resultados[1], "Debieron saldarse pagos distintos");
+       assert_eq!(resultados[2], Err(ErrorLote::SinPagoPendiente));
+       assert_eq!(resultados[3], Err(ErrorLote::SocioInexistente));
+       assert_eq!(resultados[4].is_ok(), true);
+       assert_eq!(club.get_recibos_por_fecha(0, u64::MAX).len(), 3);
+   }
 
-        //creo al owner y lo seteo como el caller
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+   ///con todo_o_nada, si un pago falla no se registra ninguno
+   #[ink::test]
+   fn realizar_pagos_en_lote_todo_o_nada_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       let resultados = club.realizar_pagos_en_lote(vec![(30537974, 5000), (30537974, 100)], true);
+       assert_eq!(resultados, vec![Err(ErrorLote::LoteCancelado), Err(ErrorLote::SinPagoPendiente)]);
+       assert_eq!(club.get_pagos_totales(Some(30537974))[0].get_fecha_pago(), None);
+       assert_eq!(club.get_recibo(1), None);
+   }
 
-        //creo los permitidos
-        let permitidos = Vec::new();
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn registrar_socios_en_lote_pausado_test(){
+       let mut club = club_pausado();
+       club.registrar_socios_en_lote(vec![(440, "Huggy Bear".to_string(), 1, None)], false);
+   }
 
-        //creo la politica de autorizacion
-        let politica_activada = true;
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn realizar_pagos_en_lote_pausado_test(){
+       let mut club = club_pausado();
+       club.realizar_pagos_en_lote(vec![(30537975, 5000)], false);
+   }
 
-        //creo la ultima emision
-        let emision_ultimo_pago = None;
+   //tests instantaneas
+   ///exporta la instantanea completa de un club en chunks, concatenandolos
+   fn exportar_completa(club: &ClubSemRust, tamanio_chunk: u32) -> (MetadatosInstantanea, Vec<Vec<u8>>) {
+       let metadatos = club.get_metadatos_instantanea(tamanio_chunk);
+       let chunks = (0..metadatos.cantidad_chunks).map(|indice| club.exportar_instantanea(indice, tamanio_chunk)).collect();
+       return (metadatos, chunks);
+   }
 
-        //creo el club
-        let club = ClubSemRust {socios, pagos, costos, owner, permitidos, politica_activada, emision_ultimo_pago};
+   ///armo un club con socios, pagos, recibos y staff, lo exporto en chunks y lo importo en un club nuevo,
+   ///comprobando que el estado reconstruido sea el mismo
+   #[ink::test]
+   fn exportar_e_importar_instantanea_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.agregar_staff(AccountId::from([0x2; 32]));
+       club.registrar_socio(30537974, "David Starsky".to_string(), 2, Some(2));
+       club.registrar_socio(31195032, "Kenneth Hutchinson".to_string(), 1, None);
+       club.realizar_pago(30537974, 3000);
+       club.agregar_lector(AccountId::from([0x5; 32]));
+       club.configurar_token_pago(Some(AccountId::from([0x7; 32])));
+       club.actualizar_puntos_categoria(1, 10);
+       club.asignar_grupo_familiar(31195032, Some(1));
+       club.realizar_pago(31195032, 5000);
+       club.canjear_puntos(31195032, 4);
+       club.vincular_titular_carnet(30537974, Some(AccountId::from([0x6; 32])));
+       club.configurar_politica_morosidad(1, 0, 2, 0);
+       club.toggle_politica_autorizacion();
+       let (metadatos, chunks) = exportar_completa(&club, 16);
+       assert_eq!(metadatos.cantidad_socios, 2);
+       assert_eq!(metadatos.cantidad_pagos, 2);
+       assert_eq!(metadatos.cantidad_recibos, 2);
+       assert_eq!(chunks.concat().len() as u32, metadatos.total_bytes);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos.clone());
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       assert!(nuevo.finalizar_importacion());
+       assert_eq!(nuevo.instantanea(), club.instantanea());
+       assert_eq!(nuevo.lectores, vec![AccountId::from([0x5; 32])]);
+       assert_eq!(nuevo.get_puntos(31195032), 6);
+       assert_eq!(nuevo.carnets.len(), 2, "No debieron emitirse carnets nuevos");
+       assert_eq!(nuevo.get_carnet(30537974).unwrap().get_titular(), Some(AccountId::from([0x6; 32])));
+       assert_eq!(nuevo.politica_morosidad, PoliticaMorosidad::new(1, 0, 2, 0));
+       assert_eq!(nuevo.get_metadatos_instantanea(16).hash, metadatos.hash);
+       assert_eq!(nuevo.get_auditoria(None, Some(AccionAdministrativa::ImportacionEstado)).len(), 1);
+   }
 
-        //comienzo a crear el vec de tuplas esperado 
-        //creo el vec de los pagos de ambos socios
-        let mut vec_montos_hutch: Vec<u128> = Vec::new();
-        let mut vec_montos_starsky: Vec<u128> = Vec::new();
-        for _i in 0..3 {
-            vec_montos_starsky.push(3000);
-            vec_montos_hutch.push(5000);
-        }
+   ///si un chunk llega adulterado, el hash no coincide y la importacion se rechaza
+   #[ink::test]
+   #[should_panic(expected = "Los bytes recibidos no coinciden")]
+   fn finalizar_importacion_hash_incorrecto_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       let (metadatos, mut chunks) = exportar_completa(&club, 1000);
+       chunks[0][5] ^= 0xff;
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       nuevo.importar_chunk(0, chunks[0].clone());
+       nuevo.finalizar_importacion();
+   }
 
-        //creo las tupla esperadas
-        let tupla1 = (starsky, vec_montos_starsky);
-        let tupla2 = (hutch, vec_montos_hutch);
+   #[ink::test]
+   #[should_panic(expected = "El chunk ingresado no es el siguiente esperado!")]
+   fn importar_chunk_fuera_de_orden_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       let (metadatos, chunks) = exportar_completa(&club, 8);
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       nuevo.importar_chunk(1, chunks[1].clone());
+   }
 
-        //creo el vec de tuplas que quiero obtener
-        let mut vec_tuplas_esperado = Vec::new();
-        vec_tuplas_esperado.push(tupla1);
-        vec_tuplas_esperado.push(tupla2);
+   #[ink::test]
+   #[should_panic(expected = "Solo se puede importar una instantanea en un club sin socios ni pagos!")]
+   fn iniciar_importacion_club_con_socios_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       let metadatos = club.get_metadatos_instantanea(100);
+       club.iniciar_importacion(metadatos);
+   }
 
-        //obtengo la tupla de consulta_de_pagos
-        let vec_tuplas_obtenido = club.consulta_de_pagos(None);
+   ///una instantanea exportada con un formato anterior se rechaza al iniciar la importacion
+   #[ink::test]
+   #[should_panic(expected = "La version de la instantanea no es compatible con este contrato!")]
+   fn iniciar_importacion_version_anterior_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let mut metadatos = club.get_metadatos_instantanea(100);
+       metadatos.version = VERSION_INSTANTANEA - 1;
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+   }
 
-        //comparo lo obtenido y esperado
-        assert_eq! (vec_tuplas_obtenido, vec_tuplas_esperado, "Debieron ser las mismas tuplas");  
-    }
+   #[ink::test]
+   #[should_panic]
+   fn exportar_instantanea_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.agregar_staff(AccountId::from([0x2; 32]));
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.exportar_instantanea(0, 100);
+   }
 
-    #[ink::test]
-    fn corroborar_bonificacion_sin_pagos_suficientes_test() {
-        //creo el club "a mano"
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn iniciar_importacion_pausado_test(){
+       let mut club = club_pausado();
+       let metadatos = club.get_metadatos_instantanea(100);
+       club.iniciar_importacion(metadatos);
+   }
 
-        //creo el vec de pagos
-        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
-        let pago2 = Pago::new(30, 3000, 30, Some(15), false);
-        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
-        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
-        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
-        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn importar_chunk_pausado_test(){
+       let mut club = club_pausado();
+       club.importar_chunk(0, Vec::new());
+   }
 
-        let mut pagos = Vec::new();
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn finalizar_importacion_pausado_test(){
+       let mut club = club_pausado();
+       club.finalizar_importacion();
+   }
 
-        pagos.push(pago1);
-        pagos.push(pago2);
-        pagos.push(pago3);
-        pagos.push(pago4);
-        pagos.push(pago5);
-        pagos.push(pago6);
+   //tests permiso de lectura
+   #[ink::test]
+   fn lector_consulta_con_politica_activada_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       let lector = AccountId::from([0x5; 32]);
+       assert_eq!(club.agregar_lector(lector), true);
+       assert_eq!(club.get_auditoria(None, Some(AccionAdministrativa::AgregarLector)).len(), 1);
+
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(lector);
+       assert_eq!(club.get_socios().len(), 1);
+       assert_eq!(club.get_pagos_totales(None).len(), 1);
+       assert_eq!(club.consulta_de_pagos(Some(30537974)).len(), 1);
+       //el permiso es solo de lectura
+       assert_eq!(club.verificar_permisos(), false);
+   }
 
-        //creo a los socios
-        //creo un socio categoria b
-        let ape_y_nom_b = "David Starsky".to_string();
-        let dni_b = 30;
-        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte_b = Deporte::deporte_from_id(&Some(2));
-        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
+   #[ink::test]
+   #[should_panic(expected = "No se cuenta con los permisos necesarios!")]
+   fn quitar_lector_revoca_consultas_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let lector = AccountId::from([0x5; 32]);
+       club.agregar_lector(lector);
+       assert_eq!(club.quitar_lector(lector), true);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(lector);
+       club.get_socios();
+   }
 
-        //creo un socio categoria a
-        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
-        let dni_a = 32;
-        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
-        let deporte_a = None;
-        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+   #[ink::test]
+   #[should_panic(expected = "No cuenta con rango Owner o la cuenta ingresada ya es lectora!")]
+   fn agregar_lector_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.agregar_staff(AccountId::from([0x2; 32]));
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.agregar_lector(AccountId::from([0x5; 32]));
+   }
 
-        //los agrego
-        let mut socios = Vec::new();
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn agregar_lector_pausado_test(){
+       let mut club = club_pausado();
+       club.agregar_lector(AccountId::from([0x5; 32]));
+   }
 
-        socios.push(starsky.clone());
-        socios.push(hutch.clone());
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn quitar_lector_pausado_test(){
+       let mut club = club_pausado();
+       club.quitar_lector(AccountId::from([0x5; 32]));
+   }
 
-        //creo los costos 
-        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 4);
+   //tests pagos en token
+   //respuesta que devuelve el token simulado y las transferencias que se le pidieron (token, desde, monto)
+   struct TokenSimulado {
+       respuesta: Result<(), ErrorPagoToken>,
+       transferencias: Vec<(AccountId, AccountId, u128)>,
+   }
 
-        //creo al owner y lo seteo como el caller
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+   thread_local! {
+       static TOKEN_SIMULADO: RefCell<TokenSimulado> = const { RefCell::new(TokenSimulado {respuesta: Err(ErrorPagoToken::LlamadaFallida), transferencias: Vec::new()}) };
+   }
 
-        //creo los permitidos
-        let permitidos = Vec::new();
+   //reemplaza a la llamada a transfer_from del token en los tests off-chain
+   pub(super) fn respuesta_token(token: AccountId, desde: AccountId, monto: u128) -> Result<(), ErrorPagoToken> {
+       return TOKEN_SIMULADO.with(|simulado| {
+           let mut simulado = simulado.borrow_mut();
+           simulado.transferencias.push((token, desde, monto));
+           return simulado.respuesta.clone();
+       });
+   }
 
-        //creo la politica de autorizacion
-        let politica_activada = true;
+   fn simular_token(respuesta: Result<(), ErrorPagoToken>) {
+       TOKEN_SIMULADO.with(|simulado| simulado.borrow_mut().respuesta = respuesta);
+   }
 
-        //creo la ultima emision
-        let emision_ultimo_pago = None;
+   fn transferencias_token() -> Vec<(AccountId, AccountId, u128)> {
+       return TOKEN_SIMULADO.with(|simulado| simulado.borrow().transferencias.clone());
+   }
 
-        //creo el club
-        let club = ClubSemRust {socios, pagos, costos, owner, permitidos, politica_activada, emision_ultimo_pago};
+   ///si el token acepta la transferencia, se debita el monto exacto del caller y el pago queda saldado con su recibo
+   #[ink::test]
+   fn pagar_con_token_transferencia_exitosa_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       let token = AccountId::from([0x7; 32]);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 2, Some(2));
+       club.configurar_token_pago(Some(token));
+       simular_token(Ok(()));
+       let pagador = AccountId::from([0x8; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(pagador);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1000);
+       let pago = club.pagar_con_token(30537974).unwrap();
+       assert_eq!(pago.get_fecha_pago(), Some(1000), "El pago debio quedar saldado");
+       assert_eq!(transferencias_token(), vec![(token, pagador, 3000)], "Debio pedirse el monto exacto al caller");
+       assert_eq!(club.recibos.len(), 1, "Debio emitirse el recibo");
+       assert_eq!(club.recibos[0].cobrado_por, pagador, "El recibo debio registrar a quien pago");
+   }
 
-        //llamo al metodo de corroborar_bonificacion, sabiendo que la cantidad de pagos necesarios
-        //para el beneficio, no alcanza aún
+   ///si el token rechaza la transferencia, se devuelve el motivo y el pago sigue pendiente, sin recibo
+   #[ink::test]
+   fn pagar_con_token_transferencia_rechazada_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 2, Some(2));
+       club.configurar_token_pago(Some(AccountId::from([0x7; 32])));
+       simular_token(Err(ErrorPagoToken::SaldoInsuficiente));
+       assert_eq!(club.pagar_con_token(30537974), Err(ErrorPagoToken::SaldoInsuficiente));
+       assert!(club.pagos.iter().all(|pago| pago.get_fecha_pago().is_none()), "El pago debio seguir pendiente");
+       assert!(club.recibos.is_empty(), "No debio emitirse un recibo");
+   }
 
-        assert_eq! (club.corroborar_bonificacion(30), false, "No debió otorgar el beneficio");
-    }
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn configurar_token_pago_pausado_test(){
+       let mut club = club_pausado();
+       club.configurar_token_pago(Some(AccountId::from([0x7; 32])));
+   }
 
-    #[ink::test]
-    fn corroborar_bonificacion_con_pagos_suficientes_test() {
-        //creo el club "a mano"
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn pagar_con_token_pausado_test(){
+       let mut club = club_pausado();
+       let _ = club.pagar_con_token(30537975);
+   }
 
-        //creo el vec de pagos
-        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
-        let pago2 = Pago::new(30, 3000, 30, Some(15), false);
-        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
-        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
-        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
-        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
+   #[ink::test]
+   fn configurar_token_pago_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       assert_eq!(club.get_token_pago(), None);
+       let token = AccountId::from([0x7; 32]);
+       assert_eq!(club.configurar_token_pago(Some(token)), true);
+       assert_eq!(club.get_token_pago(), Some(token));
+       assert_eq!(club.configurar_token_pago(None), true);
+       assert_eq!(club.get_token_pago(), None);
+       let auditoria = club.get_auditoria(None, Some(AccionAdministrativa::CambioTokenPago));
+       assert_eq!(auditoria[1].valor_anterior, Some(ValorAuditado::Cuenta(token)));
+   }
 
-        let mut pagos = Vec::new();
+   #[ink::test]
+   #[should_panic(expected = "No cuenta con rango Owner para realizar esta operacion!")]
+   fn configurar_token_pago_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.agregar_staff(AccountId::from([0x2; 32]));
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.configurar_token_pago(Some(AccountId::from([0x7; 32])));
+   }
 
-        pagos.push(pago1);
-        pagos.push(pago2);
-        pagos.push(pago3);
-        pagos.push(pago4);
-        pagos.push(pago5);
-        pagos.push(pago6);
+   #[ink::test]
+   #[should_panic(expected = "No hay un token de pago configurado!")]
+   fn pagar_con_token_sin_token_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 2, Some(2));
+       let _ = club.pagar_con_token(30537974);
+   }
 
-        //creo a los socios
-        //creo un socio categoria b
-        let ape_y_nom_b = "David Starsky".to_string();
-        let dni_b = 30;
-        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte_b = Deporte::deporte_from_id(&Some(2));
-        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
+   #[ink::test]
+   #[should_panic(expected = "El socio no tiene pagos pendientes!")]
+   fn pagar_con_token_sin_pagos_pendientes_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 2, Some(2));
+       club.realizar_pago(30537974, 3000);
+       club.configurar_token_pago(Some(AccountId::from([0x7; 32])));
+       //un socio cualquiera puede intentar pagar, pero no hay nada pendiente
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x8; 32]));
+       let _ = club.pagar_con_token(30537974);
+   }
 
-        //creo un socio categoria a
-        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
-        let dni_a = 32;
-        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
-        let deporte_a = None;
-        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+   #[ink::test]
+   #[should_panic(expected = "No se ha encontrado un socio con el DNI provisto!")]
+   fn pagar_con_token_socio_inexistente_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.configurar_token_pago(Some(AccountId::from([0x7; 32])));
+       let _ = club.pagar_con_token(30537974);
+   }
 
-        //los agrego
-        let mut socios = Vec::new();
+   //tests puntos de fidelidad
+   ///un pago a término acredita los puntos de la categoria y queda en el historial; uno fuera de término no acredita
+   #[ink::test]
+   fn puntos_pago_a_termino_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11.from_dias());
+       club.realizar_pago(28111222, 5000);
+
+       assert_eq!(club.get_puntos(30537974), 10);
+       assert_eq!(club.get_puntos(28111222), 0, "Un pago vencido no debio acreditar puntos");
+       let historial = club.get_historial_puntos(30537974);
+       assert_eq!(historial.len(), 1);
+       assert_eq!(historial[0].get_tipo(), TipoMovimientoPuntos::Acreditacion);
+       assert_eq!(historial[0].get_cantidad(), 10);
+   }
 
-        socios.push(starsky.clone());
-        socios.push(hutch.clone());
+   ///los puntos canjeados se descuentan de la proxima cuota emitida, una sola vez
+   #[ink::test]
+   fn canjear_puntos_descuento_proxima_cuota_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.actualizar_valor_punto(100);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+
+       assert_eq!(club.canjear_puntos(30537974, 6), 600);
+       assert_eq!(club.get_puntos(30537974), 4);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(31.from_dias());
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).last().unwrap().get_monto(), 4400);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(62.from_dias());
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).last().unwrap().get_monto(), 5000, "El descuento debio aplicarse una sola vez");
+   }
 
-        //creo los costos 
-        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 2);
+   #[ink::test]
+   #[should_panic(expected = "La cantidad de puntos a canjear debe ser mayor a 0 y no superar los puntos vigentes!")]
+   fn canjear_puntos_insuficientes_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       club.canjear_puntos(30537974, 11);
+   }
 
-        //creo al owner y lo seteo como el caller
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+   ///los puntos se transfieren dentro del grupo familiar y ambos socios ven el movimiento en su historial
+   #[ink::test]
+   fn transferir_puntos_grupo_familiar_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 3, None);
+       club.asignar_grupo_familiar(30537974, Some(1));
+       club.asignar_grupo_familiar(28111222, Some(1));
+       club.realizar_pago(30537974, 5000);
+
+       club.transferir_puntos(30537974, 28111222, 7);
+       assert_eq!(club.get_puntos(30537974), 3);
+       assert_eq!(club.get_puntos(28111222), 7);
+       assert_eq!(club.get_historial_puntos(30537974).last().unwrap().get_tipo(), TipoMovimientoPuntos::TransferenciaEnviada);
+       assert_eq!(club.get_historial_puntos(28111222)[0].get_tipo(), TipoMovimientoPuntos::TransferenciaRecibida);
+   }
 
-        //creo los permitidos
-        let permitidos = Vec::new();
+   #[ink::test]
+   #[should_panic(expected = "Los puntos solo pueden transferirse a otro socio del mismo grupo familiar!")]
+   fn transferir_puntos_fuera_del_grupo_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 3, None);
+       club.asignar_grupo_familiar(30537974, Some(1));
+       club.asignar_grupo_familiar(28111222, Some(2));
+       club.realizar_pago(30537974, 5000);
+       club.transferir_puntos(30537974, 28111222, 7);
+   }
 
-        //creo la politica de autorizacion
-        let politica_activada = true;
+   ///la cuenta titular del carnet de un socio puede canjear y transferir sus puntos sin permisos de staff
+   #[ink::test]
+   fn canjear_y_transferir_puntos_titular_carnet_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.actualizar_valor_punto(100);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 1, None);
+       club.asignar_grupo_familiar(30537974, Some(1));
+       club.asignar_grupo_familiar(28111222, Some(1));
+       club.realizar_pago(30537974, 5000);
+       let titular = AccountId::from([0x6; 32]);
+       club.vincular_titular_carnet(30537974, Some(titular));
+
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(titular);
+       assert_eq!(club.canjear_puntos(30537974, 4), 400);
+       assert!(club.transferir_puntos(30537974, 28111222, 6));
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       assert_eq!(club.get_puntos(30537974), 0);
+       assert_eq!(club.get_puntos(28111222), 6);
+   }
 
-        //creo la ultima emision
-        let emision_ultimo_pago = None;
+   ///el titular de un carnet no puede disponer de los puntos de otro socio
+   #[ink::test]
+   #[should_panic(expected = "No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!")]
+   fn canjear_puntos_de_otro_socio_titular_carnet_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       let titular = AccountId::from([0x6; 32]);
+       club.vincular_titular_carnet(28111222, Some(titular));
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(titular);
+       club.canjear_puntos(30537974, 4);
+   }
 
-        //creo el club
-        let club = ClubSemRust {socios, pagos, costos, owner, permitidos, politica_activada, emision_ultimo_pago};
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn canjear_puntos_pausado_test(){
+       let mut club = club_pausado();
+       club.canjear_puntos(30537974, 1);
+   }
 
-        //llamo al metodo de corroborar_bonificacion, sabiendo que la cantidad de pagos necesarios
-        //para el beneficio, alcanza
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_puntos_categoria_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_puntos_categoria(1, 10);
+   }
 
-        assert_eq! (club.corroborar_bonificacion(30), true, "Debió otorgar el beneficio");
-    }
+   ///los puntos vencidos dejan de contar y al procesarlos quedan registrados en el historial
+   #[ink::test]
+   fn vencimiento_puntos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.actualizar_vigencia_puntos(5);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(6.from_dias());
+       assert_eq!(club.get_puntos(30537974), 0);
+       assert_eq!(club.procesar_vencimiento_puntos(), 10);
+       assert_eq!(club.procesar_vencimiento_puntos(), 0);
+       let historial = club.get_historial_puntos(30537974);
+       assert_eq!(historial.last().unwrap().get_tipo(), TipoMovimientoPuntos::Vencimiento);
+       assert_eq!(historial.last().unwrap().get_cantidad(), 10);
+   }
 
-    #[ink::test]
-    fn corroborar_bonificacion_con_pago_pendiente_test() {
-        //creo el club "a mano"
+   ///al revertir un cobro se anulan los puntos que acreditó
+   #[ink::test]
+   fn revertir_pago_anula_puntos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_puntos_categoria(1, 10);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.realizar_pago(30537974, 5000);
+       club.revertir_pago(1, "Monto equivocado".to_string(), false);
+
+       assert_eq!(club.get_puntos(30537974), 0);
+       assert_eq!(club.get_historial_puntos(30537974).last().unwrap().get_tipo(), TipoMovimientoPuntos::Anulacion);
+   }
 
-        //creo el vec de pagos
-        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
-        let pago2 = Pago::new(30, 3000, 30, None, false);
-        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
-        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
-        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
-        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
+   #[ink::test]
+   #[should_panic(expected = "No cuenta con rango Owner para realizar esta operacion!")]
+   fn actualizar_valor_punto_sin_permisos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.actualizar_valor_punto(100);
+   }
 
-        let mut pagos = Vec::new();
+   //tests carnet de socio
+   ///al registrar un socio se le emite un carnet con los metadatos de su categoria y deporte
+   #[ink::test]
+   fn carnet_emitido_al_registrar_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 2, Some(1));
+
+       let carnet = club.get_carnet(28111222).unwrap();
+       assert_eq!(carnet.get_id(), 2);
+       assert_eq!(carnet.get_titular(), None);
+       assert_eq!(club.total_supply(), 2);
+       assert_eq!(club.get_attribute(Id::U32(2), b"categoria".to_vec()), Some(b"B".to_vec()));
+       assert_eq!(club.get_attribute(Id::U32(2), b"deporte".to_vec()), Some(b"Futbol".to_vec()));
+       assert_eq!(club.get_attribute(Id::U32(1), b"deporte".to_vec()), None);
+   }
 
-        pagos.push(pago1);
-        pagos.push(pago2);
-        pagos.push(pago3);
-        pagos.push(pago4);
-        pagos.push(pago5);
-        pagos.push(pago6);
+   ///la tenencia del carnet identifica al socio, que no puede transferirlo
+   #[ink::test]
+   fn carnet_titular_intransferible_test(){
+       let owner = AccountId::from([0x1; 32]);
+       let cuenta_socio = AccountId::from([0x5; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.vincular_titular_carnet(30537974, Some(cuenta_socio));
+
+       assert_eq!(club.owner_of(Id::U32(1)), Some(cuenta_socio));
+       assert_eq!(club.balance_of(cuenta_socio), 1);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(cuenta_socio);
+       assert!(club.transfer(owner, Id::U32(1), Vec::new()).is_err());
+       assert!(club.approve(owner, Some(Id::U32(1)), true).is_err());
+       assert_eq!(club.owner_of(Id::U32(1)), Some(cuenta_socio));
+   }
 
-        //creo a los socios
-        //creo un socio categoria b
-        let ape_y_nom_b = "David Starsky".to_string();
-        let dni_b = 30;
-        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte_b = Deporte::deporte_from_id(&Some(2));
-        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
+   #[ink::test]
+   #[should_panic(expected = "La cuenta ya posee el carnet de otro socio!")]
+   fn carnet_cuenta_con_otro_carnet_test(){
+       let owner = AccountId::from([0x1; 32]);
+       let cuenta_socio = AccountId::from([0x5; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 3, None);
+       club.vincular_titular_carnet(30537974, Some(cuenta_socio));
+       club.vincular_titular_carnet(28111222, Some(cuenta_socio));
+   }
+
+   ///el estado del carnet sigue los pagos del socio
+   #[ink::test]
+   fn carnet_estado_segun_pagos_test(){
+       let owner = AccountId::from([0x1; 32]);
+       let cuenta_socio = AccountId::from([0x5; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.vincular_titular_carnet(30537974, Some(cuenta_socio));
+       assert_eq!(club.estado_carnet(1), Some(EstadoCarnet::AlDia));
+
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11.from_dias());
+       assert_eq!(club.estado_carnet(1), Some(EstadoCarnet::ConDeuda));
+       assert_eq!(club.verificar_carnet_cuenta(cuenta_socio), Some(EstadoCarnet::ConDeuda));
+       assert_eq!(club.get_attribute(Id::U32(1), b"estado".to_vec()), Some(b"ConDeuda".to_vec()));
+       club.realizar_pago(30537974, 5000);
+       assert_eq!(club.estado_carnet(1), Some(EstadoCarnet::AlDia));
+   }
 
-        //creo un socio categoria a
-        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
-        let dni_a = 32;
-        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
-        let deporte_a = None;
-        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+   ///al renunciar, el socio sale del padron y su carnet queda revocado
+   #[ink::test]
+   fn renuncia_revoca_carnet_test(){
+       let owner = AccountId::from([0x1; 32]);
+       let cuenta_socio = AccountId::from([0x5; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       club.vincular_titular_carnet(30537974, Some(cuenta_socio));
+       club.registrar_renuncia(30537974);
+
+       assert_eq!(club.get_socio(30537974), None);
+       assert_eq!(club.get_carnet(30537974), None);
+       assert_eq!(club.estado_carnet(1), Some(EstadoCarnet::Revocado));
+       assert_eq!(club.owner_of(Id::U32(1)), None);
+       assert_eq!(club.balance_of(cuenta_socio), 0);
+       assert_eq!(club.verificar_carnet_cuenta(cuenta_socio), None);
+       assert_eq!(club.total_supply(), 0);
+       assert_eq!(club.get_pagos_totales(Some(30537974)).len(), 1, "Los pagos del socio debieron conservarse");
+   }
 
-        //los agrego
-        let mut socios = Vec::new();
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn vincular_titular_carnet_pausado_test(){
+       let mut club = club_pausado();
+       club.vincular_titular_carnet(30537974, Some(AccountId::from([0x5; 32])));
+   }
 
-        socios.push(starsky.clone());
-        socios.push(hutch.clone());
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn registrar_renuncia_pausado_test(){
+       let mut club = club_pausado();
+       club.registrar_renuncia(30537974);
+   }
 
-        //creo los costos 
-        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 2);
+   //tests planes de cuotas
+   //en estos tests el socio de categoria A tiene dos pagos vencidos al dia 61: el del alta y la primera cuota mensual
+   ///el plan reparte la deuda mas el interes en cuotas, y mientras esta vigente los pagos refinanciados no se pagan sueltos
+   #[ink::test]
+   fn crear_plan_cuotas_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       let plan = club.crear_plan_cuotas(30537974, 3, 10);
+       assert_eq!(plan.get_deuda_original(), 10000);
+       let cuotas = plan.get_cuotas();
+       assert_eq!(cuotas.len(), 3);
+       assert_eq!(cuotas.iter().map(|cuota| cuota.get_monto()).collect::<Vec<u128>>(), vec![3666, 3666, 3668]);
+       assert_eq!(cuotas[0].get_fecha_venci(), 91.from_dias());
+       assert_eq!(cuotas[2].get_fecha_venci(), 151.from_dias());
+       assert_eq!(club.get_planes_cuotas(30537974)[0].get_estado(), EstadoPlan::Vigente);
+       assert_eq!(club.estado_carnet(1), Some(EstadoCarnet::AlDia), "La deuda refinanciada no debio contar como vencida");
+   }
 
-        //creo al owner y lo seteo como el caller
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+   #[ink::test]
+   #[should_panic(expected = "El cliente no tiene pagos pendientes con el monto ingresado!")]
+   fn realizar_pago_refinanciado_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       club.realizar_pago(30537974, 5000);
+   }
 
-        //creo los permitidos
-        let permitidos = Vec::new();
+   ///al pagar la ultima cuota el plan se cumple y se saldan los pagos originales
+   #[ink::test]
+   fn cumplir_plan_cuotas_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       club.pagar_cuota_plan(1, 5000);
+       assert_eq!(club.get_planes_cuotas(30537974)[0].get_estado(), EstadoPlan::Vigente);
+       assert!(club.get_pagos_totales(Some(30537974)).iter().all(|pago| pago.get_fecha_pago().is_none()));
+
+       let cuota = club.pagar_cuota_plan(1, 5000);
+       assert_eq!(cuota.get_fecha_pago(), Some(61.from_dias()));
+       assert_eq!(club.get_planes_cuotas(30537974)[0].get_estado(), EstadoPlan::Cumplido);
+       assert!(club.get_pagos_totales(Some(30537974)).iter().all(|pago| pago.get_fecha_pago() == Some(61.from_dias())));
+       assert_eq!(club.get_recibos_socio(30537974).len(), 2, "Cada cuota debio emitir su recibo");
+   }
 
-        //creo la politica de autorizacion
-        let politica_activada = true;
+   ///revertir el recibo de la ultima cuota deja la cuota pendiente y el plan vuelve a estar vigente
+   #[ink::test]
+   fn revertir_pago_cuota_plan_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       club.pagar_cuota_plan(1, 5000);
+       club.pagar_cuota_plan(1, 5000);
+       assert_eq!(club.get_recibo(2).unwrap().get_origen(), OrigenRecibo::CuotaPlan(1, 2));
+
+       let pago = club.revertir_pago(2, "El pago fue rechazado por el banco".to_string(), false);
+       assert_eq!((pago.get_monto(), pago.get_fecha_pago()), (5000, None));
+       let plan = club.get_planes_cuotas(30537974)[0].clone();
+       assert_eq!(plan.get_estado(), EstadoPlan::Vigente);
+       assert_eq!(plan.get_cuotas()[1].get_fecha_pago(), None);
+       assert!(club.get_pagos_totales(Some(30537974)).iter().all(|pago| pago.get_fecha_pago().is_none()), "Los pagos refinanciados debieron volver a quedar impagos");
+       assert_eq!(club.estado_de_cuenta(30537974, 0, 61.from_dias()).saldo_final, 5000);
+
+       club.pagar_cuota_plan(1, 5000);
+       assert_eq!(club.get_planes_cuotas(30537974)[0].get_estado(), EstadoPlan::Cumplido);
+   }
 
-        //creo la ultima emision
-        let emision_ultimo_pago = None;
+   ///revertir una cuota de un plan ya caido vuelve a sumar su monto a la deuda del socio como un pago pendiente
+   #[ink::test]
+   fn revertir_pago_cuota_plan_caido_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       club.pagar_cuota_plan(1, 5000);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(122.from_dias());
+       club.procesar_planes_caidos();
+
+       club.revertir_pago(1, "El pago fue rechazado por el banco".to_string(), false);
+       let estado = club.estado_de_cuenta(30537974, 0, 122.from_dias());
+       assert_eq!(estado.deuda_total, 10000);
+       assert_eq!(estado.saldo_final, 10000);
+   }
 
-        //creo el club
-        let club = ClubSemRust {socios, pagos, costos, owner, permitidos, politica_activada, emision_ultimo_pago};
+   ///si una cuota vence sin pagarse el plan cae y la deuda original vuelve a poder cobrarse
+   #[ink::test]
+   fn plan_cuotas_caido_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       assert_eq!(club.procesar_planes_caidos(), 0);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(92.from_dias());
+       assert_eq!(club.estado_carnet(1), Some(EstadoCarnet::ConDeuda));
+       assert_eq!(club.procesar_planes_caidos(), 1);
+       assert_eq!(club.get_planes_cuotas(30537974)[0].get_estado(), EstadoPlan::Caido);
+       let auditoria = club.get_auditoria(None, Some(AccionAdministrativa::CaidaPlanCuotas));
+       assert_eq!(auditoria.len(), 1, "La caida del plan debio quedar en la auditoria");
+       assert_eq!(auditoria[0].valor_anterior, Some(ValorAuditado::Dni(30537974)));
+       club.realizar_pago(30537974, 5000);
+   }
 
-        //llamo al metodo de corroborar_bonificacion, sabiendo que la cantidad de pagos necesarios
-        //para el beneficio, alcanza
+   ///al caer un plan con una cuota pagada, lo cobrado sin su parte de interes salda los pagos refinanciados que cubre
+   #[ink::test]
+   fn plan_cuotas_caido_con_cuota_pagada_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 10);
+       club.pagar_cuota_plan(1, 5500);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(122.from_dias());
+       assert_eq!(club.procesar_planes_caidos(), 1);
+
+       let pendientes: Vec<u128> = club.get_pagos_totales(Some(30537974)).iter().filter(|pago| pago.get_fecha_pago().is_none()).map(|pago| pago.get_monto()).collect();
+       assert_eq!(pendientes, vec![5000], "El alta debio quedar saldada con lo cobrado en la cuota");
+       assert!(club.reintegros_pendientes.is_empty());
+       let estado = club.estado_de_cuenta(30537974, 0, 122.from_dias());
+       assert_eq!(estado.lineas.last().unwrap().asiento.get_tipo(), TipoAsiento::AnulacionRecargo);
+       assert_eq!(estado.lineas.last().unwrap().asiento.monto, 500, "Solo debio anularse el interes de la cuota impaga");
+       assert_eq!(estado.saldo_final, 5000);
+       assert_eq!(estado.deuda_total, 5000);
+   }
 
-        assert_eq! (club.corroborar_bonificacion(30), false, "No debió otorgar el beneficio");
-    }
+   ///si lo cobrado no alcanza a cubrir el primer pago refinanciado, queda como reintegro pendiente del socio
+   #[ink::test]
+   fn plan_cuotas_caido_reintegro_pendiente_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 3, 20);
+       club.pagar_cuota_plan(1, 4000);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(122.from_dias());
+       club.procesar_planes_caidos();
+
+       assert_eq!(club.reintegros_pendientes, vec![(30537974, 3334)]);
+       let estado = club.estado_de_cuenta(30537974, 0, 122.from_dias());
+       assert_eq!(estado.deuda_total, 10000);
+       assert_eq!(estado.saldo_final, 10000 - 3334);
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).last().unwrap().get_monto(), 5000 - 3334);
+   }
 
-    #[ink::test]
-    fn corroborar_bonificacion_con_pago_vencido_test() {
-        //creo el club "a mano"
+   ///con una cuota vencida sin pagar el plan ya esta caido, aunque todavia no se haya procesado su caida
+   #[ink::test]
+   #[should_panic(expected = "El plan de cuotas tiene una cuota vencida sin pagar y se encuentra caido!")]
+   fn pagar_cuota_plan_vencida_sin_procesar_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(92.from_dias());
+       club.pagar_cuota_plan(1, 5000);
+   }
 
-        //creo el vec de pagos
-        let pago1 = Pago::new(30, 3000, 10, Some(5), false);
-        let pago2 = Pago::new(30, 3000, 30, Some(31), false);
-        let pago3 = Pago::new(30, 3000, 30, Some(12), false);
-        let pago4 = Pago::new(32, 5000, 10, Some(4), false);
-        let pago5 = Pago::new(32, 5000, 30, Some(8), false);
-        let pago6 = Pago::new(32, 5000, 30, Some(18), false);
+   ///cada cuota del plan pagada a termino acredita los puntos de la categoria, como cualquier otro pago
+   #[ink::test]
+   fn pagar_cuota_plan_acredita_puntos_test(){
+       let mut club = club_con_socio(1, None);
+       club.actualizar_puntos_categoria(1, 10);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       club.pagar_cuota_plan(1, 5000);
+       assert_eq!(club.get_puntos(30537974), 10);
+       let historial = club.get_historial_puntos(30537974);
+       assert_eq!(historial[0].get_tipo(), TipoMovimientoPuntos::Acreditacion);
+   }
 
-        let mut pagos = Vec::new();
+   ///un plan vigente sobrevive a la exportacion e importacion: sus cuotas pagadas y las posiciones de los pagos que
+   ///refinancia siguen apuntando a los mismos pagos, que se saldan al cumplirlo en el club importado
+   #[ink::test]
+   fn exportar_e_importar_plan_cuotas_test(){
+       let mut club = club_con_socio(1, None);
+       club.registrar_socio(31195032, "Kenneth Hutchinson".to_string(), 2, Some(1));
+       club.realizar_pago(31195032, 3000);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       club.pagar_cuota_plan(1, 5000);
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert_eq!(nuevo.get_planes_cuotas(30537974), club.get_planes_cuotas(30537974));
+       nuevo.pagar_cuota_plan(1, 5000);
+       assert_eq!(nuevo.get_planes_cuotas(30537974)[0].get_estado(), EstadoPlan::Cumplido);
+       assert!(nuevo.get_pagos_totales(Some(30537974)).iter().all(|pago| pago.get_fecha_pago() == Some(61.from_dias())), "Debieron saldarse los pagos refinanciados");
+       assert!(nuevo.get_pagos_totales(Some(31195032)).iter().any(|pago| pago.get_fecha_pago().is_none()), "Los pagos de otro socio no debieron saldarse");
+   }
 
-        pagos.push(pago1);
-        pagos.push(pago2);
-        pagos.push(pago3);
-        pagos.push(pago4);
-        pagos.push(pago5);
-        pagos.push(pago6);
+   #[ink::test]
+   #[should_panic(expected = "El monto ingresado no coincide con el de la cuota!")]
+   fn pagar_cuota_plan_monto_incorrecto_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       club.crear_plan_cuotas(30537974, 2, 0);
+       club.pagar_cuota_plan(1, 4000);
+   }
 
-        //creo a los socios
-        //creo un socio categoria b
-        let ape_y_nom_b = "David Starsky".to_string();
-        let dni_b = 30;
-        let cat_b = CategoriasSocios::categoria_from_id(&2).unwrap();
-        let deporte_b = Deporte::deporte_from_id(&Some(2));
-        let starsky = Socio::new(ape_y_nom_b.clone(), dni_b, cat_b.clone(), deporte_b.clone());
+   #[ink::test]
+   #[should_panic(expected = "El socio no tiene pagos vencidos para refinanciar!")]
+   fn crear_plan_cuotas_sin_deuda_vencida_test(){
+       let mut club = club_con_socio(1, None);
+       club.crear_plan_cuotas(30537974, 2, 0);
+   }
 
-        //creo un socio categoria a
-        let ape_y_nom_a = "Kenneth Hutchinson".to_string();
-        let dni_a = 32;
-        let cat_a = CategoriasSocios::categoria_from_id(&1).unwrap();
-        let deporte_a = None;
-        let hutch = Socio::new(ape_y_nom_a.clone(), dni_a, cat_a.clone(), deporte_a.clone());
+   #[ink::test]
+   #[should_panic(expected = "No se cuenta con los permisos necesarios!")]
+   fn crear_plan_cuotas_sin_permisos_test(){
+       let mut club = club_con_socio(1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.crear_plan_cuotas(30537974, 2, 0);
+   }
 
-        //los agrego
-        let mut socios = Vec::new();
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn crear_plan_cuotas_pausado_test(){
+       let mut club = club_pausado();
+       club.crear_plan_cuotas(30537975, 2, 0);
+   }
 
-        socios.push(starsky.clone());
-        socios.push(hutch.clone());
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn pagar_cuota_plan_pausado_test(){
+       let mut club = club_pausado();
+       club.pagar_cuota_plan(1, 5000);
+   }
 
-        //creo los costos 
-        let costos = CostosCategoria::new(5000, 3000, 2000, 1000, 2);
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn procesar_planes_caidos_pausado_test(){
+       let mut club = club_pausado();
+       club.procesar_planes_caidos();
+   }
 
-        //creo al owner y lo seteo como el caller
-        let owner = AccountId::from([0x1; 32]);
-        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+   //tests planes de facturacion
+   ///un socio anual paga doce meses con descuento por adelantado y no recibe cuotas hasta que termina el año
+   #[ink::test]
+   fn plan_facturacion_anual_test(){
+       let mut club = club_con_socio(1, None);
+       club.actualizar_descuento_plan(4, 20);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 1, None);
+       club.cambiar_plan_facturacion(30537974, 4);
+
+       for mes in 1..=13 {
+           ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>((30 * mes).from_dias());
+           club.emitir_pago_mensual();
+       }
+       let pagos_anual = club.get_pagos_totales(Some(30537974));
+       assert_eq!(pagos_anual.len(), 3, "Debio recibir el pago del alta y dos cuotas anuales");
+       assert_eq!(pagos_anual[1].get_monto(), 48000);
+       assert_eq!(pagos_anual[1].get_fecha_venci(), 60.from_dias());
+       assert_eq!(pagos_anual[2].get_fecha_venci(), 420.from_dias());
+       assert_eq!(club.get_pagos_totales(Some(28111222)).len(), 14);
+       assert_eq!(club.get_facturacion_socio(30537974).get_meses_cubiertos(), 11);
+   }
 
-        //creo los permitidos
-        let permitidos = Vec::new();
+   ///el cambio de plan rige recien cuando termina el periodo que cubre la ultima cuota
+   #[ink::test]
+   fn cambiar_plan_facturacion_proximo_ciclo_test(){
+       let mut club = club_con_socio(1, None);
+       club.cambiar_plan_facturacion(30537974, 2);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).last().unwrap().get_monto(), 15000);
+
+       club.cambiar_plan_facturacion(30537974, 1);
+       let facturacion = club.get_facturacion_socio(30537974);
+       assert_eq!(facturacion.get_plan(), PlanFacturacion::Trimestral);
+       assert_eq!(facturacion.get_plan_siguiente(), Some(PlanFacturacion::Mensual));
+       for mes in 2..=4 {
+           ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>((30 * mes).from_dias());
+           club.emitir_pago_mensual();
+       }
+       let pagos = club.get_pagos_totales(Some(30537974));
+       assert_eq!(pagos.len(), 3);
+       assert_eq!(pagos[2].get_monto(), 5000);
+       assert_eq!(club.get_facturacion_socio(30537974).get_plan(), PlanFacturacion::Mensual);
+   }
 
-        //creo la politica de autorizacion
-        let politica_activada = true;
+   #[ink::test]
+   #[should_panic(expected = "El descuento debe ser de un plan de mas de un mes y no superar el 100 por ciento!")]
+   fn actualizar_descuento_plan_mensual_test(){
+       let owner = AccountId::from([0x1; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.actualizar_descuento_plan(1, 10);
+   }
 
-        //creo la ultima emision
-        let emision_ultimo_pago = None;
+   ///un plan de facturacion y sus descuentos sobreviven a la exportacion e importacion, y el socio sigue sin recibir
+   ///cuotas durante los meses que cubre la que ya pago
+   #[ink::test]
+   fn exportar_e_importar_plan_facturacion_test(){
+       let mut club = club_con_socio(1, None);
+       club.actualizar_descuento_plan(4, 20);
+       club.cambiar_plan_facturacion(30537974, 4);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert_eq!(nuevo.get_facturacion_socio(30537974), club.get_facturacion_socio(30537974));
+       assert_eq!(nuevo.descuentos_plan, club.descuentos_plan);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(60.from_dias());
+       nuevo.emitir_pago_mensual();
+       assert_eq!(nuevo.get_pagos_totales(Some(30537974)).len(), 2, "No debio emitirse una cuota dentro del año pagado");
+   }
 
-        //creo el club
-        let club = ClubSemRust {socios, pagos, costos, owner, permitidos, politica_activada, emision_ultimo_pago};
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn cambiar_plan_facturacion_pausado_test(){
+       let mut club = club_pausado();
+       club.cambiar_plan_facturacion(30537974, 4);
+   }
 
-        //llamo al metodo de corroborar_bonificacion, sabiendo que la cantidad de pagos necesarios
-        //para el beneficio, alcanza
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_descuento_plan_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_descuento_plan(4, 20);
+   }
 
-        assert_eq! (club.corroborar_bonificacion(30), false, "No debió otorgar el beneficio");
-    }
+   //tests politica de morosidad
+   ///con pagos vencidos el socio pasa a moroso y luego a suspendido, deja de recibir cuotas y vuelve a activo al saldar su deuda
+   #[ink::test]
+   fn morosidad_por_pagos_vencidos_test(){
+       let mut club = club_con_socio(1, None);
+       club.configurar_politica_morosidad(1, 0, 2, 0);
+       assert_eq!(club.get_estado_socio(30537974), EstadoSocio::Activo);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_estado_socio(30537974), EstadoSocio::Moroso);
+
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       assert_eq!(club.get_estado_socio(30537974), EstadoSocio::Suspendido);
+       assert_eq!(club.get_socios_por_estado(EstadoSocio::Suspendido), vec![30537974]);
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).len(), 2, "A un socio suspendido no debio emitirsele cuota");
+
+       club.realizar_pago(30537974, 5000);
+       club.realizar_pago(30537974, 5000);
+       assert_eq!(club.get_estado_socio(30537974), EstadoSocio::Activo);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(91.from_dias());
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).len(), 3);
+   }
 
-    
-   // test get_socios
+   ///los umbrales por dias de atraso se cuentan desde el vencimiento del pago impago mas antiguo
    #[ink::test]
-   fn get_socios_test(){
-       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None};
-       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol)};
-       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None};
-       let mut socios : Vec<Socio> = Vec::new();
-       socios.push(s1);
-       socios.push(s2);
-       socios.push(s3);
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   fn morosidad_por_dias_de_atraso_test(){
+       let mut club = club_con_socio(1, None);
+       club.configurar_politica_morosidad(0, 5, 0, 20);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(14.from_dias());
+       assert_eq!(club.get_estado_socio(30537974), EstadoSocio::Activo);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(15.from_dias());
+       assert_eq!(club.get_estado_socio(30537974), EstadoSocio::Moroso);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       assert_eq!(club.get_estado_socio(30537974), EstadoSocio::Suspendido);
+   }
+
+   ///el cambio de politica queda en la auditoria con los umbrales anteriores y los nuevos
+   #[ink::test]
+   fn configurar_politica_morosidad_auditoria_test(){
        let owner = AccountId::from([0x1; 32]);
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let club = ClubSemRust{costos, socios:socios.clone(), pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       let socios_devuelto = club.get_socios();
-       assert_eq!(socios_devuelto.clone(), socios.clone(), "Ocurrió un error al esperar el vector de socios {:#?}, se recibió {:#?}.", socios, socios_devuelto);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.configurar_politica_morosidad(1, 0, 2, 0);
+       club.configurar_politica_morosidad(0, 5, 0, 20);
+       let auditoria = club.get_auditoria(None, Some(AccionAdministrativa::CambioPoliticaMorosidad));
+       assert_eq!(auditoria.len(), 2);
+       assert_eq!(auditoria[0].valor_anterior, Some(ValorAuditado::PoliticaMorosidad(PoliticaMorosidad::new(0, 0, 0, 0))));
+       assert_eq!(auditoria[1].valor_anterior, Some(ValorAuditado::PoliticaMorosidad(PoliticaMorosidad::new(1, 0, 2, 0))));
+       assert_eq!(auditoria[1].valor_nuevo, Some(ValorAuditado::PoliticaMorosidad(PoliticaMorosidad::new(0, 5, 0, 20))));
    }
+
    #[ink::test]
-   fn get_socios_vacio_test(){
-       let mut socios : Vec<Socio> = Vec::new();
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "Los umbrales de suspension no pueden ser menores a los de morosidad!")]
+   fn configurar_politica_morosidad_invalida_test(){
        let owner = AccountId::from([0x1; 32]);
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let club = ClubSemRust{costos, socios:socios.clone(), pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       let socios_devuelto = club.get_socios();
-       assert_eq!(socios_devuelto.clone(), socios.clone(), "Ocurrió un error al esperar el vector de socios vacio! Se recibió {:#?}", socios_devuelto);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.configurar_politica_morosidad(3, 0, 2, 0);
    }
+
    #[ink::test]
-   #[should_panic]
-   fn get_socios_sin_permisos_test(){
-       let mut socios : Vec<Socio> = Vec::new();
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "No cuenta con rango Owner para realizar esta operacion!")]
+   fn configurar_politica_morosidad_sin_permisos_test(){
        let owner = AccountId::from([0x1; 32]);
-       let not_owner = AccountId::from([0x2; 32]);
-       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       club.get_socios();
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x2; 32]));
+       club.configurar_politica_morosidad(1, 0, 2, 0);
    }
 
-   // test toggle_politica_autorizacion
    #[ink::test]
-   fn toggle_politica_autorizacion_test(){
-       let mut socios : Vec<Socio> = Vec::new();
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn configurar_politica_morosidad_pausado_test(){
+       let mut club = club_pausado();
+       club.configurar_politica_morosidad(1, 0, 2, 0);
+   }
+
+   //tests estado de cuenta
+   ///el estado de cuenta lista cargos y pagos en orden con el saldo acumulado, y la deuda y vencimientos a la fecha
+   #[ink::test]
+   fn estado_de_cuenta_saldo_acumulado_test(){
        let owner = AccountId::from([0x1; 32]);
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let mut club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       let valor_politica = club.get_politica_autorizacion();
-       assert_eq!(club.toggle_politica_autorizacion(), !valor_politica, "La función no cumplió su cometido!");
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 1);
+       club.registrar_socio(30537974, "David Starsky".to_string(), 1, None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5.from_dias());
+       club.realizar_pago(30537974, 5000);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(40.from_dias());
+
+       let estado = club.estado_de_cuenta(30537974, 0, 40.from_dias());
+       let tipos: Vec<TipoAsiento> = estado.lineas.iter().map(|linea| linea.asiento.get_tipo()).collect();
+       assert_eq!(tipos, vec![TipoAsiento::Cargo, TipoAsiento::Pago, TipoAsiento::Cargo, TipoAsiento::Bonificacion]);
+       let saldos: Vec<i128> = estado.lineas.iter().map(|linea| linea.saldo).collect();
+       assert_eq!(saldos, vec![5000, 0, 5000, 4000]);
+       assert_eq!(estado.saldo_final, 4000);
+       assert_eq!(estado.deuda_total, 4000);
+       assert_eq!(estado.vencido_mas_antiguo, None);
+       assert_eq!(estado.proximo_vencimiento, Some(60.from_dias()));
+
+       //al filtrar por fechas, lo anterior queda resumido en el saldo inicial
+       let estado = club.estado_de_cuenta(30537974, 10.from_dias(), 40.from_dias());
+       assert_eq!(estado.saldo_inicial, 0);
+       assert_eq!(estado.lineas.len(), 2);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61.from_dias());
+       assert_eq!(club.estado_de_cuenta(30537974, 0, 61.from_dias()).vencido_mas_antiguo, Some(60.from_dias()));
+   }
+
+   ///la reversion de un cobro vuelve a sumar el monto a la deuda
+   #[ink::test]
+   fn estado_de_cuenta_reversion_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.revertir_pago(1, "Monto equivocado".to_string(), false);
+       let estado = club.estado_de_cuenta(30537974, 0, 0);
+       assert_eq!(estado.lineas.last().unwrap().asiento.get_tipo(), TipoAsiento::Reversion);
+       assert_eq!(estado.saldo_final, 5000);
+       assert_eq!(estado.deuda_total, 5000);
    }
+
+   ///el titular del carnet puede consultar su propio estado de cuenta sin ser staff
    #[ink::test]
-   #[should_panic]
-   fn toggle_politica_autorizacion_sin_permisos_test(){
-       let mut socios : Vec<Socio> = Vec::new();
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   fn mi_estado_de_cuenta_test(){
+       let cuenta_socio = AccountId::from([0x5; 32]);
+       let mut club = club_con_socio(1, None);
+       club.vincular_titular_carnet(30537974, Some(cuenta_socio));
+
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(cuenta_socio);
+       let estado = club.mi_estado_de_cuenta(0, 0);
+       assert_eq!(estado.dni, 30537974);
+       assert_eq!(estado.deuda_total, 5000);
+       assert_eq!(club.estado_de_cuenta(30537974, 0, 0), estado);
+   }
+
+   ///la cuenta corriente se exporta con la instantanea, por lo que el estado de cuenta del club importado es el mismo
+   #[ink::test]
+   fn exportar_e_importar_estado_de_cuenta_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert_eq!(nuevo.estado_de_cuenta(30537974, 0, 30.from_dias()), club.estado_de_cuenta(30537974, 0, 30.from_dias()));
+       assert_eq!(nuevo.estado_de_cuenta(30537974, 0, 30.from_dias()).lineas.len(), 3);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!")]
+   fn estado_de_cuenta_de_otro_socio_test(){
+       let cuenta_socio = AccountId::from([0x5; 32]);
+       let mut club = club_con_socio(1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 1, None);
+       club.vincular_titular_carnet(30537974, Some(cuenta_socio));
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(cuenta_socio);
+       club.estado_de_cuenta(28111222, 0, 0);
+   }
+
+   //tests libre deuda
+   ///un socio al dia recibe un certificado que cualquiera puede verificar hasta que vence o se revoca
+   #[ink::test]
+   fn emitir_y_verificar_libre_deuda_test(){
        let owner = AccountId::from([0x1; 32]);
-       let not_owner = AccountId::from([0x2; 32]);
-       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let mut club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       club.toggle_politica_autorizacion();
+       let tercero = AccountId::from([0x7; 32]);
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       let certificado = club.emitir_libre_deuda(30537974, [9u8; 32]);
+       assert_eq!(certificado.get_id(), 1);
+       assert_eq!(certificado.hash_dni, CertificadoLibreDeuda::calcular_hash_dni(30537974, &[9u8; 32]));
+       assert_eq!(certificado.valido_hasta, 30.from_dias());
+
+       //cualquier cuenta puede verificarlo, pero no una copia adulterada
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(tercero);
+       assert!(club.verificar_libre_deuda(certificado.clone()));
+       let mut adulterado = certificado.clone();
+       adulterado.valido_hasta = 365.from_dias();
+       assert!(!club.verificar_libre_deuda(adulterado));
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(31.from_dias());
+       assert!(!club.verificar_libre_deuda(certificado.clone()));
+
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(0);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       assert!(club.revocar_libre_deuda(1));
+       assert!(!club.verificar_libre_deuda(certificado));
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El socio tiene pagos pendientes!")]
+   fn emitir_libre_deuda_con_pagos_pendientes_test(){
+       let mut club = club_con_socio(1, None);
+       club.emitir_libre_deuda(30537974, [9u8; 32]);
+   }
+
+   ///el arancel de una reserva que todavia no se sumo a una cuota tambien es deuda del socio
+   #[ink::test]
+   #[should_panic(expected = "El socio tiene reservas pendientes de cobro!")]
+   fn emitir_libre_deuda_con_reserva_sin_cobrar_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       club.reservar_instalacion(cancha, 30537974, 2.from_dias() + 10.from_horas(), 2, false);
+       club.emitir_libre_deuda(30537974, [9u8; 32]);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El certificado ya fue revocado!")]
+   fn revocar_libre_deuda_dos_veces_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.emitir_libre_deuda(30537974, [9u8; 32]);
+       club.revocar_libre_deuda(1);
+       club.revocar_libre_deuda(1);
+   }
+
+   ///sin la sal, el DNI del certificado no puede obtenerse probando valores: cada certificado usa la suya
+   #[ink::test]
+   fn libre_deuda_hash_dni_con_sal_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       let primero = club.emitir_libre_deuda(30537974, [9u8; 32]);
+       let segundo = club.emitir_libre_deuda(30537974, [4u8; 32]);
+       assert_ne!(primero.hash_dni, segundo.hash_dni, "Cada certificado debio ocultar el DNI con su propia sal");
+       assert_ne!(primero.hash_dni, hash_blake2x256(&30537974u32.encode()), "El DNI no debio quedar sin sal");
+       assert_eq!(segundo.hash_dni, CertificadoLibreDeuda::calcular_hash_dni(30537974, &[4u8; 32]));
+   }
+
+   ///los certificados y su vigencia se exportan con la instantanea, y siguen verificandose en el club importado
+   #[ink::test]
+   fn exportar_e_importar_libre_deuda_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.actualizar_vigencia_certificados(60);
+       let certificado = club.emitir_libre_deuda(30537974, [9u8; 32]);
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert!(nuevo.verificar_libre_deuda(certificado));
+       assert_eq!(nuevo.emitir_libre_deuda(30537974, [4u8; 32]).get_id(), 2, "La numeracion debio continuar");
+       assert_eq!(nuevo.vigencia_certificados, 60.from_dias());
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn emitir_libre_deuda_pausado_test(){
+       let mut club = club_pausado();
+       club.emitir_libre_deuda(30537974, [9u8; 32]);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn revocar_libre_deuda_pausado_test(){
+       let mut club = club_pausado();
+       club.revocar_libre_deuda(1);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_vigencia_certificados_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_vigencia_certificados(60);
+   }
+
+   //tests apto medico
+   ///el socio queda habilitado para el deporte solo mientras su apto medico esta vigente
+   #[ink::test]
+   fn apto_medico_habilita_deporte_test(){
+       let mut club = club_con_socio(2, Some(2));
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 3, None);
+       assert!(!club.habilitado_para_deporte(30537974));
+       assert!(club.registrar_apto_medico(30537974, 0, 365.from_dias(), Some(Hash::from([0x9; 32]))));
+       club.registrar_apto_medico(28111222, 0, 365.from_dias(), None);
+       assert!(club.habilitado_para_deporte(30537974));
+       //la categoria C no practica deportes aunque tenga apto
+       assert!(!club.habilitado_para_deporte(28111222));
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(365.from_dias());
+       assert!(!club.habilitado_para_deporte(30537974));
+       assert_eq!(club.get_apto_medico(30537974).unwrap().get_vence(), 365.from_dias());
+   }
+
+   ///los aptos por vencer se listan del mas proximo al mas lejano, sin los ya vencidos
+   #[ink::test]
+   fn get_aptos_por_vencer_test(){
+       let mut club = club_con_socio(2, Some(2));
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 1, None);
+       club.registrar_socio(25000000, "Harold Dobey".to_string(), 1, None);
+       club.registrar_apto_medico(30537974, 0, 20.from_dias(), None);
+       club.registrar_apto_medico(28111222, 0, 10.from_dias(), None);
+       club.registrar_apto_medico(25000000, 0, 100.from_dias(), None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5.from_dias());
+       let dnis: Vec<u32> = club.get_aptos_por_vencer(30).iter().map(|apto| apto.get_dni()).collect();
+       assert_eq!(dnis, vec![28111222, 30537974]);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(15.from_dias());
+       let dnis: Vec<u32> = club.get_aptos_por_vencer(30).iter().map(|apto| apto.get_dni()).collect();
+       assert_eq!(dnis, vec![30537974]);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El vencimiento del apto medico debe ser posterior a su emision!")]
+   fn registrar_apto_medico_vencimiento_invalido_test(){
+       let mut club = club_con_socio(2, Some(2));
+       club.registrar_apto_medico(30537974, 10.from_dias(), 10.from_dias(), None);
+   }
+
+   ///los aptos medicos se exportan con la instantanea, por lo que el socio sigue habilitado en el club importado
+   #[ink::test]
+   fn exportar_e_importar_apto_medico_test(){
+       let mut club = club_con_socio(2, Some(2));
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert!(nuevo.habilitado_para_deporte(30537974));
+       assert_eq!(nuevo.get_apto_medico(30537974), club.get_apto_medico(30537974));
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn registrar_apto_medico_pausado_test(){
+       let mut club = club_pausado();
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+   }
+
+   //tests clases
+   ///con la clase llena el socio queda en espera, y ocupa el lugar de quien abandona
+   #[ink::test]
+   fn inscribir_en_clase_con_espera_test(){
+       let owner = AccountId::from([0x1; 32]);
+       let profesor = AccountId::from([0x6; 32]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       for dni in [30537974, 28111222, 25000000] {
+           club.registrar_socio(dni, "Socio".to_string(), 1, None);
+           club.registrar_apto_medico(dni, 0, 365.from_dias(), None);
+       }
+       let id = club.crear_clase(2, profesor, vec![(1, 18 * 60, 20 * 60), (3, 18 * 60, 20 * 60)], 2);
+       assert_eq!(club.inscribir_en_clase(id, 30537974), EstadoInscripcion::Inscripto);
+       assert_eq!(club.inscribir_en_clase(id, 28111222), EstadoInscripcion::Inscripto);
+       assert_eq!(club.inscribir_en_clase(id, 25000000), EstadoInscripcion::EnEspera(1));
+
+       assert!(club.abandonar_clase(id, 30537974));
+       let clase = club.get_clases(Some(2))[0].clone();
+       assert_eq!(clase.get_inscriptos(), vec![28111222, 25000000]);
+       assert!(clase.get_espera().is_empty());
+       assert!(club.get_clases(Some(3)).is_empty());
    }
 
-   // test es_admin
+   ///al aumentar el cupo pasan a inscriptos los primeros de la lista de espera
    #[ink::test]
-   fn es_admin_es_test(){
-       let mut socios : Vec<Socio> = Vec::new();
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   fn actualizar_cupo_clase_promueve_espera_test(){
        let owner = AccountId::from([0x1; 32]);
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       assert_eq!(club.es_admin(), true, "El caller no es admin!");
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       for dni in [30537974, 28111222] {
+           club.registrar_socio(dni, "Socio".to_string(), 2, Some(5));
+           club.registrar_apto_medico(dni, 0, 365.from_dias(), None);
+       }
+       let id = club.crear_clase(5, owner, vec![(6, 9 * 60, 10 * 60)], 1);
+       club.inscribir_en_clase(id, 30537974);
+       club.inscribir_en_clase(id, 28111222);
+       club.actualizar_cupo_clase(id, 2);
+       assert_eq!(club.get_clases(None)[0].get_inscriptos(), vec![30537974, 28111222]);
    }
+
    #[ink::test]
-   fn es_admin_no_es_test(){
-       let mut socios : Vec<Socio> = Vec::new();
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "La categoria del socio no incluye el deporte de la clase!")]
+   fn inscribir_en_clase_otro_deporte_test(){
        let owner = AccountId::from([0x1; 32]);
-       let not_owner = AccountId::from([0x2; 32]);
-       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       assert_eq!(club.es_admin(), false, "El caller es admin!");
+       let mut club = club_con_socio(2, Some(2));
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let id = club.crear_clase(6, owner, vec![(2, 600, 660)], 10);
+       club.inscribir_en_clase(id, 30537974);
    }
 
-   // test actualizar_costo_beneficio
    #[ink::test]
-   fn actualizar_costo_beneficio_test(){
-       let mut socios : Vec<Socio> = Vec::new();
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "El socio tiene pagos vencidos!")]
+   fn inscribir_en_clase_con_deuda_test(){
        let owner = AccountId::from([0x1; 32]);
-       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let mut club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       let nuevo_costo = 300;
-       assert_eq!(club.actualizar_costo_beneficio(nuevo_costo), true, "No se actualizó!")
+       let mut club = club_con_socio(1, None);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let id = club.crear_clase(1, owner, vec![(2, 600, 660)], 10);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11.from_dias());
+       club.inscribir_en_clase(id, 30537974);
    }
+
    #[ink::test]
-   #[should_panic]
-   fn actualizar_costo_beneficio_sin_permisos_test(){
-       let mut socios : Vec<Socio> = Vec::new();
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "El socio no tiene un apto medico vigente!")]
+   fn inscribir_en_clase_sin_apto_test(){
        let owner = AccountId::from([0x1; 32]);
-       let not_owner = AccountId::from([0x2; 32]);
-       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(not_owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let mut club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       let nuevo_costo = 300;
-       assert_eq!(club.actualizar_costo_beneficio(nuevo_costo), false, "Se actualizó el costo cuando no deberia!")
+       let mut club = club_con_socio(1, None);
+       let id = club.crear_clase(1, owner, vec![(2, 600, 660)], 10);
+       club.inscribir_en_clase(id, 30537974);
    }
 
-   // test existe_socio
+   ///las clases, con sus inscriptos y su lista de espera, se exportan con la instantanea
    #[ink::test]
-   fn existe_socio_test(){
-       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None};
-       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol)};
-       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None};
-       let mut socios : Vec<Socio> = Vec::new();
-       socios.push(s1);
-       socios.push(s2);
-       socios.push(s3);
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   fn exportar_e_importar_clases_test(){
+       let owner = AccountId::from([0x1; 32]);
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let id = club.crear_clase(1, owner, vec![(2, 600, 660)], 10);
+       club.inscribir_en_clase(id, 30537974);
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert_eq!(nuevo.get_clases(None), club.get_clases(None));
+       assert_eq!(nuevo.crear_clase(1, owner, vec![(4, 600, 660)], 10), id + 1, "La numeracion de las clases debio continuar");
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn crear_clase_pausado_test(){
+       let mut club = club_pausado();
+       club.crear_clase(1, AccountId::from([0x1; 32]), vec![(2, 600, 660)], 10);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn inscribir_en_clase_pausado_test(){
+       let mut club = club_pausado();
+       club.inscribir_en_clase(1, 30537974);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn abandonar_clase_pausado_test(){
+       let mut club = club_pausado();
+       club.abandonar_clase(1, 30537974);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_cupo_clase_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_cupo_clase(1, 20);
+   }
+
+   //tests profesores
+   ///el profesor ve y registra la asistencia de su clase sin ser staff
+   #[ink::test]
+   fn profesor_gestiona_su_clase_test(){
+       let profesor = AccountId::from([0x6; 32]);
+       let mut club = club_con_socio(1, None);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       assert!(club.registrar_profesor(profesor, "Marcelo Bielsa".to_string(), vec![1, 1]));
+       assert_eq!(club.get_profesores()[0].get_deportes(), vec![Deporte::Futbol]);
+       let id = club.crear_clase(1, profesor, vec![(2, 600, 660)], 10);
+       club.inscribir_en_clase(id, 30537974);
+
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(profesor);
+       assert_eq!(club.get_clase(id).get_inscriptos(), vec![30537974]);
+       assert_eq!(club.get_mis_clases().len(), 1);
+       assert!(club.registrar_asistencia_clase(id, 30537974, 0));
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!")]
+   fn profesor_clase_ajena_test(){
        let owner = AccountId::from([0x1; 32]);
+       let profesor = AccountId::from([0x6; 32]);
+       let otro_profesor = AccountId::from([0x7; 32]);
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       assert_eq!(club.existe_socio(222), true, "No se encontró el socio cuando el socio si existe!");
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_profesor(profesor, "Marcelo Bielsa".to_string(), vec![1]);
+       club.registrar_profesor(otro_profesor, "Carlos Bianchi".to_string(), vec![1]);
+       let id = club.crear_clase(1, profesor, vec![(2, 600, 660)], 10);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(otro_profesor);
+       club.get_clase(id);
    }
+
+   ///al quitar al profesor pierde el acceso a sus clases
    #[ink::test]
-   fn existe_socio_no_existe_test(){
-       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None};
-       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol)};
-       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None};
-       let mut socios : Vec<Socio> = Vec::new();
-       socios.push(s1);
-       socios.push(s2);
-       socios.push(s3);
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "La cuenta no es un profesor activo!")]
+   fn quitar_profesor_test(){
        let owner = AccountId::from([0x1; 32]);
+       let profesor = AccountId::from([0x6; 32]);
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       assert_eq!(club.existe_socio(7777), false, "Se encontró el socio cuando el socio no existe!");
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_profesor(profesor, "Marcelo Bielsa".to_string(), vec![1]);
+       assert!(club.quitar_profesor(profesor));
+       assert!(!club.get_profesores()[0].is_activo());
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(profesor);
+       club.get_mis_clases();
    }
-   
-   // test registrar_socio
+
+   ///un profesor no tiene los permisos de un staff
    #[ink::test]
-   #[should_panic]
-   fn registrar_socio_ya_existe_test(){
-       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None};
-       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol)};
-       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None};
-       let mut socios : Vec<Socio> = Vec::new();
-       socios.push(s1);
-       socios.push(s2);
-       socios.push(s3);
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "No se cuenta con los permisos necesarios!")]
+   fn profesor_no_es_staff_test(){
        let owner = AccountId::from([0x1; 32]);
+       let profesor = AccountId::from([0x6; 32]);
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let mut club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       let resultado = club.registrar_socio(222, "Giordano Luca".to_string(), 2, Some(3));
+       let mut club = ClubSemRust::new(5000, 3000, 2000, 1000, 3);
+       club.registrar_profesor(profesor, "Marcelo Bielsa".to_string(), vec![1]);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(profesor);
+       club.crear_clase(1, profesor, vec![(2, 600, 660)], 10);
    }
+
+   ///el registro de profesores se exporta con la instantanea, y el profesor conserva el acceso a sus clases
    #[ink::test]
-   #[should_panic]
-   fn registrar_socio_ya_existe_deporte_incorrecto_test(){
-       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None};
-       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol)};
-       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None};
-       let mut socios : Vec<Socio> = Vec::new();
-       socios.push(s1);
-       socios.push(s2);
-       socios.push(s3);
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   fn exportar_e_importar_profesores_test(){
+       let profesor = AccountId::from([0x6; 32]);
+       let mut club = club_con_socio(1, None);
+       club.registrar_profesor(profesor, "Marcelo Bielsa".to_string(), vec![1]);
+       club.crear_clase(1, profesor, vec![(2, 600, 660)], 10);
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert_eq!(nuevo.get_profesores(), club.get_profesores());
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(profesor);
+       assert_eq!(nuevo.get_mis_clases().len(), 1);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn registrar_profesor_pausado_test(){
+       let mut club = club_pausado();
+       club.registrar_profesor(AccountId::from([0x6; 32]), "Marcelo Bielsa".to_string(), vec![1]);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn quitar_profesor_pausado_test(){
+       let mut club = club_pausado();
+       club.quitar_profesor(AccountId::from([0x6; 32]));
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn registrar_asistencia_clase_pausado_test(){
+       let mut club = club_pausado();
+       club.registrar_asistencia_clase(1, 30537974, 0);
+   }
+
+   //tests asistencias
+   ///un dispositivo de acceso registra ingresos, que se cuentan por mes, socio y deporte
+   #[ink::test]
+   fn registrar_ingreso_y_asistencias_mensuales_test(){
        let owner = AccountId::from([0x1; 32]);
+       let molinete = AccountId::from([0x8; 32]);
+       let mut club = club_con_socio(1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 2, Some(5));
+       club.realizar_pago(30537974, 5000);
+       club.realizar_pago(28111222, 3000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       club.registrar_apto_medico(28111222, 0, 365.from_dias(), None);
+       assert!(club.agregar_dispositivo_acceso(molinete));
+
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(molinete);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(40.from_dias());
+       club.registrar_ingreso(30537974, 8, 1.from_dias());
+       club.registrar_ingreso(30537974, 8, 2.from_dias());
+       club.registrar_ingreso(30537974, 1, 3.from_dias());
+       club.registrar_ingreso(28111222, 5, 3.from_dias());
+       club.registrar_ingreso(28111222, 5, 35.from_dias());
+
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let mut club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       let resultado = club.registrar_socio(555, "Cerati Gustavo".to_string(), 2, Some(11));
+       let enero = club.get_asistencias_mensuales(1970, 1, None);
+       assert_eq!(enero, vec![
+           AsistenciasMensuales {dni: 28111222, deporte: Deporte::Natacion, cantidad: 1},
+           AsistenciasMensuales {dni: 30537974, deporte: Deporte::Futbol, cantidad: 1},
+           AsistenciasMensuales {dni: 30537974, deporte: Deporte::Gimnasio, cantidad: 2},
+       ]);
+       assert_eq!(club.get_asistencias_mensuales(1970, 2, Some(30537974)), vec![]);
+       let historial = club.get_asistencias(28111222, 0, 40.from_dias());
+       assert_eq!(historial.iter().map(|asistencia| asistencia.get_fecha()).collect::<Vec<u64>>(), vec![3.from_dias(), 35.from_dias()]);
    }
+
    #[ink::test]
-   #[should_panic]
-   fn registrar_socio_ya_existe_categoria_incorrecta_test(){
-       let s1 = Socio{apellido_y_nombre : "Giordano Luca".to_string(), dni : 222, categoria : CategoriasSocios::A, deporte : None};
-       let s2 = Socio{apellido_y_nombre : "Basterrechea Franco".to_string(), dni : 333, categoria : CategoriasSocios::B, deporte : Some(Deporte::Futbol)};
-       let s3 = Socio{apellido_y_nombre : "Beiserman Nicolas".to_string(), dni : 444, categoria : CategoriasSocios::A, deporte : None};
-       let mut socios : Vec<Socio> = Vec::new();
-       socios.push(s1);
-       socios.push(s2);
-       socios.push(s3);
-       let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-       let pagos : Vec<Pago>= Vec::new();
+   #[should_panic(expected = "El socio tiene pagos vencidos!")]
+   fn registrar_ingreso_con_deuda_test(){
+       let mut club = club_con_socio(1, None);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11.from_dias());
+       club.registrar_ingreso(30537974, 8, 11.from_dias());
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El socio se encuentra suspendido!")]
+   fn registrar_ingreso_suspendido_test(){
+       let mut club = club_con_socio(1, None);
+       club.configurar_politica_morosidad(0, 0, 1, 0);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11.from_dias());
+       club.registrar_ingreso(30537974, 8, 11.from_dias());
+   }
+
+   ///el profesor solo registra ingresos de los inscriptos en sus clases
+   #[ink::test]
+   #[should_panic(expected = "No se cuenta con los permisos necesarios o la politica de autorizacion se encuentra activada!")]
+   fn registrar_ingreso_profesor_ajeno_test(){
+       let profesor = AccountId::from([0x6; 32]);
+       let mut club = club_con_socio(1, None);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       club.registrar_profesor(profesor, "Marcelo Bielsa".to_string(), vec![1]);
+       club.crear_clase(1, profesor, vec![(2, 600, 660)], 10);
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(profesor);
+       club.registrar_ingreso(30537974, 1, 0);
+   }
+
+   ///las asistencias y los dispositivos de acceso se exportan con la instantanea
+   #[ink::test]
+   fn exportar_e_importar_asistencias_test(){
+       let molinete = AccountId::from([0x8; 32]);
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       club.agregar_dispositivo_acceso(molinete);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2.from_dias());
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(molinete);
+       club.registrar_ingreso(30537974, 8, 1.from_dias());
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x1; 32]));
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert_eq!(nuevo.get_asistencias(30537974, 0, 2.from_dias()), club.get_asistencias(30537974, 0, 2.from_dias()));
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(molinete);
+       nuevo.registrar_ingreso(30537974, 8, 2.from_dias());
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(AccountId::from([0x1; 32]));
+       assert_eq!(nuevo.get_asistencias(30537974, 0, 2.from_dias()).len(), 2);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn agregar_dispositivo_acceso_pausado_test(){
+       let mut club = club_pausado();
+       club.agregar_dispositivo_acceso(AccountId::from([0x8; 32]));
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn quitar_dispositivo_acceso_pausado_test(){
+       let mut club = club_pausado();
+       club.quitar_dispositivo_acceso(AccountId::from([0x8; 32]));
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn registrar_ingreso_pausado_test(){
+       let mut club = club_pausado();
+       club.registrar_ingreso(30537974, 8, 0);
+   }
+
+   //tests reservas
+   ///el arancel de una reserva se suma a la proxima cuota del socio, y los turnos superpuestos se rechazan
+   #[ink::test]
+   fn reservar_instalacion_proxima_cuota_test(){
        let owner = AccountId::from([0x1; 32]);
+       let cuenta_socio = AccountId::from([0x5; 32]);
+       let mut club = club_con_socio(2, Some(6));
+       club.realizar_pago(30537974, 3000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       club.vincular_titular_carnet(30537974, Some(cuenta_socio));
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 6, 8, 22, 700);
+
+       ink::env::test::set_caller::<ink::env::DefaultEnvironment>(cuenta_socio);
+       let inicio = 2.from_dias() + 10.from_horas();
+       let reserva = club.reservar_instalacion(cancha, 30537974, inicio, 2, false);
+       assert_eq!(reserva.get_monto(), 1400);
+       assert_eq!(club.get_turnos_ocupados(cancha, 0, 3.from_dias()), vec![(inicio, inicio + 2.from_horas())]);
+
        ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-       let permitidos : Vec<AccountId> = Vec::new();
-       let politica_activada = true;
-       let emision_ultimo_pago = None;
-       let mut club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago};
-       let resultado = club.registrar_socio(555, "Cerati Gustavo".to_string(), 45, Some(3));
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).last().unwrap().get_monto(), 3000 + 1400);
+       assert!(club.get_reservas_socio(30537974)[0].is_cobrada());
    }
+
    #[ink::test]
-   #[should_panic]
-   fn registrar_socio_sin_permisos_test() {
-    let s1 = Socio{apellido_y_nombre : "David Starsky".to_string(), dni : 537, categoria : CategoriasSocios::B, deporte : Some(Deporte::Basquet)};
-    let s2 = Socio{apellido_y_nombre : "Kenneth Hutchinson".to_string(), dni : 540, categoria : CategoriasSocios::B, deporte : Some(Deporte::Basquet)};
-    let s3 = Socio{apellido_y_nombre : "Huggy Bear".to_string(), dni : 440, categoria : CategoriasSocios::A, deporte : None};
-    let mut socios : Vec<Socio> = Vec::new();
-    socios.push(s1);
-    socios.push(s2);
-    socios.push(s3);
-    let costos = CostosCategoria::new(5000, 2000, 1000, 500, 3);
-    let pagos : Vec<Pago>= Vec::new();
-    let owner = AccountId::from([0x1; 32]);
-    ink::env::test::set_caller::<ink::env::DefaultEnvironment>(owner);
-    let permitidos : Vec<AccountId> = Vec::new();
-    let politica_activada = true;
-    let emision_ultimo_pago = None;
-    let mut club = ClubSemRust{costos, socios, pagos, owner, permitidos, politica_activada, emision_ultimo_pago}; 
-    let no_permitido = AccountId::from([0x2; 32]);
-    ink::env::test::set_caller::<ink::env::DefaultEnvironment>(no_permitido);
-    let resultado = club.registrar_socio(982, "Michael Knight".to_string(), 1, None);
+   #[should_panic(expected = "El turno se superpone con otra reserva de la instalacion!")]
+   fn reservar_instalacion_superpuesta_test(){
+       let mut club = club_con_socio(1, None);
+       club.registrar_socio(28111222, "Ken Hutchinson".to_string(), 1, None);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       club.registrar_apto_medico(28111222, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       club.reservar_instalacion(cancha, 30537974, 1.from_dias() + 10.from_horas(), 2, false);
+       club.reservar_instalacion(cancha, 28111222, 1.from_dias() + 11.from_horas(), 1, false);
    }
-   //test ConversionFechas
+
+   ///el cobro inmediato emite el recibo en el momento, y cancelar a tiempo libera el turno
    #[ink::test]
-   fn conversion_fechas_test(){
-       assert_eq!(0.from_segundos(), 0);
-       assert_eq!(1.from_segundos(), 1000);
-       assert_eq!(10.from_segundos(), 10000);
-       assert_eq!(0.from_horas(), 0);
-       assert_eq!(1.from_horas(), 3600000);
-       assert_eq!(10.from_horas(), 36000000);
-       assert_eq!(0.from_dias(), 0);
-       assert_eq!(1.from_dias(), 86400000);
-       assert_eq!(10.from_dias(), 864000000);
-       assert_eq!(0.from_semanas(), 0);
-       assert_eq!(1.from_semanas(), 604800000);
-       assert_eq!(10.from_semanas(), 6048000000);
-       assert_eq!(0.from_meses(), 0);
-       assert_eq!(1.from_meses(), 2629743000);
-       assert_eq!(10.from_meses(), 26297430000);
-       assert_eq!(0.from_anios(), 0);
-       assert_eq!(1.from_anios(), 31556926000);
-       assert_eq!(10.from_anios(), 315569260000);
+   fn reservar_instalacion_cobro_inmediato_y_cancelacion_test(){
+       let mut club = club_con_socio(1, None);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       let inicio = 2.from_dias() + 9.from_horas();
+       let reserva = club.reservar_instalacion(cancha, 30537974, inicio, 1, true);
+       assert!(reserva.is_cobrada());
+       assert_eq!(club.get_recibos_socio(30537974).last().unwrap().monto, 700);
+       assert!(club.cancelar_reserva(reserva.get_id()));
+       assert!(club.get_turnos_ocupados(cancha, 0, 3.from_dias()).is_empty());
+       club.reservar_instalacion(cancha, 30537974, inicio, 1, false);
    }
-   //tests deporte
+
    #[ink::test]
-   fn deporte_from_id_test(){
-       assert_eq!(Deporte::deporte_from_id(&Some(1)), Some(Deporte::Futbol));
-       assert_eq!(Deporte::deporte_from_id(&Some(2)), Some(Deporte::Basquet));
-       assert_eq!(Deporte::deporte_from_id(&Some(3)), Some(Deporte::Rugby));
-       assert_eq!(Deporte::deporte_from_id(&Some(4)), Some(Deporte::Hockey));
-       assert_eq!(Deporte::deporte_from_id(&Some(5)), Some(Deporte::Natacion));
-       assert_eq!(Deporte::deporte_from_id(&Some(6)), Some(Deporte::Tenis));
-       assert_eq!(Deporte::deporte_from_id(&Some(7)), Some(Deporte::Paddle));
-       assert_eq!(Deporte::deporte_from_id(&Some(8)), Some(Deporte::Gimnasio));
-       assert_eq!(Deporte::deporte_from_id(&None), None);
+   #[should_panic(expected = "Vencio el plazo para cancelar la reserva!")]
+   fn cancelar_reserva_fuera_de_plazo_test(){
+       let mut club = club_con_socio(1, None);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       club.reservar_instalacion(cancha, 30537974, 1.from_dias() + 9.from_horas(), 1, false);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1.from_dias());
+       club.cancelar_reserva(1);
    }
 
    #[ink::test]
-   #[should_panic]
-   fn deporte_from_id_incorrecta_test(){
-       let dep = Deporte::deporte_from_id(&Some(10));
+   #[should_panic(expected = "El socio tiene pagos vencidos!")]
+   fn reservar_instalacion_con_deuda_test(){
+       let mut club = club_con_socio(1, None);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11.from_dias());
+       club.reservar_instalacion(cancha, 30537974, 12.from_dias() + 9.from_horas(), 1, false);
+   }
+
+   ///revertir el cobro inmediato de una reserva la cancela y acredita su arancel, sin dejar deuda
+   #[ink::test]
+   fn revertir_pago_reserva_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       let reserva = club.reservar_instalacion(cancha, 30537974, 2.from_dias() + 9.from_horas(), 1, true);
+       assert_eq!(club.get_recibo(2).unwrap().get_origen(), OrigenRecibo::Reserva(reserva.get_id()));
+
+       let pago = club.revertir_pago(2, "El pago fue rechazado por el banco".to_string(), false);
+       assert_eq!(pago.get_monto(), 700);
+       assert!(club.get_reservas_socio(30537974)[0].is_cancelada());
+       assert!(club.get_turnos_ocupados(cancha, 0, 3.from_dias()).is_empty());
+       assert_eq!(club.estado_de_cuenta(30537974, 0, 0).saldo_final, 0);
+   }
+
+   ///si la reserva ya estaba cancelada, revertir su cobro consume el reintegro pendiente que habia generado
+   #[ink::test]
+   fn revertir_pago_reserva_cancelada_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       let reserva = club.reservar_instalacion(cancha, 30537974, 2.from_dias() + 9.from_horas(), 1, true);
+       club.cancelar_reserva(reserva.get_id());
+       assert_eq!(club.reintegros_pendientes, vec![(30537974, 700)]);
+
+       club.revertir_pago(2, "El pago fue rechazado por el banco".to_string(), false);
+       assert!(club.reintegros_pendientes.is_empty());
+       assert_eq!(club.estado_de_cuenta(30537974, 0, 0).saldo_final, 0);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).last().unwrap().get_monto(), 5000);
+   }
+
+   ///al renunciar, lo cobrado de sus reservas futuras y su reintegro pendiente quedan como reintegro en su cuenta corriente
+   #[ink::test]
+   fn registrar_renuncia_con_reservas_cobradas_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       let cancelada = club.reservar_instalacion(cancha, 30537974, 2.from_dias() + 9.from_horas(), 1, true);
+       club.cancelar_reserva(cancelada.get_id());
+       club.reservar_instalacion(cancha, 30537974, 3.from_dias() + 9.from_horas(), 1, true);
+       club.reservar_instalacion(cancha, 30537974, 4.from_dias() + 9.from_horas(), 1, false);
+
+       club.registrar_renuncia(30537974);
+       assert!(club.reintegros_pendientes.is_empty());
+       let reintegros: Vec<u128> = club.asientos.iter().filter(|asiento| (asiento.dni == 30537974) && (asiento.tipo == TipoAsiento::Reintegro)).map(|asiento| asiento.monto).collect();
+       assert_eq!(reintegros, vec![1400]);
+       let saldo: i128 = club.asientos.iter().filter(|asiento| asiento.dni == 30537974)
+           .map(|asiento| if asiento.tipo.es_debito() { asiento.monto as i128 } else { -(asiento.monto as i128) }).sum();
+       assert_eq!(saldo, -1400, "El club debio quedar debiendole al socio lo cobrado");
+   }
+
+   ///si el plan anual cubre el mes, el arancel de la reserva se emite igual en un pago aparte
+   #[ink::test]
+   fn reservar_instalacion_plan_anual_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       club.cambiar_plan_facturacion(30537974, 4);
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       let anual = club.get_pagos_totales(Some(30537974)).last().unwrap().get_monto();
+       club.realizar_pago(30537974, anual);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       club.reservar_instalacion(cancha, 30537974, 40.from_dias() + 9.from_horas(), 1, false);
+
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(60.from_dias());
+       club.emitir_pago_mensual();
+       let pagos = club.get_pagos_totales(Some(30537974));
+       assert_eq!(pagos.len(), 3);
+       assert_eq!(pagos[2].get_monto(), 700);
+       assert_eq!(pagos[2].get_fecha_venci(), 90.from_dias());
+       assert!(club.get_reservas_socio(30537974)[0].is_cobrada());
+   }
+
+   ///al socio suspendido no se le emite cuota, pero si el arancel de sus reservas
+   #[ink::test]
+   fn reservar_instalacion_socio_suspendido_test(){
+       let mut club = club_con_socio(1, None);
+       club.configurar_politica_morosidad(0, 0, 1, 0);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       club.reservar_instalacion(cancha, 30537974, 5.from_dias() + 9.from_horas(), 1, false);
+
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       let pagos = club.get_pagos_totales(Some(30537974));
+       assert_eq!(pagos.len(), 2);
+       assert_eq!(pagos[1].get_monto(), 700);
+   }
+
+   ///cancelar una reserva ya cobrada descuenta su arancel del proximo pago del socio
+   #[ink::test]
+   fn cancelar_reserva_cobrada_reintegro_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       let reserva = club.reservar_instalacion(cancha, 30537974, 2.from_dias() + 9.from_horas(), 1, true);
+       club.cancelar_reserva(reserva.get_id());
+
+       ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(30.from_dias());
+       club.emitir_pago_mensual();
+       assert_eq!(club.get_pagos_totales(Some(30537974)).last().unwrap().get_monto(), 5000 - 700);
+       assert!(club.asientos.iter().any(|asiento| (asiento.tipo == TipoAsiento::Reintegro) && (asiento.monto == 700)));
+       assert!(club.reintegros_pendientes.is_empty());
+   }
+
+   ///las instalaciones, las reservas, el plazo de cancelacion y los reintegros pendientes se exportan con la instantanea
+   #[ink::test]
+   fn exportar_e_importar_reservas_test(){
+       let mut club = club_con_socio(1, None);
+       club.realizar_pago(30537974, 5000);
+       club.registrar_apto_medico(30537974, 0, 365.from_dias(), None);
+       club.actualizar_plazo_cancelacion(48);
+       let cancha = club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+       let cobrada = club.reservar_instalacion(cancha, 30537974, 3.from_dias() + 9.from_horas(), 1, true);
+       club.reservar_instalacion(cancha, 30537974, 4.from_dias() + 9.from_horas(), 2, false);
+       club.cancelar_reserva(cobrada.get_id());
+       let (metadatos, chunks) = exportar_completa(&club, 64);
+
+       let mut nuevo = ClubSemRust::new(0, 0, 0, 0, 0);
+       nuevo.iniciar_importacion(metadatos);
+       for (indice, chunk) in chunks.into_iter().enumerate() {
+           nuevo.importar_chunk(indice as u32, chunk);
+       }
+       nuevo.finalizar_importacion();
+       assert_eq!(nuevo.get_instalaciones(), club.get_instalaciones());
+       assert_eq!(nuevo.get_reservas_socio(30537974), club.get_reservas_socio(30537974));
+       assert_eq!(nuevo.get_turnos_ocupados(cancha, 0, 5.from_dias()), vec![(4.from_dias() + 9.from_horas(), 4.from_dias() + 11.from_horas())]);
+       assert_eq!(nuevo.plazo_cancelacion, 48.from_horas());
+       assert_eq!(nuevo.reintegros_pendientes, vec![(30537974, 700)]);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn crear_instalacion_pausado_test(){
+       let mut club = club_pausado();
+       club.crear_instalacion("Cancha 1".to_string(), 7, 8, 22, 700);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn actualizar_plazo_cancelacion_pausado_test(){
+       let mut club = club_pausado();
+       club.actualizar_plazo_cancelacion(48);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn reservar_instalacion_pausado_test(){
+       let mut club = club_pausado();
+       club.reservar_instalacion(1, 30537974, 1.from_dias() + 9.from_horas(), 1, false);
+   }
+
+   #[ink::test]
+   #[should_panic(expected = "El contrato se encuentra pausado!")]
+   fn cancelar_reserva_pausado_test(){
+       let mut club = club_pausado();
+       club.cancelar_reserva(1);
    }
     }
-}
\ No newline at end of file
+
+    #[cfg(all(test, feature = "e2e-tests"))]
+    mod e2e_tests {
+        use super::*;
+        use ink_e2e::build_message;
+        use psp22_prueba::psp22_prueba::Psp22PruebaRef;
+
+        type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+        ///despliego el token de prueba y el club, registro un socio y cobro su primer pago en token desde la cuenta de bob,
+        ///primero sin autorizacion (el pago sigue pendiente) y luego con ella
+        #[ink_e2e::test(additional_contracts = "psp22_prueba/Cargo.toml")]
+        async fn pagar_con_token_e2e(mut client: ink_e2e::Client<C, E>) -> E2EResult<()> {
+            let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+            let token = client.instantiate("psp22_prueba", &ink_e2e::alice(), Psp22PruebaRef::new(1_000_000), 0, None)
+                .await.expect("fallo el despliegue del token").account_id;
+            let club = client.instantiate("ClubSemRust", &ink_e2e::alice(), ClubSemRustRef::new(5000, 3000, 2000, 1000, 3), 0, None)
+                .await.expect("fallo el despliegue del club").account_id;
+
+            let configurar = build_message::<ClubSemRustRef>(club.clone()).call(|club| club.configurar_token_pago(Some(token)));
+            client.call(&ink_e2e::alice(), configurar, 0, None).await.expect("fallo configurar_token_pago");
+            let registrar = build_message::<ClubSemRustRef>(club.clone()).call(|club| club.registrar_socio(30537974, "David Starsky".to_string(), 2, Some(2)));
+            client.call(&ink_e2e::alice(), registrar, 0, None).await.expect("fallo registrar_socio");
+            let fondear = build_message::<Psp22PruebaRef>(token.clone()).call(|token| token.transfer(bob, 10_000, Vec::new()));
+            client.call(&ink_e2e::alice(), fondear, 0, None).await.expect("fallo transfer");
+
+            //sin autorizacion, el token rechaza el debito
+            let pagar = build_message::<ClubSemRustRef>(club.clone()).call(|club| club.pagar_con_token(30537974));
+            let rechazo = client.call_dry_run(&ink_e2e::bob(), &pagar, 0, None).await;
+            assert_eq!(rechazo.return_value(), Err(ErrorPagoToken::AutorizacionInsuficiente));
+
+            let autorizar = build_message::<Psp22PruebaRef>(token.clone()).call(|token| token.approve(club, 3000));
+            client.call(&ink_e2e::bob(), autorizar, 0, None).await.expect("fallo approve");
+            let pago = client.call(&ink_e2e::bob(), pagar, 0, None).await.expect("fallo pagar_con_token").return_value();
+            assert_eq!(pago.map(|pago| pago.get_fecha_pago().is_some()), Ok(true));
+
+            let saldo_club = build_message::<Psp22PruebaRef>(token.clone()).call(|token| token.balance_of(club));
+            assert_eq!(client.call_dry_run(&ink_e2e::alice(), &saldo_club, 0, None).await.return_value(), 3000);
+            let saldo_bob = build_message::<Psp22PruebaRef>(token.clone()).call(|token| token.balance_of(bob));
+            assert_eq!(client.call_dry_run(&ink_e2e::alice(), &saldo_bob, 0, None).await.return_value(), 7000);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,342 @@
+//! Núcleo de facturación del Club SemRust: las reglas de cobro del club como funciones puras, sin depender de ink ni
+//! del entorno del contrato. El contrato ClubSemRust es un envoltorio de estas reglas, y cualquier otra herramienta
+//! (simuladores, reportes) puede reutilizarlas para obtener exactamente los mismos resultados.
+//!
+//! Todas las fechas son timestamps en milisegundos, como el block_timestamp del contrato.
+#![cfg_attr(not(test), no_std)]
+#![allow(clippy::needless_return, clippy::wrong_self_convention)]
+
+///El trait ConversionFecha se encarga de facilitar el trabajo en el manejo del tiempo con milisegundos.
+///Permite la traducción de una manera sencilla del Timestamp del entonrno a las distintas medidas de tiempo que
+///puedan ser necesarias al momento de manejar el tiempo.   
+pub trait ConversionFecha {
+    // Es llamado por el tipo que implemente el trait y devuelve los n segundos correspondientes
+    fn from_segundos (&self) -> u64;
+    // Es llamado por el tipo que implemente el trait y devuelve los n minutos correspondientes
+    fn from_horas (&self) -> u64;
+    // Es llamado por el tipo que implemente el trait y devuelve las n horas correspondientes
+    fn from_dias (&self) -> u64;
+    // Es llamado por el tipo que implemente el trait y devuelve los n dias correspondientes
+    fn from_semanas (&self) -> u64;
+    // Es llamado por el tipo que implemente el trait y devuelve las n semanas correspondientes
+    fn from_meses (&self) -> u64;
+    // Es llamado por el tipo que implemente el trait y devuelve los n años correspondientes
+    fn from_anios (&self) -> u64;
+}
+
+/// Implementacion del trait ConversionFecha para el u64, mismo tipo de dato del Timestamp del entorno
+/// para realizar todas las operaciones necesarias. A partir del calculo de los minutos hacia las unidades mas significativas
+/// se decidió reutilizar la funcion from_segundos para mejorar la legibilidad de las formulas empleadas. Se adapta la formula para el caso de la conversion
+/// a meses y años, debido a que existen diferentes cantidades para las mismas unidades (28, 29, 30 o 31 dias para 1 mes y diferentes cantidades de dias 
+/// en la composicion de 1 año).
+impl ConversionFecha for u64 {
+    /// Se multiplica el numero que llama el metodo por 1000, devolviendo el resultado 
+     fn from_segundos (&self) -> u64 {
+         return self * 1000;
+     }   
+      
+     ///Se multiplica el resultado de los segundos correspondientes
+     ///por 3600 se obtiene la cantidad de horas desde el numero disparador del metodo. 
+     fn from_horas (&self) -> u64 {
+         return self.from_segundos() * 3600;
+     }
+      
+     ///Se multiplica el resultado de los segundos correspondientes
+     ///por 86400 se obtiene la cantidad de dias desde el numero disparador del metodo.
+     fn from_dias (&self) -> u64 {
+         return self.from_segundos() * 86400;
+     }
+        
+     ///Se multiplica el resultado de los segundos correspondientes
+     ///por 86400 se obtiene la cantidad de semanas desde el numero disparador del metodo.
+     fn from_semanas (&self) -> u64 {
+         return self.from_segundos() * 604800;
+     }
+     
+     ///Se multiplica el numero disparador del metodo por 2629743, devolviendo el numero de meses correspondiente donde 1 Mes = 30.44 días 
+     fn from_meses (&self) -> u64 {
+         return self.from_segundos() * 2629743;
+     }
+     
+     ///Se multiplica el numero disparador del metodo por 2629743, devolviendo el numero de años correspondiente donde 1 Año = 365.24 días 
+     fn from_anios (&self) -> u64 {
+         return self.from_segundos() * 31556926;
+     }
+ }
+
+/// Errores de la aritmética de montos y fechas: el resultado no entra en el tipo (Desbordamiento) o una resta daría
+/// un valor negativo (ResultadoNegativo).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorAritmetico {
+    Desbordamiento,
+    ResultadoNegativo,
+}
+
+/// Un monto de dinero, en la misma unidad que los costos del club. Sus operaciones nunca desbordan en silencio: las
+/// verificadas devuelven un ErrorAritmetico y las saturadas se detienen en 0 o en el máximo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Monto(pub u128);
+
+impl Monto {
+    /// Retorna el monto como u128
+    pub fn valor (self) -> u128 {
+        return self.0;
+    }
+
+    /// Suma otro monto, o Desbordamiento si el resultado no entra en un u128
+    pub fn sumar (self, otro: Monto) -> Result<Monto, ErrorAritmetico> {
+        return self.0.checked_add(otro.0).map(Monto).ok_or(ErrorAritmetico::Desbordamiento);
+    }
+
+    /// Resta otro monto, o ResultadoNegativo si otro es mayor
+    pub fn restar (self, otro: Monto) -> Result<Monto, ErrorAritmetico> {
+        return self.0.checked_sub(otro.0).map(Monto).ok_or(ErrorAritmetico::ResultadoNegativo);
+    }
+
+    /// Multiplica el monto por factor, o Desbordamiento si el resultado no entra en un u128
+    pub fn multiplicar (self, factor: u128) -> Result<Monto, ErrorAritmetico> {
+        return self.0.checked_mul(factor).map(Monto).ok_or(ErrorAritmetico::Desbordamiento);
+    }
+
+    /// Suma otro monto, quedándose en el máximo si el resultado no entra en un u128
+    pub fn sumar_saturado (self, otro: Monto) -> Monto {
+        return Monto(self.0.saturating_add(otro.0));
+    }
+
+    /// Resta otro monto, quedándose en 0 si otro es mayor
+    pub fn restar_saturado (self, otro: Monto) -> Monto {
+        return Monto(self.0.saturating_sub(otro.0));
+    }
+}
+
+/// Un instante en milisegundos, como el block_timestamp del contrato. Al igual que Monto, sus operaciones verificadas
+/// devuelven un ErrorAritmetico y las saturadas se detienen en el máximo.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub struct Timestamp(pub u64);
+
+impl Timestamp {
+    /// Retorna el instante como u64
+    pub fn valor (self) -> u64 {
+        return self.0;
+    }
+
+    /// Suma una duración en milisegundos, o Desbordamiento si el resultado no entra en un u64
+    pub fn sumar (self, duracion: u64) -> Result<Timestamp, ErrorAritmetico> {
+        return self.0.checked_add(duracion).map(Timestamp).ok_or(ErrorAritmetico::Desbordamiento);
+    }
+
+    /// Suma una cantidad de dias, o Desbordamiento si la duración o el resultado no entran en un u64
+    pub fn sumar_dias (self, dias: u64) -> Result<Timestamp, ErrorAritmetico> {
+        return self.sumar(duracion_dias(dias)?);
+    }
+
+    /// Suma una duración en milisegundos, quedándose en el máximo si el resultado no entra en un u64
+    pub fn sumar_saturado (self, duracion: u64) -> Timestamp {
+        return Timestamp(self.0.saturating_add(duracion));
+    }
+
+    /// Milisegundos transcurridos desde anterior, o ResultadoNegativo si anterior es posterior a este instante
+    pub fn transcurrido_desde (self, anterior: Timestamp) -> Result<u64, ErrorAritmetico> {
+        return self.0.checked_sub(anterior.0).ok_or(ErrorAritmetico::ResultadoNegativo);
+    }
+}
+
+/// Duración en milisegundos de una cantidad de dias. A diferencia de ConversionFecha::from_dias, devuelve Desbordamiento
+/// en lugar de desbordar si la cantidad es demasiado grande.
+pub fn duracion_dias (dias: u64) -> Result<u64, ErrorAritmetico> {
+    return dias.checked_mul(1.from_dias()).ok_or(ErrorAritmetico::Desbordamiento);
+}
+
+/// Convierte un timestamp en milisegundos al año y mes calendario UTC que le corresponden, con el algoritmo de días
+/// a fecha civil de Howard Hinnant.
+pub fn anio_y_mes (milisegundos: u64) -> (u32, u8) {
+    let dias = milisegundos / 1.from_dias() + 719_468;
+    let era = dias / 146_097;
+    let dia_de_era = dias % 146_097;
+    let anio_de_era = (dia_de_era - dia_de_era / 1460 + dia_de_era / 36_524 - dia_de_era / 146_096) / 365;
+    let dia_del_anio = dia_de_era - (365 * anio_de_era + anio_de_era / 4 - anio_de_era / 100);
+    let mes_desde_marzo = (5 * dia_del_anio + 2) / 153;
+    let mes = if mes_desde_marzo < 10 { mes_desde_marzo + 3 } else { mes_desde_marzo - 9 };
+    let anio = anio_de_era + era * 400 + if mes <= 2 { 1 } else { 0 };
+    return (anio as u32, mes as u8);
+}
+
+/// Dias desde el alta de un socio hasta el vencimiento de su primer pago.
+pub const DIAS_VENCIMIENTO_ALTA: u64 = 10;
+/// Dias desde la emisión de una cuota mensual hasta su vencimiento.
+pub const DIAS_VENCIMIENTO_CUOTA: u64 = 30;
+/// Dias que deben pasar entre dos emisiones de cuotas mensuales.
+pub const DIAS_ENTRE_EMISIONES: u64 = 30;
+
+/// El trait PagoFacturable expone lo que las reglas de facturación necesitan saber de un pago, para que cada
+/// herramienta pueda usar su propio tipo de pago. Lo implementa el Pago del contrato.
+pub trait PagoFacturable {
+    // DNI del socio al que pertenece el pago
+    fn dni (&self) -> u32;
+    // Monto a pagar
+    fn monto (&self) -> u128;
+    // Fecha de vencimiento
+    fn fecha_venci (&self) -> u64;
+    // Fecha en que se efectuó el pago, None si está pendiente
+    fn fecha_pago (&self) -> Option<u64>;
+    // Si el pago fue bonificado
+    fn bonificado (&self) -> bool;
+    // Registra el pago como efectuado en la fecha recibida
+    fn marcar_pagado (&mut self, fecha_pago: u64);
+}
+
+/// Devuelve el monto de una cuota de una categoria cuyo costo es costo_categoria: el costo completo, o el costo menos
+/// el beneficio si la cuota es bonificada. Si el beneficio supera el costo, devuelve ResultadoNegativo.
+pub fn precio_cuota (costo_categoria: u128, beneficio: u128, bonificado: bool) -> Result<u128, ErrorAritmetico> {
+    if bonificado {
+        return Monto(costo_categoria).restar(Monto(beneficio)).map(Monto::valor);
+    }
+    return Ok (costo_categoria);
+}
+
+/// Decide si la próxima cuota de un socio debe ser bonificada, a partir de sus pagos en el orden en que fueron emitidos.
+/// Corresponde la bonificación si el socio tiene al menos pagos_consecutivos pagos, y los últimos pagos_consecutivos
+/// fueron pagados a término y ninguno de ellos fue bonificado. Sin pagos, nunca corresponde.
+pub fn corresponde_bonificacion<P: PagoFacturable> (pagos_del_socio: &[P], pagos_consecutivos: u8) -> bool {
+    let necesarios = pagos_consecutivos as usize;
+    if pagos_del_socio.is_empty() || (pagos_del_socio.len() < necesarios) {
+        return false;
+    }
+    return pagos_del_socio.iter().rev().take(necesarios).all(|pago| {
+        match pago.fecha_pago() {
+            Some (fecha_pago) => (fecha_pago <= pago.fecha_venci()) && (!pago.bonificado()),
+            None => false,
+        }
+    });
+}
+
+/// Vencimiento del primer pago de un socio dado de alta en fecha_alta, o Desbordamiento si no entra en un u64.
+pub fn vencimiento_alta (fecha_alta: u64) -> Result<u64, ErrorAritmetico> {
+    return Timestamp(fecha_alta).sumar_dias(DIAS_VENCIMIENTO_ALTA).map(Timestamp::valor);
+}
+
+/// Vencimiento de una cuota mensual emitida en fecha_emision, o Desbordamiento si no entra en un u64.
+pub fn vencimiento_cuota (fecha_emision: u64) -> Result<u64, ErrorAritmetico> {
+    return Timestamp(fecha_emision).sumar_dias(DIAS_VENCIMIENTO_CUOTA).map(Timestamp::valor);
+}
+
+/// Indica si, habiendo sido la última emisión de cuotas en ultima_emision, ya se puede emitir en la fecha ahora. Si la
+/// próxima emisión no entra en un u64, nunca se puede emitir.
+pub fn puede_emitir (ultima_emision: u64, ahora: u64) -> bool {
+    return Timestamp(ultima_emision).sumar_dias(DIAS_ENTRE_EMISIONES).is_ok_and(|proxima| Timestamp(ahora) >= proxima);
+}
+
+/// Busca el pago al que corresponde aplicar un cobro de monto para el socio dni: el primer pago pendiente de ese socio
+/// con exactamente ese monto, entre las posiciones que disponible acepte. Devuelve su posición, o None si no hay.
+pub fn posicion_pago_a_aplicar<P: PagoFacturable> (pagos: &[P], dni: u32, monto: u128, disponible: impl Fn(usize) -> bool) -> Option<usize> {
+    return pagos.iter().enumerate()
+        .find(|(pos, pago)| (pago.dni() == dni) && (pago.monto() == monto) && (pago.fecha_pago().is_none()) && disponible(*pos))
+        .map(|(pos, _)| pos);
+}
+
+/// Aplica un cobro de monto para el socio dni en fecha_pago: marca como pagado el pago que indica
+/// posicion_pago_a_aplicar y devuelve su posición, o None si el socio no tiene un pago pendiente con ese monto.
+pub fn aplicar_pago<P: PagoFacturable> (pagos: &mut [P], dni: u32, monto: u128, fecha_pago: u64) -> Option<usize> {
+    let pos = posicion_pago_a_aplicar(pagos, dni, monto, |_| true)?;
+    pagos[pos].marcar_pagado(fecha_pago);
+    return Some (pos);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct PagoPrueba {
+        dni: u32,
+        monto: u128,
+        fecha_venci: u64,
+        fecha_pago: Option<u64>,
+        bonificado: bool,
+    }
+
+    impl PagoFacturable for PagoPrueba {
+        fn dni (&self) -> u32 { self.dni }
+        fn monto (&self) -> u128 { self.monto }
+        fn fecha_venci (&self) -> u64 { self.fecha_venci }
+        fn fecha_pago (&self) -> Option<u64> { self.fecha_pago }
+        fn bonificado (&self) -> bool { self.bonificado }
+        fn marcar_pagado (&mut self, fecha_pago: u64) { self.fecha_pago = Some (fecha_pago); }
+    }
+
+    fn pago (dni: u32, monto: u128, fecha_venci: u64, fecha_pago: Option<u64>, bonificado: bool) -> PagoPrueba {
+        return PagoPrueba {dni, monto, fecha_venci, fecha_pago, bonificado};
+    }
+
+    #[test]
+    fn precio_cuota_test() {
+        assert_eq!(precio_cuota(3000, 1000, false), Ok (3000));
+        assert_eq!(precio_cuota(3000, 1000, true), Ok (2000));
+        //un beneficio mayor al costo es un error, no un monto desbordado
+        assert_eq!(precio_cuota(500, 1000, true), Err (ErrorAritmetico::ResultadoNegativo));
+        assert_eq!(precio_cuota(500, 1000, false), Ok (500));
+    }
+
+    #[test]
+    fn monto_y_timestamp_test() {
+        assert_eq!(Monto(u128::MAX).sumar(Monto(1)), Err (ErrorAritmetico::Desbordamiento));
+        assert_eq!(Monto(u128::MAX).sumar_saturado(Monto(1)), Monto(u128::MAX));
+        assert_eq!(Monto(1).restar(Monto(2)), Err (ErrorAritmetico::ResultadoNegativo));
+        assert_eq!(Monto(1).restar_saturado(Monto(2)), Monto(0));
+        assert_eq!(Monto(u128::MAX / 2 + 1).multiplicar(2), Err (ErrorAritmetico::Desbordamiento));
+        assert_eq!(Monto(300).multiplicar(2), Ok (Monto(600)));
+        assert_eq!(Timestamp(u64::MAX).sumar(1), Err (ErrorAritmetico::Desbordamiento));
+        assert_eq!(Timestamp(u64::MAX).sumar_saturado(1), Timestamp(u64::MAX));
+        assert_eq!(Timestamp(0).sumar_dias(u64::MAX), Err (ErrorAritmetico::Desbordamiento));
+        assert_eq!(Timestamp(5).transcurrido_desde(Timestamp(10)), Err (ErrorAritmetico::ResultadoNegativo));
+        assert_eq!(vencimiento_cuota(u64::MAX), Err (ErrorAritmetico::Desbordamiento));
+        assert!(!puede_emitir(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn anio_y_mes_test() {
+        assert_eq!(anio_y_mes(0), (1970, 1));
+        //29 de febrero de 2024 y 1 de marzo de 2024
+        assert_eq!(anio_y_mes(1_709_164_800_000), (2024, 2));
+        assert_eq!(anio_y_mes(1_709_251_200_000), (2024, 3));
+        assert_eq!(anio_y_mes(1_735_689_599_999), (2024, 12));
+    }
+
+    #[test]
+    fn corresponde_bonificacion_test() {
+        let mut pagos = vec![pago(1, 3000, 10, Some(5), false), pago(1, 3000, 20, Some(20), false)];
+        //faltan pagos para alcanzar los consecutivos
+        assert!(!corresponde_bonificacion(&pagos, 3));
+        pagos.push(pago(1, 3000, 30, Some(29), false));
+        assert!(corresponde_bonificacion(&pagos, 3));
+        //solo se miran los ultimos pagos
+        pagos.insert(0, pago(1, 3000, 0, None, false));
+        assert!(corresponde_bonificacion(&pagos, 3));
+        //un pago fuera de termino, pendiente o bonificado corta la racha
+        pagos.push(pago(1, 3000, 40, Some(41), false));
+        assert!(!corresponde_bonificacion(&pagos, 3));
+        pagos.push(pago(1, 3000, 50, None, false));
+        assert!(!corresponde_bonificacion(&pagos, 1));
+        pagos.push(pago(1, 2000, 60, Some(60), true));
+        assert!(!corresponde_bonificacion(&pagos, 1));
+        assert!(!corresponde_bonificacion::<PagoPrueba>(&[], 0));
+    }
+
+    #[test]
+    fn vencimientos_y_emision_test() {
+        assert_eq!(vencimiento_alta(1000), Ok (1000 + 10.from_dias()));
+        assert_eq!(vencimiento_cuota(1000), Ok (1000 + 30.from_dias()));
+        assert!(!puede_emitir(1000, 1000 + 30.from_dias() - 1));
+        assert!(puede_emitir(1000, 1000 + 30.from_dias()));
+    }
+
+    #[test]
+    fn aplicar_pago_test() {
+        let mut pagos = vec![pago(1, 3000, 10, Some(5), false), pago(2, 3000, 10, None, false), pago(1, 3000, 20, None, false), pago(1, 3000, 30, None, false)];
+        assert_eq!(posicion_pago_a_aplicar(&pagos, 1, 3000, |pos| pos != 2), Some (3));
+        assert_eq!(aplicar_pago(&mut pagos, 1, 3000, 15), Some (2));
+        assert_eq!(pagos[2].fecha_pago, Some (15));
+        assert_eq!(aplicar_pago(&mut pagos, 1, 2000, 15), None);
+        assert_eq!(aplicar_pago(&mut pagos, 3, 3000, 15), None);
+    }
+}
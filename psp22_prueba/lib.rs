@@ -0,0 +1,126 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![allow(unexpected_cfgs, clippy::needless_return)]
+
+///Token PSP22 mínimo, usado solo para probar los cobros en token del contrato ClubSemRust. Implementa los mensajes del
+///estándar (con sus mismos selectores) sin extensiones: saldo, autorizaciones, transfer y transfer_from. Todo el
+///suministro inicial queda en la cuenta que lo despliega.
+#[ink::contract]
+pub mod psp22_prueba {
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// Errores del estándar PSP22.
+    #[derive(scale::Decode, scale::Encode, Debug, Clone, PartialEq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        Custom(String),
+        InsufficientBalance,
+        InsufficientAllowance,
+    }
+
+    #[ink(storage)]
+    pub struct Psp22Prueba {
+        suministro_total: u128,
+        saldos: Mapping<AccountId, u128>,
+        autorizaciones: Mapping<(AccountId, AccountId), u128>,
+    }
+
+    impl Psp22Prueba {
+        #[ink(constructor)]
+        pub fn new(suministro_total: u128) -> Self {
+            let mut saldos = Mapping::new();
+            saldos.insert(Self::env().caller(), &suministro_total);
+            return Self {suministro_total, saldos, autorizaciones: Mapping::new()};
+        }
+
+        #[ink(message, selector = 0x162df8c2)]
+        pub fn total_supply(&self) -> u128 {
+            return self.suministro_total;
+        }
+
+        #[ink(message, selector = 0x6568382f)]
+        pub fn balance_of(&self, owner: AccountId) -> u128 {
+            return self.saldos.get(owner).unwrap_or_default();
+        }
+
+        #[ink(message, selector = 0x4d47d921)]
+        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
+            return self.autorizaciones.get((owner, spender)).unwrap_or_default();
+        }
+
+        #[ink(message, selector = 0xb20f1bbd)]
+        pub fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error> {
+            self.autorizaciones.insert((self.env().caller(), spender), &value);
+            return Ok(());
+        }
+
+        #[ink(message, selector = 0xdb20f9f5)]
+        pub fn transfer(&mut self, to: AccountId, value: u128, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            return self.mover(self.env().caller(), to, value);
+        }
+
+        ///Transfiere value desde from hacia to, consumiendo la autorización que from le dio al caller.
+        #[ink(message, selector = 0x54b3c76e)]
+        pub fn transfer_from(&mut self, from: AccountId, to: AccountId, value: u128, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let autorizado = self.allowance(from, caller);
+            if autorizado < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+            self.mover(from, to, value)?;
+            self.autorizaciones.insert((from, caller), &(autorizado - value));
+            return Ok(());
+        }
+
+        fn mover(&mut self, from: AccountId, to: AccountId, value: u128) -> Result<(), PSP22Error> {
+            let saldo_from = self.balance_of(from);
+            if saldo_from < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            self.saldos.insert(from, &(saldo_from - value));
+            let saldo_to = self.balance_of(to);
+            self.saldos.insert(to, &(saldo_to + value));
+            return Ok(());
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[ink::test]
+        fn transfer_from_consume_autorizacion_test() {
+            let dueno = AccountId::from([0x1; 32]);
+            let club = AccountId::from([0x9; 32]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(dueno);
+            let mut token = Psp22Prueba::new(10000);
+            assert_eq!(token.approve(club, 3000), Ok(()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(club);
+            assert_eq!(token.transfer_from(dueno, club, 5000, Vec::new()), Err(PSP22Error::InsufficientAllowance));
+            assert_eq!(token.transfer_from(dueno, club, 3000, Vec::new()), Ok(()));
+            assert_eq!(token.balance_of(dueno), 7000);
+            assert_eq!(token.balance_of(club), 3000);
+            assert_eq!(token.allowance(dueno, club), 0);
+        }
+
+        #[ink::test]
+        fn transfer_from_saldo_insuficiente_test() {
+            let dueno = AccountId::from([0x1; 32]);
+            let socio = AccountId::from([0x2; 32]);
+            let club = AccountId::from([0x9; 32]);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(dueno);
+            let mut token = Psp22Prueba::new(10000);
+            assert_eq!(token.transfer(socio, 1000, Vec::new()), Ok(()));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(socio);
+            token.approve(club, 5000).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(club);
+            assert_eq!(token.transfer_from(socio, club, 3000, Vec::new()), Err(PSP22Error::InsufficientBalance));
+            //un rechazo no modifica saldos ni autorizaciones
+            assert_eq!(token.balance_of(socio), 1000);
+            assert_eq!(token.allowance(socio, club), 5000);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! reportes_club: genera reportes CSV o JSON a partir de una Instantanea del contrato ClubSemRust, tal como la devuelve
+//! exportar_instantanea (concatenando todos sus chunks). El archivo puede contener los bytes SCALE tal cual o su
+//! representacion hexadecimal con prefijo 0x.
+//!
+//! uso: reportes_club <archivo> <padron|deudas|recaudacion|bonificaciones> [--formato csv|json] [--fecha <milisegundos>]
+#![allow(clippy::needless_return)]
+
+mod reportes;
+mod salida;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use scale::Decode;
+use ClubSemRust::ClubSemRust::{Instantanea, VERSION_INSTANTANEA};
+
+const USO: &str = "uso: reportes_club <archivo> <padron|deudas|recaudacion|bonificaciones> [--formato csv|json] [--fecha <milisegundos>]";
+
+/// Opciones leidas de la linea de comandos.
+struct Opciones {
+    archivo: String,
+    reporte: String,
+    json: bool,
+    fecha: Option<u64>,
+}
+
+fn leer_opciones(argumentos: &[String]) -> Result<Opciones, String> {
+    let mut posicionales = Vec::new();
+    let mut json = false;
+    let mut fecha = None;
+    let mut iter = argumentos.iter();
+    while let Some(argumento) = iter.next() {
+        match argumento.as_str() {
+            "--formato" => match iter.next().map(String::as_str) {
+                Some("csv") => json = false,
+                Some("json") => json = true,
+                _ => return Err("--formato debe ser csv o json".to_string()),
+            },
+            "--fecha" => {
+                let valor = iter.next().ok_or("--fecha necesita un valor en milisegundos")?;
+                fecha = Some(valor.parse::<u64>().map_err(|_| format!("fecha invalida: {}", valor))?);
+            }
+            _ => posicionales.push(argumento.clone()),
+        }
+    }
+    let [archivo, reporte] = <[String; 2]>::try_from(posicionales).map_err(|_| USO.to_string())?;
+    return Ok(Opciones {archivo, reporte, json, fecha});
+}
+
+/// Interpreta el contenido del archivo: si es texto con prefijo 0x lo decodifica como hexadecimal, si no lo toma como
+/// los bytes SCALE de la instantanea.
+fn bytes_instantanea(contenido: Vec<u8>) -> Result<Vec<u8>, String> {
+    let Some(hex) = std::str::from_utf8(&contenido).ok().map(str::trim).and_then(|texto| texto.strip_prefix("0x")) else {
+        return Ok(contenido);
+    };
+    if hex.len() % 2 != 0 {
+        return Err("el contenido hexadecimal tiene una cantidad impar de digitos".to_string());
+    }
+    return hex.as_bytes().chunks(2)
+        .map(|par| std::str::from_utf8(par).ok().and_then(|digitos| u8::from_str_radix(digitos, 16).ok())
+            .ok_or_else(|| "el contenido hexadecimal no es valido".to_string()))
+        .collect();
+}
+
+fn ejecutar(opciones: Opciones) -> Result<String, String> {
+    let contenido = std::fs::read(&opciones.archivo).map_err(|e| format!("no se pudo leer {}: {}", opciones.archivo, e))?;
+    let bytes = bytes_instantanea(contenido)?;
+    let instantanea = Instantanea::decode(&mut &bytes[..]).map_err(|e| format!("no se pudo decodificar la instantanea: {}", e))?;
+    if instantanea.version != VERSION_INSTANTANEA {
+        return Err(format!("version de instantanea {} no soportada (se esperaba {})", instantanea.version, VERSION_INSTANTANEA));
+    }
+    let tabla = match opciones.reporte.as_str() {
+        "padron" => reportes::padron(&instantanea),
+        "deudas" => {
+            let ahora = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default();
+            reportes::deudas(&instantanea, opciones.fecha.unwrap_or(ahora))
+        }
+        "recaudacion" => reportes::recaudacion(&instantanea),
+        "bonificaciones" => reportes::bonificaciones(&instantanea),
+        otro => return Err(format!("reporte desconocido: {}\n{}", otro, USO)),
+    };
+    return Ok(if opciones.json { salida::a_json(&tabla) } else { salida::a_csv(&tabla) });
+}
+
+fn main() {
+    let argumentos: Vec<String> = std::env::args().skip(1).collect();
+    match leer_opciones(&argumentos).and_then(ejecutar) {
+        Ok(reporte) => print!("{}", reporte),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_instantanea_hexadecimal_test() {
+        assert_eq!(bytes_instantanea(b"0x0aff\n".to_vec()), Ok(vec![0x0a, 0xff]));
+        assert_eq!(bytes_instantanea(vec![1, 2, 3]), Ok(vec![1, 2, 3]));
+        assert!(bytes_instantanea(b"0x0g".to_vec()).is_err());
+    }
+
+    #[test]
+    fn bytes_instantanea_no_ascii_test() {
+        assert!(bytes_instantanea("0x0é0".as_bytes().to_vec()).is_err());
+        assert!(bytes_instantanea("0xé0a".as_bytes().to_vec()).is_err());
+    }
+}
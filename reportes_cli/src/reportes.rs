@@ -0,0 +1,233 @@
+//! Reportes calculados sobre una Instantanea del club. Cada reporte devuelve una Tabla, que luego se escribe como CSV
+//! o JSON desde el modulo salida.
+
+use std::collections::BTreeMap;
+
+use ClubSemRust::ClubSemRust::{Instantanea, Pago, Socio};
+
+/// Un valor de una celda de la tabla: texto o numero. Se distinguen para que la salida JSON no escriba los montos como
+/// strings.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Valor {
+    Texto(String),
+    Numero(u128),
+}
+
+/// El resultado de un reporte: los nombres de las columnas y una fila de valores por cada registro, en el mismo orden.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tabla {
+    pub columnas: Vec<&'static str>,
+    pub filas: Vec<Vec<Valor>>,
+}
+
+/// Convierte un timestamp en milisegundos (como los que guarda el contrato) al mes calendario UTC que le corresponde,
+/// con formato AAAA-MM. El año y mes se obtienen de nucleo_facturacion, igual que en el contrato.
+pub fn mes_de(milisegundos: u64) -> String {
+    let (anio, mes) = nucleo_facturacion::anio_y_mes(milisegundos);
+    return format!("{:04}-{:02}", anio, mes);
+}
+
+/// Nombre a mostrar de un socio. Los socios registrados con proteccion de datos no guardan su apellido y nombre.
+fn nombre_de(socio: &Socio) -> String {
+    if socio.get_compromiso().is_some() {
+        return "(protegido)".to_string();
+    }
+    return socio.get_apellido_y_nombre();
+}
+
+/// Deporte a mostrar de un socio, vacio si su categoria no tiene uno asignado.
+fn deporte_de(socio: &Socio) -> String {
+    return socio.get_deporte().map(|deporte| format!("{:?}", deporte)).unwrap_or_default();
+}
+
+/// Los pagos de la instantanea que pertenecen al socio.
+fn pagos_de<'a>(instantanea: &'a Instantanea, socio: &'a Socio) -> impl Iterator<Item = &'a Pago> {
+    return instantanea.pagos.iter().filter(move |pago| pago.get_dni_socio() == socio.get_dni());
+}
+
+/// Padron de socios agrupado por categoria y deporte: una fila por socio, ordenadas por categoria, deporte y DNI.
+pub fn padron(instantanea: &Instantanea) -> Tabla {
+    let mut socios: Vec<&Socio> = instantanea.socios.iter().collect();
+    socios.sort_by_key(|socio| (format!("{:?}", socio.get_categoria()), deporte_de(socio), socio.get_dni()));
+    let filas = socios.into_iter().map(|socio| vec![
+        Valor::Texto(format!("{:?}", socio.get_categoria())),
+        Valor::Texto(deporte_de(socio)),
+        Valor::Numero(socio.get_dni() as u128),
+        Valor::Texto(nombre_de(socio)),
+    ]).collect();
+    return Tabla {columnas: vec!["categoria", "deporte", "dni", "apellido_y_nombre"], filas};
+}
+
+/// Deuda de cada socio a la fecha de referencia (en milisegundos): cantidad y monto de los pagos pendientes, y de
+/// aquellos pendientes cuyo vencimiento ya paso. Incluye a los socios sin deuda, ordenados por DNI.
+pub fn deudas(instantanea: &Instantanea, fecha_referencia: u64) -> Tabla {
+    let mut socios: Vec<&Socio> = instantanea.socios.iter().collect();
+    socios.sort_by_key(|socio| socio.get_dni());
+    let filas = socios.into_iter().map(|socio| {
+        let pendientes: Vec<&Pago> = pagos_de(instantanea, socio).filter(|pago| pago.get_fecha_pago().is_none()).collect();
+        let vencidos: Vec<&&Pago> = pendientes.iter().filter(|pago| pago.get_fecha_venci() < fecha_referencia).collect();
+        vec![
+            Valor::Numero(socio.get_dni() as u128),
+            Valor::Texto(nombre_de(socio)),
+            Valor::Numero(pendientes.len() as u128),
+            Valor::Numero(pendientes.iter().map(|pago| pago.get_monto()).sum()),
+            Valor::Numero(vencidos.len() as u128),
+            Valor::Numero(vencidos.iter().map(|pago| pago.get_monto()).sum()),
+        ]
+    }).collect();
+    return Tabla {
+        columnas: vec!["dni", "apellido_y_nombre", "pagos_pendientes", "deuda_total", "pagos_vencidos", "deuda_vencida"],
+        filas,
+    };
+}
+
+/// Recaudacion por mes calendario (UTC) segun la fecha en que se efectuo cada pago: cantidad de pagos y monto total.
+pub fn recaudacion(instantanea: &Instantanea) -> Tabla {
+    let mut por_mes: BTreeMap<String, (u128, u128)> = BTreeMap::new();
+    for pago in &instantanea.pagos {
+        if let Some(fecha_pago) = pago.get_fecha_pago() {
+            let acumulado = por_mes.entry(mes_de(fecha_pago)).or_default();
+            acumulado.0 += 1;
+            acumulado.1 += pago.get_monto();
+        }
+    }
+    let filas = por_mes.into_iter().map(|(mes, (cantidad, total))| vec![
+        Valor::Texto(mes),
+        Valor::Numero(cantidad),
+        Valor::Numero(total),
+    ]).collect();
+    return Tabla {columnas: vec!["mes", "cantidad_pagos", "total"], filas};
+}
+
+/// Uso del beneficio por pagos consecutivos: por cada socio con pagos emitidos, cuantos fueron bonificados y el
+/// descuento estimado. El descuento se estima con el beneficio vigente en la instantanea, ya que el contrato no guarda
+/// el valor que tenia el beneficio al emitir cada pago.
+pub fn bonificaciones(instantanea: &Instantanea) -> Tabla {
+    let beneficio = instantanea.costos.get_beneficio();
+    let mut socios: Vec<&Socio> = instantanea.socios.iter().collect();
+    socios.sort_by_key(|socio| socio.get_dni());
+    let filas = socios.into_iter().filter_map(|socio| {
+        let emitidos = pagos_de(instantanea, socio).count() as u128;
+        if emitidos == 0 {
+            return None;
+        }
+        let bonificados = pagos_de(instantanea, socio).filter(|pago| pago.is_bonificado()).count() as u128;
+        Some(vec![
+            Valor::Numero(socio.get_dni() as u128),
+            Valor::Texto(nombre_de(socio)),
+            Valor::Numero(emitidos),
+            Valor::Numero(bonificados),
+            Valor::Numero(bonificados * beneficio),
+        ])
+    }).collect();
+    return Tabla {
+        columnas: vec!["dni", "apellido_y_nombre", "pagos_emitidos", "pagos_bonificados", "descuento_estimado"],
+        filas,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ClubSemRust::ClubSemRust::{CategoriasSocios, CostosCategoria, Deporte, DescuentosPlan, PoliticaMorosidad, PuntosCategoria, VERSION_INSTANTANEA};
+
+    const ENERO_2024: u64 = 1_704_067_200_000;
+    const FEBRERO_2024: u64 = 1_706_745_600_000;
+
+    fn instantanea_de_prueba() -> Instantanea {
+        Instantanea {
+            version: VERSION_INSTANTANEA,
+            costos: CostosCategoria::new(5000, 3000, 2000, 1000, 3),
+            socios: vec![
+                Socio::new("Kenneth Hutchinson".to_string(), 31195032, CategoriasSocios::A, None),
+                Socio::new("David Starsky".to_string(), 30537974, CategoriasSocios::B, Some(Deporte::Tenis)),
+                Socio::new("Harold Dobey".to_string(), 20123456, CategoriasSocios::B, Some(Deporte::Futbol)),
+            ],
+            pagos: vec![
+                Pago::new(30537974, 3000, ENERO_2024 + 10, Some(ENERO_2024), false),
+                Pago::new(30537974, 2000, FEBRERO_2024 + 10, Some(FEBRERO_2024 + 5), true),
+                Pago::new(31195032, 5000, ENERO_2024 + 10, Some(ENERO_2024 + 1), false),
+                Pago::new(31195032, 5000, FEBRERO_2024 + 10, None, false),
+                Pago::new(31195032, 5000, FEBRERO_2024 + 20, None, false),
+            ],
+            owner: ink::primitives::AccountId::from([0x1; 32]),
+            permitidos: Vec::new(),
+            politica_activada: false,
+            emision_ultimo_pago: Some(FEBRERO_2024),
+            proteccion_datos: false,
+            recibos: Vec::new(),
+            ultimo_nro_recibo: 0,
+            movimientos: Vec::new(),
+            ventana_reversion: 0,
+            retencion_auditoria: 100,
+            guardianes: Vec::new(),
+            lectores: Vec::new(),
+            token_pago: None,
+            puntos_categoria: PuntosCategoria::new(0, 0, 0),
+            vigencia_puntos: 0,
+            valor_punto: 1,
+            lotes_puntos: Vec::new(),
+            movimientos_puntos: Vec::new(),
+            canjes_pendientes: Vec::new(),
+            grupos_familiares: Vec::new(),
+            carnets: Vec::new(),
+            planes_cuotas: Vec::new(),
+            descuentos_plan: DescuentosPlan::new(0, 0, 0),
+            facturacion_socios: Vec::new(),
+            politica_morosidad: PoliticaMorosidad::new(0, 0, 0, 0),
+            asientos: Vec::new(),
+            certificados: Vec::new(),
+            vigencia_certificados: 0,
+            aptos_medicos: Vec::new(),
+            clases: Vec::new(),
+            profesores: Vec::new(),
+            asistencias: Vec::new(),
+            dispositivos_acceso: Vec::new(),
+            instalaciones: Vec::new(),
+            reservas: Vec::new(),
+            plazo_cancelacion: 86_400_000,
+            reintegros_pendientes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn mes_de_test() {
+        assert_eq!(mes_de(0), "1970-01");
+        assert_eq!(mes_de(ENERO_2024), "2024-01");
+        assert_eq!(mes_de(FEBRERO_2024 - 1), "2024-01");
+        assert_eq!(mes_de(FEBRERO_2024), "2024-02");
+    }
+
+    #[test]
+    fn padron_ordenado_por_categoria_y_deporte_test() {
+        let tabla = padron(&instantanea_de_prueba());
+        let dnis: Vec<&Valor> = tabla.filas.iter().map(|fila| &fila[2]).collect();
+        assert_eq!(dnis, vec![&Valor::Numero(31195032), &Valor::Numero(20123456), &Valor::Numero(30537974)]);
+        assert_eq!(tabla.filas[1][1], Valor::Texto("Futbol".to_string()));
+    }
+
+    #[test]
+    fn deudas_separa_vencidas_test() {
+        let tabla = deudas(&instantanea_de_prueba(), FEBRERO_2024 + 15);
+        let fila = tabla.filas.iter().find(|fila| fila[0] == Valor::Numero(31195032)).unwrap();
+        assert_eq!(fila[2..], [Valor::Numero(2), Valor::Numero(10000), Valor::Numero(1), Valor::Numero(5000)]);
+        let sin_deuda = tabla.filas.iter().find(|fila| fila[0] == Valor::Numero(20123456)).unwrap();
+        assert_eq!(sin_deuda[3], Valor::Numero(0));
+    }
+
+    #[test]
+    fn recaudacion_por_mes_test() {
+        let tabla = recaudacion(&instantanea_de_prueba());
+        assert_eq!(tabla.filas, vec![
+            vec![Valor::Texto("2024-01".to_string()), Valor::Numero(2), Valor::Numero(8000)],
+            vec![Valor::Texto("2024-02".to_string()), Valor::Numero(1), Valor::Numero(2000)],
+        ]);
+    }
+
+    #[test]
+    fn bonificaciones_omite_socios_sin_pagos_test() {
+        let tabla = bonificaciones(&instantanea_de_prueba());
+        assert_eq!(tabla.filas.len(), 2);
+        assert_eq!(tabla.filas[0][2..], [Valor::Numero(2), Valor::Numero(1), Valor::Numero(1000)]);
+    }
+}
@@ -0,0 +1,88 @@
+//! Formatos de salida de los reportes: CSV y JSON, armados a mano para no sumar dependencias.
+
+use crate::reportes::{Tabla, Valor};
+
+/// Escapa un campo CSV: si contiene comas, comillas o saltos de linea lo encierra entre comillas dobles,
+/// duplicando las comillas internas.
+fn campo_csv(texto: &str) -> String {
+    if texto.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", texto.replace('"', "\"\""));
+    }
+    return texto.to_string();
+}
+
+/// Escapa un texto para usarlo como string JSON (sin las comillas que lo encierran).
+fn texto_json(texto: &str) -> String {
+    let mut escapado = String::with_capacity(texto.len());
+    for c in texto.chars() {
+        match c {
+            '"' => escapado.push_str("\\\""),
+            '\\' => escapado.push_str("\\\\"),
+            '\n' => escapado.push_str("\\n"),
+            '\r' => escapado.push_str("\\r"),
+            '\t' => escapado.push_str("\\t"),
+            c if (c as u32) < 0x20 => escapado.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escapado.push(c),
+        }
+    }
+    return escapado;
+}
+
+/// Devuelve la tabla como CSV: una linea de encabezado con los nombres de las columnas y una linea por fila.
+pub fn a_csv(tabla: &Tabla) -> String {
+    let mut salida = tabla.columnas.iter().map(|columna| campo_csv(columna)).collect::<Vec<_>>().join(",");
+    salida.push('\n');
+    for fila in &tabla.filas {
+        let campos = fila.iter().map(|valor| match valor {
+            Valor::Texto(texto) => campo_csv(texto),
+            Valor::Numero(numero) => numero.to_string(),
+        });
+        salida.push_str(&campos.collect::<Vec<_>>().join(","));
+        salida.push('\n');
+    }
+    return salida;
+}
+
+/// Devuelve la tabla como un arreglo JSON con un objeto por fila, cuyas claves son los nombres de las columnas.
+/// Los montos se escriben como numeros, aunque superen el rango que algunos lectores de JSON representan sin perdida.
+pub fn a_json(tabla: &Tabla) -> String {
+    let objetos = tabla.filas.iter().map(|fila| {
+        let pares = tabla.columnas.iter().zip(fila).map(|(columna, valor)| match valor {
+            Valor::Texto(texto) => format!("\"{}\":\"{}\"", texto_json(columna), texto_json(texto)),
+            Valor::Numero(numero) => format!("\"{}\":{}", texto_json(columna), numero),
+        });
+        format!("{{{}}}", pares.collect::<Vec<_>>().join(","))
+    });
+    return format!("[{}]\n", objetos.collect::<Vec<_>>().join(","));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tabla_de_prueba() -> Tabla {
+        Tabla {
+            columnas: vec!["dni", "apellido_y_nombre"],
+            filas: vec![
+                vec![Valor::Numero(30537974), Valor::Texto("Starsky, David".to_string())],
+                vec![Valor::Numero(31195032), Valor::Texto("Hutch \"Ken\"".to_string())],
+            ],
+        }
+    }
+
+    #[test]
+    fn a_csv_escapa_campos_test() {
+        assert_eq!(
+            a_csv(&tabla_de_prueba()),
+            "dni,apellido_y_nombre\n30537974,\"Starsky, David\"\n31195032,\"Hutch \"\"Ken\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn a_json_escapa_campos_test() {
+        assert_eq!(
+            a_json(&tabla_de_prueba()),
+            "[{\"dni\":30537974,\"apellido_y_nombre\":\"Starsky, David\"},{\"dni\":31195032,\"apellido_y_nombre\":\"Hutch \\\"Ken\\\"\"}]\n"
+        );
+    }
+}
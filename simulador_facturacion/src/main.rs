@@ -0,0 +1,83 @@
+//! simulador_facturacion: simula la facturación del club sobre un padrón sintético durante muchos meses, con las mismas
+//! reglas de precio y bonificación que el contrato (nucleo_facturacion), para evaluar cambios en CostosCategoria o en
+//! el beneficio antes de aplicarlos. Escribe en stdout un CSV con el resumen de cada mes. La misma semilla y los mismos
+//! parámetros producen siempre la misma salida.
+//!
+//! uso: simulador_facturacion [--semilla N] [--meses N] [--socios N] [--costos A,B,C] [--beneficio N]
+//!                            [--pagos-consecutivos N] [--prob-a-termino P] [--prob-regulariza P]
+//!                            [--tasa-altas P] [--tasa-bajas P]
+#![allow(clippy::needless_return)]
+
+mod simulacion;
+
+use std::str::FromStr;
+
+use simulacion::{simular, Parametros, ResumenMensual};
+
+const USO: &str = "uso: simulador_facturacion [--semilla N] [--meses N] [--socios N] [--costos A,B,C] [--beneficio N] [--pagos-consecutivos N] [--prob-a-termino P] [--prob-regulariza P] [--tasa-altas P] [--tasa-bajas P]";
+
+fn valor<T: FromStr>(opcion: &str, texto: Option<&String>) -> Result<T, String> {
+    let texto = texto.ok_or(format!("{} necesita un valor", opcion))?;
+    return texto.parse::<T>().map_err(|_| format!("valor invalido para {}: {}", opcion, texto));
+}
+
+fn probabilidad(opcion: &str, texto: Option<&String>) -> Result<f64, String> {
+    let p: f64 = valor(opcion, texto)?;
+    if !(0.0..=1.0).contains(&p) {
+        return Err(format!("{} debe estar entre 0 y 1", opcion));
+    }
+    return Ok(p);
+}
+
+fn leer_parametros(argumentos: &[String]) -> Result<Parametros, String> {
+    let mut parametros = Parametros::default();
+    let mut iter = argumentos.iter();
+    while let Some(opcion) = iter.next() {
+        let opcion = opcion.as_str();
+        match opcion {
+            "--semilla" => parametros.semilla = valor(opcion, iter.next())?,
+            "--meses" => parametros.meses = valor(opcion, iter.next())?,
+            "--socios" => parametros.socios_iniciales = valor(opcion, iter.next())?,
+            "--beneficio" => parametros.beneficio = valor(opcion, iter.next())?,
+            "--pagos-consecutivos" => parametros.pagos_consecutivos = valor(opcion, iter.next())?,
+            "--prob-a-termino" => parametros.prob_a_termino = probabilidad(opcion, iter.next())?,
+            "--prob-regulariza" => parametros.prob_regulariza = probabilidad(opcion, iter.next())?,
+            "--tasa-altas" => parametros.tasa_altas = probabilidad(opcion, iter.next())?,
+            "--tasa-bajas" => parametros.tasa_bajas = probabilidad(opcion, iter.next())?,
+            "--costos" => {
+                let texto: String = valor(opcion, iter.next())?;
+                let costos = texto.split(',').map(|costo| costo.trim().parse::<u128>()).collect::<Result<Vec<_>, _>>();
+                parametros.costos = costos.ok().and_then(|costos| costos.try_into().ok())
+                    .ok_or(format!("--costos debe tener tres montos separados por coma: {}", texto))?;
+            }
+            _ => return Err(USO.to_string()),
+        }
+    }
+    if parametros.costos.iter().any(|costo| *costo < parametros.beneficio) {
+        return Err("el beneficio no puede superar el costo de ninguna categoria".to_string());
+    }
+    return Ok(parametros);
+}
+
+fn a_csv(resumenes: &[ResumenMensual]) -> String {
+    let mut salida = String::from("mes,socios_activos,altas,bajas,cuotas_emitidas,monto_emitido,cuotas_bonificadas,descuentos,recaudado,deuda_vencida,socios_morosos\n");
+    for r in resumenes {
+        salida.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{}\n",
+            r.mes, r.socios_activos, r.altas, r.bajas, r.cuotas_emitidas, r.monto_emitido, r.cuotas_bonificadas,
+            r.descuentos, r.recaudado, r.deuda_vencida, r.socios_morosos
+        ));
+    }
+    return salida;
+}
+
+fn main() {
+    let argumentos: Vec<String> = std::env::args().skip(1).collect();
+    match leer_parametros(&argumentos) {
+        Ok(parametros) => print!("{}", a_csv(&simular(&parametros))),
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Modelo de la simulación: un padrón sintético que se factura mes a mes con las reglas de nucleo_facturacion, las
+//! mismas que aplica el contrato.
+
+use nucleo_facturacion::{
+    aplicar_pago, corresponde_bonificacion, precio_cuota, puede_emitir, vencimiento_alta, vencimiento_cuota,
+    ConversionFecha, PagoFacturable, DIAS_ENTRE_EMISIONES,
+};
+
+/// Las fechas simuladas parten de 0 y avanzan de a un mes, por lo que sus vencimientos siempre entran en un u64.
+const FECHA_FUERA_DE_RANGO: &str = "la fecha simulada excede el rango de los timestamps";
+
+/// Generador pseudoaleatorio SplitMix64. Es chico y suficiente para la simulación, y garantiza que la misma semilla
+/// produzca siempre la misma corrida en cualquier plataforma.
+pub struct Aleatorio {
+    estado: u64,
+}
+
+impl Aleatorio {
+    pub fn new(semilla: u64) -> Aleatorio {
+        return Aleatorio {estado: semilla};
+    }
+
+    pub fn siguiente(&mut self) -> u64 {
+        self.estado = self.estado.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.estado;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        return z ^ (z >> 31);
+    }
+
+    /// Devuelve true con probabilidad p (entre 0 y 1).
+    pub fn ocurre(&mut self, p: f64) -> bool {
+        return ((self.siguiente() >> 11) as f64 / (1u64 << 53) as f64) < p;
+    }
+
+    /// Devuelve un numero entre desde y hasta, ambos incluidos.
+    pub fn entre(&mut self, desde: u64, hasta: u64) -> u64 {
+        return desde + self.siguiente() % (hasta - desde + 1);
+    }
+}
+
+/// Parámetros de una corrida: la política de cobro a evaluar (costos, beneficio y pagos consecutivos) y el
+/// comportamiento supuesto de los socios. Las probabilidades y tasas son mensuales, entre 0 y 1.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parametros {
+    pub semilla: u64,
+    pub meses: u32,
+    pub socios_iniciales: u32,
+    pub costos: [u128; 3],
+    pub beneficio: u128,
+    pub pagos_consecutivos: u8,
+    /// Probabilidad de que un socio pague una cuota antes de su vencimiento.
+    pub prob_a_termino: f64,
+    /// Probabilidad de que un socio pague, en un mes dado, cada cuota que ya tiene vencida.
+    pub prob_regulariza: f64,
+    /// Altas esperadas por mes, como fracción de los socios activos.
+    pub tasa_altas: f64,
+    /// Probabilidad de que un socio activo renuncie en un mes dado.
+    pub tasa_bajas: f64,
+}
+
+impl Default for Parametros {
+    fn default() -> Parametros {
+        return Parametros {
+            semilla: 1,
+            meses: 36,
+            socios_iniciales: 200,
+            costos: [5000, 3000, 2000],
+            beneficio: 1000,
+            pagos_consecutivos: 3,
+            prob_a_termino: 0.8,
+            prob_regulariza: 0.5,
+            tasa_altas: 0.03,
+            tasa_bajas: 0.02,
+        };
+    }
+}
+
+/// Resumen de un mes simulado.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResumenMensual {
+    pub mes: u32,
+    pub socios_activos: u32,
+    pub altas: u32,
+    pub bajas: u32,
+    pub cuotas_emitidas: u32,
+    pub monto_emitido: u128,
+    pub cuotas_bonificadas: u32,
+    /// Lo que se dejó de cobrar por las cuotas bonificadas.
+    pub descuentos: u128,
+    pub recaudado: u128,
+    /// Suma de las cuotas impagas y vencidas al cierre del mes, incluidas las de socios que renunciaron.
+    pub deuda_vencida: u128,
+    pub socios_morosos: u32,
+}
+
+#[derive(Debug, Clone)]
+struct PagoSimulado {
+    dni: u32,
+    monto: u128,
+    fecha_venci: u64,
+    fecha_pago: Option<u64>,
+    bonificado: bool,
+}
+
+impl PagoFacturable for PagoSimulado {
+    fn dni(&self) -> u32 {
+        return self.dni;
+    }
+    fn monto(&self) -> u128 {
+        return self.monto;
+    }
+    fn fecha_venci(&self) -> u64 {
+        return self.fecha_venci;
+    }
+    fn fecha_pago(&self) -> Option<u64> {
+        return self.fecha_pago;
+    }
+    fn bonificado(&self) -> bool {
+        return self.bonificado;
+    }
+    fn marcar_pagado(&mut self, fecha_pago: u64) {
+        self.fecha_pago = Some(fecha_pago);
+    }
+}
+
+struct SocioSimulado {
+    dni: u32,
+    costo: u128,
+    activo: bool,
+    pagos: Vec<PagoSimulado>,
+}
+
+/// Estado de una corrida en curso.
+struct Club<'a> {
+    parametros: &'a Parametros,
+    aleatorio: Aleatorio,
+    socios: Vec<SocioSimulado>,
+    proximo_dni: u32,
+    ultima_emision: Option<u64>,
+}
+
+impl Club<'_> {
+    /// Da de alta un socio de categoría al azar, con su primer pago como en el contrato: costo completo y vencimiento a
+    /// los 10 dias.
+    fn alta(&mut self, fecha: u64) {
+        let costo = self.parametros.costos[self.aleatorio.entre(0, 2) as usize];
+        let dni = self.proximo_dni;
+        self.proximo_dni += 1;
+        let pago = PagoSimulado {dni, monto: costo, fecha_venci: vencimiento_alta(fecha).expect(FECHA_FUERA_DE_RANGO), fecha_pago: None, bonificado: false};
+        self.socios.push(SocioSimulado {dni, costo, activo: true, pagos: vec![pago]});
+        self.ultima_emision.get_or_insert(fecha);
+    }
+
+    /// Emite la cuota del mes a cada socio activo, como emitir_pago_mensual. Devuelve cuotas, monto, bonificadas y
+    /// descuentos emitidos.
+    fn emitir(&mut self, fecha: u64) -> (u32, u128, u32, u128) {
+        let mut resultado = (0, 0, 0, 0);
+        match self.ultima_emision {
+            Some(ultima) if puede_emitir(ultima, fecha) => {}
+            _ => return resultado,
+        }
+        for socio in self.socios.iter_mut().filter(|socio| socio.activo) {
+            let bonificado = corresponde_bonificacion(&socio.pagos, self.parametros.pagos_consecutivos);
+            let monto = precio_cuota(socio.costo, self.parametros.beneficio, bonificado).expect("leer_parametros verifica que el beneficio no supere ningun costo");
+            socio.pagos.push(PagoSimulado {dni: socio.dni, monto, fecha_venci: vencimiento_cuota(fecha).expect(FECHA_FUERA_DE_RANGO), fecha_pago: None, bonificado});
+            resultado.0 += 1;
+            resultado.1 += monto;
+            if bonificado {
+                resultado.2 += 1;
+                resultado.3 += socio.costo - monto;
+            }
+        }
+        self.ultima_emision = Some(fecha);
+        return resultado;
+    }
+
+    /// Simula los pagos de los socios durante el mes [inicio, fin). Primero cada cuota vencida puede regularizarse, y
+    /// luego cada cuota que vence en el mes puede pagarse a término. Los cobros se aplican con aplicar_pago, igual que en
+    /// realizar_pago: si el socio tiene una cuota vieja pendiente del mismo monto, el cobro la salda a ella primero.
+    fn cobrar(&mut self, inicio: u64, fin: u64) -> u128 {
+        let mut recaudado = 0;
+        for socio in self.socios.iter_mut() {
+            let pendientes: Vec<(u128, u64)> = socio.pagos.iter()
+                .filter(|pago| pago.fecha_pago.is_none())
+                .map(|pago| (pago.monto, pago.fecha_venci))
+                .collect();
+            for (monto, fecha_venci) in pendientes {
+                let fecha_pago = if fecha_venci < inicio {
+                    if !self.aleatorio.ocurre(self.parametros.prob_regulariza) {
+                        continue;
+                    }
+                    self.aleatorio.entre(inicio, fin - 1)
+                } else if socio.activo && fecha_venci <= fin {
+                    if !self.aleatorio.ocurre(self.parametros.prob_a_termino) {
+                        continue;
+                    }
+                    self.aleatorio.entre(inicio, fecha_venci)
+                } else {
+                    continue;
+                };
+                if aplicar_pago(&mut socio.pagos, socio.dni, monto, fecha_pago).is_some() {
+                    recaudado += monto;
+                }
+            }
+        }
+        return recaudado;
+    }
+}
+
+/// Simula parametros.meses meses y devuelve el resumen de cada uno. El mes 0 solo registra a los socios iniciales;
+/// las cuotas mensuales se emiten a partir del mes 1, cada 30 dias como en el contrato.
+pub fn simular(parametros: &Parametros) -> Vec<ResumenMensual> {
+    let mut club = Club {
+        parametros,
+        aleatorio: Aleatorio::new(parametros.semilla),
+        socios: Vec::new(),
+        proximo_dni: 1,
+        ultima_emision: None,
+    };
+    for _ in 0..parametros.socios_iniciales {
+        club.alta(0);
+    }
+    let mut resumenes = Vec::new();
+    for mes in 0..parametros.meses {
+        let inicio = mes as u64 * DIAS_ENTRE_EMISIONES.from_dias();
+        let fin = inicio + DIAS_ENTRE_EMISIONES.from_dias();
+        let mut altas = if mes == 0 { parametros.socios_iniciales } else { 0 };
+        let mut bajas = 0;
+        if mes > 0 {
+            for socio in club.socios.iter_mut().filter(|socio| socio.activo) {
+                if club.aleatorio.ocurre(parametros.tasa_bajas) {
+                    socio.activo = false;
+                    bajas += 1;
+                }
+            }
+            let activos = club.socios.iter().filter(|socio| socio.activo).count();
+            for _ in 0..activos {
+                if club.aleatorio.ocurre(parametros.tasa_altas) {
+                    club.alta(inicio);
+                    altas += 1;
+                }
+            }
+        }
+        let (cuotas_emitidas, monto_emitido, cuotas_bonificadas, descuentos) = club.emitir(inicio);
+        let recaudado = club.cobrar(inicio, fin);
+        let mut deuda_vencida = 0;
+        let mut socios_morosos = 0;
+        for socio in &club.socios {
+            let deuda: u128 = socio.pagos.iter()
+                .filter(|pago| pago.fecha_pago.is_none() && pago.fecha_venci < fin)
+                .map(|pago| pago.monto)
+                .sum();
+            deuda_vencida += deuda;
+            if deuda > 0 {
+                socios_morosos += 1;
+            }
+        }
+        resumenes.push(ResumenMensual {
+            mes,
+            socios_activos: club.socios.iter().filter(|socio| socio.activo).count() as u32,
+            altas,
+            bajas,
+            cuotas_emitidas,
+            monto_emitido,
+            cuotas_bonificadas,
+            descuentos,
+            recaudado,
+            deuda_vencida,
+            socios_morosos,
+        });
+    }
+    return resumenes;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn misma_semilla_misma_corrida_test() {
+        let parametros = Parametros {meses: 24, ..Parametros::default()};
+        assert_eq!(simular(&parametros), simular(&parametros));
+        let otra = Parametros {semilla: 2, ..parametros.clone()};
+        assert_ne!(simular(&parametros), simular(&otra));
+    }
+
+    #[test]
+    fn socios_cumplidores_sin_deuda_y_con_bonificacion_test() {
+        let parametros = Parametros {
+            meses: 9,
+            socios_iniciales: 10,
+            costos: [3000, 3000, 3000],
+            prob_a_termino: 1.0,
+            tasa_altas: 0.0,
+            tasa_bajas: 0.0,
+            ..Parametros::default()
+        };
+        let resumenes = simular(&parametros);
+        for resumen in &resumenes {
+            assert_eq!(resumen.deuda_vencida, 0);
+            assert_eq!(resumen.recaudado, resumen.monto_emitido + if resumen.mes == 0 { 30000 } else { 0 });
+        }
+        //con 3 pagos consecutivos, se bonifica una cuota de cada 4: meses 3 y 7
+        let bonificados: Vec<u32> = resumenes.iter().filter(|r| r.cuotas_bonificadas > 0).map(|r| r.mes).collect();
+        assert_eq!(bonificados, vec![3, 7]);
+        assert_eq!(resumenes[3].descuentos, 10 * 1000);
+    }
+
+    #[test]
+    fn socios_que_no_pagan_acumulan_deuda_test() {
+        let parametros = Parametros {
+            meses: 4,
+            socios_iniciales: 5,
+            costos: [2000, 2000, 2000],
+            prob_a_termino: 0.0,
+            prob_regulariza: 0.0,
+            tasa_altas: 0.0,
+            tasa_bajas: 0.0,
+            ..Parametros::default()
+        };
+        let resumenes = simular(&parametros);
+        assert!(resumenes.iter().all(|resumen| resumen.recaudado == 0 && resumen.cuotas_bonificadas == 0));
+        //la cuota de alta vence en el mes 0, y cada cuota mensual vence al final de su mes
+        assert_eq!(resumenes.iter().map(|r| r.deuda_vencida).collect::<Vec<_>>(), vec![10000, 10000, 20000, 30000]);
+        assert_eq!(resumenes[3].socios_morosos, 5);
+    }
+}